@@ -0,0 +1,130 @@
+//! Code generated by mcp-icon-server's `get_icon_code` and `get_icon_details`,
+//! compiled against yew-shortcuts so the server only suggests code that builds.
+//!
+//! Do not edit: regenerate with `UPDATE_CODEGEN_CHECK=1 cargo test -p mcp-icon-server`.
+
+pub mod solid_house {
+    pub mod code {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg, IconFit};
+
+        pub fn examples() -> Vec<Html> {
+            vec![
+                // Default cropped version (always available):
+                html! {
+                    <FontAwesomeSvg icon={icons::solid::HOUSE} />
+                },
+                // Full SVG version (requires "full-svg" feature in Cargo.toml):
+                html! {
+                    <FontAwesomeSvg icon={icons::solid::HOUSE} full={true} />
+                },
+                // Square viewBox, so icons of different widths line up in a grid:
+                html! {
+                    <FontAwesomeSvg icon={icons::solid::HOUSE} fit={IconFit::Square} />
+                },
+            ]
+        }
+    }
+
+    pub mod details {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg};
+
+        #[function_component]
+        pub fn MyIconComponent() -> Html {
+            html! {
+                <div class="icon-container">
+                    <FontAwesomeSvg
+                        icon={icons::solid::HOUSE}
+                        classes={classes!("text-blue-500", "w-6", "h-6")}
+                        style={"color: currentColor"}
+                    />
+                </div>
+            }
+        }
+    }
+}
+
+pub mod regular_heart {
+    pub mod code {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg, IconFit};
+
+        pub fn examples() -> Vec<Html> {
+            vec![
+                // Default cropped version (always available):
+                html! {
+                    <FontAwesomeSvg icon={icons::regular::HEART} />
+                },
+                // Full SVG version (requires "full-svg" feature in Cargo.toml):
+                html! {
+                    <FontAwesomeSvg icon={icons::regular::HEART} full={true} />
+                },
+                // Square viewBox, so icons of different widths line up in a grid:
+                html! {
+                    <FontAwesomeSvg icon={icons::regular::HEART} fit={IconFit::Square} />
+                },
+            ]
+        }
+    }
+
+    pub mod details {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg};
+
+        #[function_component]
+        pub fn MyIconComponent() -> Html {
+            html! {
+                <div class="icon-container">
+                    <FontAwesomeSvg
+                        icon={icons::regular::HEART}
+                        classes={classes!("text-blue-500", "w-6", "h-6")}
+                        style={"color: currentColor"}
+                    />
+                </div>
+            }
+        }
+    }
+}
+
+pub mod brands_github {
+    pub mod code {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg, IconFit};
+
+        pub fn examples() -> Vec<Html> {
+            vec![
+                // Default cropped version (always available):
+                html! {
+                    <FontAwesomeSvg icon={icons::brands::GITHUB} />
+                },
+                // Full SVG version (requires "full-svg" feature in Cargo.toml):
+                html! {
+                    <FontAwesomeSvg icon={icons::brands::GITHUB} full={true} />
+                },
+                // Square viewBox, so icons of different widths line up in a grid:
+                html! {
+                    <FontAwesomeSvg icon={icons::brands::GITHUB} fit={IconFit::Square} />
+                },
+            ]
+        }
+    }
+
+    pub mod details {
+        use yew::prelude::*;
+        use yew_shortcuts::{fontawesome::icons, FontAwesomeSvg};
+
+        #[function_component]
+        pub fn MyIconComponent() -> Html {
+            html! {
+                <div class="icon-container">
+                    <FontAwesomeSvg
+                        icon={icons::brands::GITHUB}
+                        classes={classes!("text-blue-500", "w-6", "h-6")}
+                        style={"color: currentColor"}
+                    />
+                </div>
+            }
+        }
+    }
+}
@@ -1,64 +1,114 @@
+use icon_model::manifest::{Manifest, ManifestIcon};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
 use std::path::Path;
 
+/// Look-alike icons suggested for each icon
+const SIMILAR_ICONS: usize = 6;
+
 fn main() {
-    // Tell cargo to rerun this build script if fontawesome.rs changes
-    println!("cargo:rerun-if-changed=../yew-shortcuts/src/fontawesome.rs");
-    
-    // Read the fontawesome.rs file
-    let fontawesome_path = Path::new("../yew-shortcuts/src/fontawesome.rs");
-    let content = fs::read_to_string(fontawesome_path)
-        .expect("Failed to read fontawesome.rs");
-    
-    // Count icons by looking for "pub const" patterns in the icons module
-    let mut total_icons = 0;
-    let mut solid_icons = 0;
-    let mut regular_icons = 0;
-    let mut brands_icons = 0;
-    
-    // Track which module we're in
-    let mut current_module = "";
-    
-    for line in content.lines() {
-        let trimmed = line.trim();
-        
-        // Check for module declarations
-        if trimmed.starts_with("pub mod solid {") {
-            current_module = "solid";
-        } else if trimmed.starts_with("pub mod regular {") {
-            current_module = "regular";
-        } else if trimmed.starts_with("pub mod brands {") {
-            current_module = "brands";
+    let free_path = Path::new("../yew-shortcuts/src/fontawesome.json");
+    let pro_path = Path::new("../yew-shortcuts/src/fontawesome/pro/fontawesome-pro.json");
+
+    // Tell cargo to rerun this build script if the icon manifests change. A
+    // missing path counts as changed on every build, so the Pro manifest is
+    // only watched when it is read or exists.
+    let pro = env::var_os("CARGO_FEATURE_PRO").is_some();
+    println!("cargo:rerun-if-changed={}", free_path.display());
+    if pro || pro_path.exists() {
+        println!("cargo:rerun-if-changed={}", pro_path.display());
+    }
+
+    // Read the manifest written next to fontawesome.rs, plus the local Pro one with the `pro` feature
+    let mut manifests = vec![Manifest::read(free_path).expect("Failed to read fontawesome.json")];
+    if pro {
+        manifests.push(
+            Manifest::read(pro_path)
+                .expect("Failed to read fontawesome-pro.json; run `icon-processor --pro <dir>` first"),
+        );
+    }
+
+    // Group icons per category, free categories first
+    let mut categories: Vec<(&str, Vec<&ManifestIcon>)> = Vec::new();
+    for manifest in &manifests {
+        let mut by_category: BTreeMap<&str, Vec<&ManifestIcon>> = BTreeMap::new();
+        for icon in &manifest.icons {
+            by_category.entry(&icon.category).or_default().push(icon);
         }
-        
-        // Count icon constants
-        if trimmed.starts_with("pub const ") && trimmed.contains(": &Icon = &Icon {") {
-            total_icons += 1;
-            match current_module {
-                "solid" => solid_icons += 1,
-                "regular" => regular_icons += 1,
-                "brands" => brands_icons += 1,
-                _ => {}
+        categories.extend(by_category);
+    }
+    let total_icons: usize = categories.iter().map(|(_, icons)| icons.len()).sum();
+
+    // Icons that look alike, by the Hamming distance of their perceptual hashes.
+    // Hashes are bucketed by their number of set bits: two hashes differ in at
+    // least as many bits as their counts do, so the search widens bucket by
+    // bucket and stops once no further bucket can beat the nearest icons found.
+    // With the Pro set this avoids comparing every pair of tens of thousands of icons.
+    let mut by_ones: Vec<Vec<(u64, &str)>> = vec![Vec::new(); 65];
+    for icon in categories.iter().flat_map(|(_, icons)| icons.iter()) {
+        if let Some(hash) = icon.perceptual_hash() {
+            by_ones[hash.count_ones() as usize].push((hash, icon.import_path.as_str()));
+        }
+    }
+    let similar_icons = |icon: &ManifestIcon| -> Vec<&str> {
+        let Some(hash) = icon.perceptual_hash() else {
+            return Vec::new();
+        };
+        let ones = hash.count_ones() as usize;
+        // The nearest icons so far as (distance, import path), kept sorted
+        let mut nearest: Vec<(u32, &str)> = Vec::with_capacity(SIMILAR_ICONS + 1);
+        for delta in 0..by_ones.len() {
+            if nearest.len() == SIMILAR_ICONS && nearest.last().is_some_and(|(worst, _)| *worst < delta as u32) {
+                break;
+            }
+            let buckets = [ones.checked_sub(delta), Some(ones + delta).filter(|_| delta > 0)];
+            for bucket in buckets.into_iter().flatten().filter_map(|bucket| by_ones.get(bucket)) {
+                for &(other, import_path) in bucket {
+                    if import_path == icon.import_path {
+                        continue;
+                    }
+                    let candidate = ((hash ^ other).count_ones(), import_path);
+                    if nearest.len() < SIMILAR_ICONS || nearest.last().is_some_and(|worst| candidate < *worst) {
+                        let position = nearest.partition_point(|found| *found < candidate);
+                        nearest.insert(position, candidate);
+                        nearest.truncate(SIMILAR_ICONS);
+                    }
+                }
             }
         }
+        nearest.into_iter().map(|(_, import_path)| import_path).collect()
+    };
+
+    // Generate the icon catalog for the demo
+    let mut generated = String::new();
+    generated.push_str("/// Auto-generated icon catalog from build.rs\n");
+    generated.push_str(&format!("pub const TOTAL_ICONS: usize = {};\n\n", total_icons));
+    generated.push_str("/// An icon of the catalog as (Rust name, icon, icons that look alike)\n");
+    generated.push_str("pub type CatalogIcon = (&'static str, &'static fontawesome::Icon, &'static [&'static fontawesome::Icon]);\n\n");
+    generated.push_str("/// Every category with its icons\n");
+    generated.push_str("pub const CATEGORIES: &[(&str, &[CatalogIcon])] = &[\n");
+    for (category, icons) in &categories {
+        generated.push_str(&format!("    (\"{}\", &[\n", category));
+        for icon in icons {
+            generated.push_str(&format!(
+                "        (\"{}\", {}, &[{}]),\n",
+                icon.rust_name,
+                icon.import_path,
+                similar_icons(icon).join(", ")
+            ));
+        }
+        generated.push_str("    ]),\n");
     }
-    
-    // Generate a constants file for the demo
+    generated.push_str("];\n");
+
     let out_dir = env::var("OUT_DIR").unwrap();
-    let dest_path = Path::new(&out_dir).join("icon_counts.rs");
-    
-    let generated = format!(
-        "/// Auto-generated icon counts from build.rs\n\
-         pub const TOTAL_ICONS: usize = {};\n\
-         pub const SOLID_ICONS_COUNT: usize = {};\n\
-         pub const REGULAR_ICONS_COUNT: usize = {};\n\
-         pub const BRANDS_ICONS_COUNT: usize = {};\n",
-        total_icons, solid_icons, regular_icons, brands_icons
-    );
-    
-    fs::write(&dest_path, generated).expect("Failed to write icon counts");
-    
-    eprintln!("Counted {} total icons ({} solid, {} regular, {} brands)", 
-             total_icons, solid_icons, regular_icons, brands_icons);
-}
\ No newline at end of file
+    let dest_path = Path::new(&out_dir).join("icon_catalog.rs");
+    fs::write(&dest_path, generated).expect("Failed to write icon catalog");
+
+    let counts: Vec<String> = categories
+        .iter()
+        .map(|(category, icons)| format!("{} {}", icons.len(), category))
+        .collect();
+    eprintln!("Counted {} total icons ({})", total_icons, counts.join(", "));
+}
@@ -1,2822 +0,0 @@
-//! All FontAwesome 7.0 icons for the demo
-//! 
-//! This module lists all available icons for the demo application.
-//! Generated by icon-processor/src/bin/generate_demo_icons.rs
-
-use yew_shortcuts::fontawesome::{Icon, icons};
-
-pub const BRANDS_ICONS: &[(&str, &Icon)] = &[
-    ("accessible-icon", &icons::brands::ACCESSIBLE_ICON),
-    ("accusoft", &icons::brands::ACCUSOFT),
-    ("adn", &icons::brands::ADN),
-    ("adversal", &icons::brands::ADVERSAL),
-    ("affiliatetheme", &icons::brands::AFFILIATETHEME),
-    ("airbnb", &icons::brands::AIRBNB),
-    ("algolia", &icons::brands::ALGOLIA),
-    ("alipay", &icons::brands::ALIPAY),
-    ("amazon", &icons::brands::AMAZON),
-    ("amazon-pay", &icons::brands::AMAZON_PAY),
-    ("amilia", &icons::brands::AMILIA),
-    ("android", &icons::brands::ANDROID),
-    ("angellist", &icons::brands::ANGELLIST),
-    ("angrycreative", &icons::brands::ANGRYCREATIVE),
-    ("angular", &icons::brands::ANGULAR),
-    ("apper", &icons::brands::APPER),
-    ("apple", &icons::brands::APPLE),
-    ("apple-pay", &icons::brands::APPLE_PAY),
-    ("app-store", &icons::brands::APP_STORE),
-    ("app-store-ios", &icons::brands::APP_STORE_IOS),
-    ("artstation", &icons::brands::ARTSTATION),
-    ("asymmetrik", &icons::brands::ASYMMETRIK),
-    ("atlassian", &icons::brands::ATLASSIAN),
-    ("audible", &icons::brands::AUDIBLE),
-    ("autoprefixer", &icons::brands::AUTOPREFIXER),
-    ("avianex", &icons::brands::AVIANEX),
-    ("aviato", &icons::brands::AVIATO),
-    ("aws", &icons::brands::AWS),
-    ("bandcamp", &icons::brands::BANDCAMP),
-    ("battle-net", &icons::brands::BATTLE_NET),
-    ("behance", &icons::brands::BEHANCE),
-    ("behance-square", &icons::brands::BEHANCE_SQUARE),
-    ("bilibili", &icons::brands::BILIBILI),
-    ("bimobject", &icons::brands::BIMOBJECT),
-    ("bitbucket", &icons::brands::BITBUCKET),
-    ("bitcoin", &icons::brands::BITCOIN),
-    ("bity", &icons::brands::BITY),
-    ("blackberry", &icons::brands::BLACKBERRY),
-    ("black-tie", &icons::brands::BLACK_TIE),
-    ("blogger", &icons::brands::BLOGGER),
-    ("blogger-b", &icons::brands::BLOGGER_B),
-    ("bluesky", &icons::brands::BLUESKY),
-    ("bluetooth", &icons::brands::BLUETOOTH),
-    ("bluetooth-b", &icons::brands::BLUETOOTH_B),
-    ("bootstrap", &icons::brands::BOOTSTRAP),
-    ("bots", &icons::brands::BOTS),
-    ("brave", &icons::brands::BRAVE),
-    ("brave-reverse", &icons::brands::BRAVE_REVERSE),
-    ("btc", &icons::brands::BTC),
-    ("buffer", &icons::brands::BUFFER),
-    ("buromobelexperte", &icons::brands::BUROMOBELEXPERTE),
-    ("buysellads", &icons::brands::BUYSELLADS),
-    ("buy-n-large", &icons::brands::BUY_N_LARGE),
-    ("canadian-maple-leaf", &icons::brands::CANADIAN_MAPLE_LEAF),
-    ("cash-app", &icons::brands::CASH_APP),
-    ("cc-amazon-pay", &icons::brands::CC_AMAZON_PAY),
-    ("cc-amex", &icons::brands::CC_AMEX),
-    ("cc-apple-pay", &icons::brands::CC_APPLE_PAY),
-    ("cc-diners-club", &icons::brands::CC_DINERS_CLUB),
-    ("cc-discover", &icons::brands::CC_DISCOVER),
-    ("cc-jcb", &icons::brands::CC_JCB),
-    ("cc-mastercard", &icons::brands::CC_MASTERCARD),
-    ("cc-paypal", &icons::brands::CC_PAYPAL),
-    ("cc-stripe", &icons::brands::CC_STRIPE),
-    ("cc-visa", &icons::brands::CC_VISA),
-    ("centercode", &icons::brands::CENTERCODE),
-    ("centos", &icons::brands::CENTOS),
-    ("chrome", &icons::brands::CHROME),
-    ("chromecast", &icons::brands::CHROMECAST),
-    ("cloudflare", &icons::brands::CLOUDFLARE),
-    ("cloudscale", &icons::brands::CLOUDSCALE),
-    ("cloudsmith", &icons::brands::CLOUDSMITH),
-    ("cloudversify", &icons::brands::CLOUDVERSIFY),
-    ("cmplid", &icons::brands::CMPLID),
-    ("codepen", &icons::brands::CODEPEN),
-    ("codiepie", &icons::brands::CODIEPIE),
-    ("confluence", &icons::brands::CONFLUENCE),
-    ("connectdevelop", &icons::brands::CONNECTDEVELOP),
-    ("contao", &icons::brands::CONTAO),
-    ("cotton-bureau", &icons::brands::COTTON_BUREAU),
-    ("cpanel", &icons::brands::CPANEL),
-    ("creative-commons", &icons::brands::CREATIVE_COMMONS),
-    ("creative-commons-by", &icons::brands::CREATIVE_COMMONS_BY),
-    ("creative-commons-nc", &icons::brands::CREATIVE_COMMONS_NC),
-    ("creative-commons-nc-eu", &icons::brands::CREATIVE_COMMONS_NC_EU),
-    ("creative-commons-nc-jp", &icons::brands::CREATIVE_COMMONS_NC_JP),
-    ("creative-commons-nd", &icons::brands::CREATIVE_COMMONS_ND),
-    ("creative-commons-pd", &icons::brands::CREATIVE_COMMONS_PD),
-    ("creative-commons-pd-alt", &icons::brands::CREATIVE_COMMONS_PD_ALT),
-    ("creative-commons-remix", &icons::brands::CREATIVE_COMMONS_REMIX),
-    ("creative-commons-sa", &icons::brands::CREATIVE_COMMONS_SA),
-    ("creative-commons-sampling", &icons::brands::CREATIVE_COMMONS_SAMPLING),
-    ("creative-commons-sampling-plus", &icons::brands::CREATIVE_COMMONS_SAMPLING_PLUS),
-    ("creative-commons-share", &icons::brands::CREATIVE_COMMONS_SHARE),
-    ("creative-commons-zero", &icons::brands::CREATIVE_COMMONS_ZERO),
-    ("critical-role", &icons::brands::CRITICAL_ROLE),
-    ("css", &icons::brands::CSS),
-    ("css3", &icons::brands::CSS3),
-    ("css3-alt", &icons::brands::CSS3_ALT),
-    ("cuttlefish", &icons::brands::CUTTLEFISH),
-    ("dailymotion", &icons::brands::DAILYMOTION),
-    ("dart-lang", &icons::brands::DART_LANG),
-    ("dashcube", &icons::brands::DASHCUBE),
-    ("debian", &icons::brands::DEBIAN),
-    ("deezer", &icons::brands::DEEZER),
-    ("delicious", &icons::brands::DELICIOUS),
-    ("deploydog", &icons::brands::DEPLOYDOG),
-    ("deskpro", &icons::brands::DESKPRO),
-    ("dev", &icons::brands::DEV),
-    ("deviantart", &icons::brands::DEVIANTART),
-    ("dhl", &icons::brands::DHL),
-    ("diaspora", &icons::brands::DIASPORA),
-    ("digg", &icons::brands::DIGG),
-    ("digital-ocean", &icons::brands::DIGITAL_OCEAN),
-    ("discord", &icons::brands::DISCORD),
-    ("discourse", &icons::brands::DISCOURSE),
-    ("disqus", &icons::brands::DISQUS),
-    ("dochub", &icons::brands::DOCHUB),
-    ("docker", &icons::brands::DOCKER),
-    ("draft2digital", &icons::brands::DRAFT2DIGITAL),
-    ("dribbble", &icons::brands::DRIBBBLE),
-    ("dribbble-square", &icons::brands::DRIBBBLE_SQUARE),
-    ("dropbox", &icons::brands::DROPBOX),
-    ("drupal", &icons::brands::DRUPAL),
-    ("duolingo", &icons::brands::DUOLINGO),
-    ("dyalog", &icons::brands::DYALOG),
-    ("d-and-d", &icons::brands::D_AND_D),
-    ("d-and-d-beyond", &icons::brands::D_AND_D_BEYOND),
-    ("earlybirds", &icons::brands::EARLYBIRDS),
-    ("ebay", &icons::brands::EBAY),
-    ("edge", &icons::brands::EDGE),
-    ("edge-legacy", &icons::brands::EDGE_LEGACY),
-    ("elementor", &icons::brands::ELEMENTOR),
-    ("eleventy", &icons::brands::ELEVENTY),
-    ("ello", &icons::brands::ELLO),
-    ("ember", &icons::brands::EMBER),
-    ("empire", &icons::brands::EMPIRE),
-    ("envira", &icons::brands::ENVIRA),
-    ("erlang", &icons::brands::ERLANG),
-    ("ethereum", &icons::brands::ETHEREUM),
-    ("etsy", &icons::brands::ETSY),
-    ("evernote", &icons::brands::EVERNOTE),
-    ("expeditedssl", &icons::brands::EXPEDITEDSSL),
-    ("facebook", &icons::brands::FACEBOOK),
-    ("facebook-f", &icons::brands::FACEBOOK_F),
-    ("facebook-messenger", &icons::brands::FACEBOOK_MESSENGER),
-    ("facebook-square", &icons::brands::FACEBOOK_SQUARE),
-    ("fantasy-flight-games", &icons::brands::FANTASY_FLIGHT_GAMES),
-    ("fedex", &icons::brands::FEDEX),
-    ("fedora", &icons::brands::FEDORA),
-    ("figma", &icons::brands::FIGMA),
-    ("files-pinwheel", &icons::brands::FILES_PINWHEEL),
-    ("firefox", &icons::brands::FIREFOX),
-    ("firefox-browser", &icons::brands::FIREFOX_BROWSER),
-    ("firstdraft", &icons::brands::FIRSTDRAFT),
-    ("first-order", &icons::brands::FIRST_ORDER),
-    ("first-order-alt", &icons::brands::FIRST_ORDER_ALT),
-    ("flickr", &icons::brands::FLICKR),
-    ("flipboard", &icons::brands::FLIPBOARD),
-    ("flutter", &icons::brands::FLUTTER),
-    ("fly", &icons::brands::FLY),
-    ("fonticons", &icons::brands::FONTICONS),
-    ("fonticons-fi", &icons::brands::FONTICONS_FI),
-    ("font-awesome", &icons::brands::FONT_AWESOME),
-    ("font-awesome-alt", &icons::brands::FONT_AWESOME_ALT),
-    ("font-awesome-flag", &icons::brands::FONT_AWESOME_FLAG),
-    ("font-awesome-logo-full", &icons::brands::FONT_AWESOME_LOGO_FULL),
-    ("fort-awesome", &icons::brands::FORT_AWESOME),
-    ("fort-awesome-alt", &icons::brands::FORT_AWESOME_ALT),
-    ("forumbee", &icons::brands::FORUMBEE),
-    ("foursquare", &icons::brands::FOURSQUARE),
-    ("freebsd", &icons::brands::FREEBSD),
-    ("free-code-camp", &icons::brands::FREE_CODE_CAMP),
-    ("fulcrum", &icons::brands::FULCRUM),
-    ("galactic-republic", &icons::brands::GALACTIC_REPUBLIC),
-    ("galactic-senate", &icons::brands::GALACTIC_SENATE),
-    ("get-pocket", &icons::brands::GET_POCKET),
-    ("gg", &icons::brands::GG),
-    ("gg-circle", &icons::brands::GG_CIRCLE),
-    ("git", &icons::brands::GIT),
-    ("github", &icons::brands::GITHUB),
-    ("github-alt", &icons::brands::GITHUB_ALT),
-    ("github-square", &icons::brands::GITHUB_SQUARE),
-    ("gitkraken", &icons::brands::GITKRAKEN),
-    ("gitlab", &icons::brands::GITLAB),
-    ("gitlab-square", &icons::brands::GITLAB_SQUARE),
-    ("gitter", &icons::brands::GITTER),
-    ("git-alt", &icons::brands::GIT_ALT),
-    ("git-square", &icons::brands::GIT_SQUARE),
-    ("glide", &icons::brands::GLIDE),
-    ("glide-g", &icons::brands::GLIDE_G),
-    ("gofore", &icons::brands::GOFORE),
-    ("golang", &icons::brands::GOLANG),
-    ("goodreads", &icons::brands::GOODREADS),
-    ("goodreads-g", &icons::brands::GOODREADS_G),
-    ("google", &icons::brands::GOOGLE),
-    ("google-drive", &icons::brands::GOOGLE_DRIVE),
-    ("google-pay", &icons::brands::GOOGLE_PAY),
-    ("google-play", &icons::brands::GOOGLE_PLAY),
-    ("google-plus", &icons::brands::GOOGLE_PLUS),
-    ("google-plus-g", &icons::brands::GOOGLE_PLUS_G),
-    ("google-plus-square", &icons::brands::GOOGLE_PLUS_SQUARE),
-    ("google-scholar", &icons::brands::GOOGLE_SCHOLAR),
-    ("google-wallet", &icons::brands::GOOGLE_WALLET),
-    ("gratipay", &icons::brands::GRATIPAY),
-    ("grav", &icons::brands::GRAV),
-    ("gripfire", &icons::brands::GRIPFIRE),
-    ("grunt", &icons::brands::GRUNT),
-    ("guilded", &icons::brands::GUILDED),
-    ("gulp", &icons::brands::GULP),
-    ("hackerrank", &icons::brands::HACKERRANK),
-    ("hacker-news", &icons::brands::HACKER_NEWS),
-    ("hacker-news-square", &icons::brands::HACKER_NEWS_SQUARE),
-    ("hashnode", &icons::brands::HASHNODE),
-    ("hips", &icons::brands::HIPS),
-    ("hire-a-helper", &icons::brands::HIRE_A_HELPER),
-    ("hive", &icons::brands::HIVE),
-    ("hooli", &icons::brands::HOOLI),
-    ("hornbill", &icons::brands::HORNBILL),
-    ("hotjar", &icons::brands::HOTJAR),
-    ("houzz", &icons::brands::HOUZZ),
-    ("html5", &icons::brands::HTML5),
-    ("hubspot", &icons::brands::HUBSPOT),
-    ("icon-11ty", &icons::brands::ICON_11TY),
-    ("icon-42-group", &icons::brands::ICON_42_GROUP),
-    ("icon-500px", &icons::brands::ICON_500PX),
-    ("ideal", &icons::brands::IDEAL),
-    ("imdb", &icons::brands::IMDB),
-    ("innosoft", &icons::brands::INNOSOFT),
-    ("instagram", &icons::brands::INSTAGRAM),
-    ("instagram-square", &icons::brands::INSTAGRAM_SQUARE),
-    ("instalod", &icons::brands::INSTALOD),
-    ("intercom", &icons::brands::INTERCOM),
-    ("internet-explorer", &icons::brands::INTERNET_EXPLORER),
-    ("invision", &icons::brands::INVISION),
-    ("ioxhost", &icons::brands::IOXHOST),
-    ("itch-io", &icons::brands::ITCH_IO),
-    ("itunes", &icons::brands::ITUNES),
-    ("itunes-note", &icons::brands::ITUNES_NOTE),
-    ("java", &icons::brands::JAVA),
-    ("jedi-order", &icons::brands::JEDI_ORDER),
-    ("jenkins", &icons::brands::JENKINS),
-    ("jira", &icons::brands::JIRA),
-    ("joget", &icons::brands::JOGET),
-    ("joomla", &icons::brands::JOOMLA),
-    ("js", &icons::brands::JS),
-    ("jsfiddle", &icons::brands::JSFIDDLE),
-    ("js-square", &icons::brands::JS_SQUARE),
-    ("jxl", &icons::brands::JXL),
-    ("kaggle", &icons::brands::KAGGLE),
-    ("kakao-talk", &icons::brands::KAKAO_TALK),
-    ("keybase", &icons::brands::KEYBASE),
-    ("keycdn", &icons::brands::KEYCDN),
-    ("kickstarter", &icons::brands::KICKSTARTER),
-    ("kickstarter-k", &icons::brands::KICKSTARTER_K),
-    ("korvue", &icons::brands::KORVUE),
-    ("laravel", &icons::brands::LARAVEL),
-    ("lastfm", &icons::brands::LASTFM),
-    ("lastfm-square", &icons::brands::LASTFM_SQUARE),
-    ("leanpub", &icons::brands::LEANPUB),
-    ("less", &icons::brands::LESS),
-    ("letterboxd", &icons::brands::LETTERBOXD),
-    ("line", &icons::brands::LINE),
-    ("linkedin", &icons::brands::LINKEDIN),
-    ("linkedin-in", &icons::brands::LINKEDIN_IN),
-    ("linktree", &icons::brands::LINKTREE),
-    ("linode", &icons::brands::LINODE),
-    ("linux", &icons::brands::LINUX),
-    ("lumon", &icons::brands::LUMON),
-    ("lumon-drop", &icons::brands::LUMON_DROP),
-    ("lyft", &icons::brands::LYFT),
-    ("magento", &icons::brands::MAGENTO),
-    ("mailchimp", &icons::brands::MAILCHIMP),
-    ("mandalorian", &icons::brands::MANDALORIAN),
-    ("markdown", &icons::brands::MARKDOWN),
-    ("mastodon", &icons::brands::MASTODON),
-    ("maxcdn", &icons::brands::MAXCDN),
-    ("mdb", &icons::brands::MDB),
-    ("medapps", &icons::brands::MEDAPPS),
-    ("medium", &icons::brands::MEDIUM),
-    ("medium-m", &icons::brands::MEDIUM_M),
-    ("medrt", &icons::brands::MEDRT),
-    ("meetup", &icons::brands::MEETUP),
-    ("megaport", &icons::brands::MEGAPORT),
-    ("mendeley", &icons::brands::MENDELEY),
-    ("meta", &icons::brands::META),
-    ("microblog", &icons::brands::MICROBLOG),
-    ("microsoft", &icons::brands::MICROSOFT),
-    ("mintbit", &icons::brands::MINTBIT),
-    ("mix", &icons::brands::MIX),
-    ("mixcloud", &icons::brands::MIXCLOUD),
-    ("mixer", &icons::brands::MIXER),
-    ("mizuni", &icons::brands::MIZUNI),
-    ("modx", &icons::brands::MODX),
-    ("monero", &icons::brands::MONERO),
-    ("napster", &icons::brands::NAPSTER),
-    ("neos", &icons::brands::NEOS),
-    ("nfc-directional", &icons::brands::NFC_DIRECTIONAL),
-    ("nfc-symbol", &icons::brands::NFC_SYMBOL),
-    ("nimblr", &icons::brands::NIMBLR),
-    ("node", &icons::brands::NODE),
-    ("node-js", &icons::brands::NODE_JS),
-    ("notion", &icons::brands::NOTION),
-    ("npm", &icons::brands::NPM),
-    ("ns8", &icons::brands::NS8),
-    ("nutritionix", &icons::brands::NUTRITIONIX),
-    ("octopus-deploy", &icons::brands::OCTOPUS_DEPLOY),
-    ("odnoklassniki", &icons::brands::ODNOKLASSNIKI),
-    ("odnoklassniki-square", &icons::brands::ODNOKLASSNIKI_SQUARE),
-    ("odysee", &icons::brands::ODYSEE),
-    ("old-republic", &icons::brands::OLD_REPUBLIC),
-    ("openai", &icons::brands::OPENAI),
-    ("opencart", &icons::brands::OPENCART),
-    ("openid", &icons::brands::OPENID),
-    ("opensuse", &icons::brands::OPENSUSE),
-    ("opera", &icons::brands::OPERA),
-    ("optin-monster", &icons::brands::OPTIN_MONSTER),
-    ("orcid", &icons::brands::ORCID),
-    ("osi", &icons::brands::OSI),
-    ("padlet", &icons::brands::PADLET),
-    ("page4", &icons::brands::PAGE4),
-    ("pagelines", &icons::brands::PAGELINES),
-    ("palfed", &icons::brands::PALFED),
-    ("pandora", &icons::brands::PANDORA),
-    ("patreon", &icons::brands::PATREON),
-    ("paypal", &icons::brands::PAYPAL),
-    ("perbyte", &icons::brands::PERBYTE),
-    ("periscope", &icons::brands::PERISCOPE),
-    ("phabricator", &icons::brands::PHABRICATOR),
-    ("phoenix-framework", &icons::brands::PHOENIX_FRAMEWORK),
-    ("phoenix-squadron", &icons::brands::PHOENIX_SQUADRON),
-    ("php", &icons::brands::PHP),
-    ("pied-piper", &icons::brands::PIED_PIPER),
-    ("pied-piper-alt", &icons::brands::PIED_PIPER_ALT),
-    ("pied-piper-hat", &icons::brands::PIED_PIPER_HAT),
-    ("pied-piper-pp", &icons::brands::PIED_PIPER_PP),
-    ("pied-piper-square", &icons::brands::PIED_PIPER_SQUARE),
-    ("pinterest", &icons::brands::PINTEREST),
-    ("pinterest-p", &icons::brands::PINTEREST_P),
-    ("pinterest-square", &icons::brands::PINTEREST_SQUARE),
-    ("pix", &icons::brands::PIX),
-    ("pixelfed", &icons::brands::PIXELFED),
-    ("pixiv", &icons::brands::PIXIV),
-    ("playstation", &icons::brands::PLAYSTATION),
-    ("product-hunt", &icons::brands::PRODUCT_HUNT),
-    ("pushed", &icons::brands::PUSHED),
-    ("python", &icons::brands::PYTHON),
-    ("qq", &icons::brands::QQ),
-    ("quinscape", &icons::brands::QUINSCAPE),
-    ("quora", &icons::brands::QUORA),
-    ("raspberry-pi", &icons::brands::RASPBERRY_PI),
-    ("ravelry", &icons::brands::RAVELRY),
-    ("react", &icons::brands::REACT),
-    ("reacteurope", &icons::brands::REACTEUROPE),
-    ("readme", &icons::brands::README),
-    ("rebel", &icons::brands::REBEL),
-    ("reddit", &icons::brands::REDDIT),
-    ("reddit-alien", &icons::brands::REDDIT_ALIEN),
-    ("reddit-square", &icons::brands::REDDIT_SQUARE),
-    ("redhat", &icons::brands::REDHAT),
-    ("red-river", &icons::brands::RED_RIVER),
-    ("rendact", &icons::brands::RENDACT),
-    ("renren", &icons::brands::RENREN),
-    ("replyd", &icons::brands::REPLYD),
-    ("researchgate", &icons::brands::RESEARCHGATE),
-    ("resolving", &icons::brands::RESOLVING),
-    ("rev", &icons::brands::REV),
-    ("rocketchat", &icons::brands::ROCKETCHAT),
-    ("rockrms", &icons::brands::ROCKRMS),
-    ("rust", &icons::brands::RUST),
-    ("r-project", &icons::brands::R_PROJECT),
-    ("safari", &icons::brands::SAFARI),
-    ("salesforce", &icons::brands::SALESFORCE),
-    ("sass", &icons::brands::SASS),
-    ("schlix", &icons::brands::SCHLIX),
-    ("screenpal", &icons::brands::SCREENPAL),
-    ("scribd", &icons::brands::SCRIBD),
-    ("searchengin", &icons::brands::SEARCHENGIN),
-    ("sellcast", &icons::brands::SELLCAST),
-    ("sellsy", &icons::brands::SELLSY),
-    ("servicestack", &icons::brands::SERVICESTACK),
-    ("shirtsinbulk", &icons::brands::SHIRTSINBULK),
-    ("shoelace", &icons::brands::SHOELACE),
-    ("shopify", &icons::brands::SHOPIFY),
-    ("shopware", &icons::brands::SHOPWARE),
-    ("signal-messenger", &icons::brands::SIGNAL_MESSENGER),
-    ("simplybuilt", &icons::brands::SIMPLYBUILT),
-    ("sistrix", &icons::brands::SISTRIX),
-    ("sith", &icons::brands::SITH),
-    ("sitrox", &icons::brands::SITROX),
-    ("sketch", &icons::brands::SKETCH),
-    ("skyatlas", &icons::brands::SKYATLAS),
-    ("skype", &icons::brands::SKYPE),
-    ("slack", &icons::brands::SLACK),
-    ("slack-hash", &icons::brands::SLACK_HASH),
-    ("slideshare", &icons::brands::SLIDESHARE),
-    ("snapchat", &icons::brands::SNAPCHAT),
-    ("snapchat-ghost", &icons::brands::SNAPCHAT_GHOST),
-    ("snapchat-square", &icons::brands::SNAPCHAT_SQUARE),
-    ("soundcloud", &icons::brands::SOUNDCLOUD),
-    ("sourcetree", &icons::brands::SOURCETREE),
-    ("space-awesome", &icons::brands::SPACE_AWESOME),
-    ("speakap", &icons::brands::SPEAKAP),
-    ("speaker-deck", &icons::brands::SPEAKER_DECK),
-    ("spotify", &icons::brands::SPOTIFY),
-    ("squarespace", &icons::brands::SQUARESPACE),
-    ("square-behance", &icons::brands::SQUARE_BEHANCE),
-    ("square-bluesky", &icons::brands::SQUARE_BLUESKY),
-    ("square-dribbble", &icons::brands::SQUARE_DRIBBBLE),
-    ("square-facebook", &icons::brands::SQUARE_FACEBOOK),
-    ("square-figma", &icons::brands::SQUARE_FIGMA),
-    ("square-font-awesome", &icons::brands::SQUARE_FONT_AWESOME),
-    ("square-font-awesome-stroke", &icons::brands::SQUARE_FONT_AWESOME_STROKE),
-    ("square-git", &icons::brands::SQUARE_GIT),
-    ("square-github", &icons::brands::SQUARE_GITHUB),
-    ("square-gitlab", &icons::brands::SQUARE_GITLAB),
-    ("square-google-plus", &icons::brands::SQUARE_GOOGLE_PLUS),
-    ("square-hacker-news", &icons::brands::SQUARE_HACKER_NEWS),
-    ("square-instagram", &icons::brands::SQUARE_INSTAGRAM),
-    ("square-js", &icons::brands::SQUARE_JS),
-    ("square-kickstarter", &icons::brands::SQUARE_KICKSTARTER),
-    ("square-lastfm", &icons::brands::SQUARE_LASTFM),
-    ("square-letterboxd", &icons::brands::SQUARE_LETTERBOXD),
-    ("square-linkedin", &icons::brands::SQUARE_LINKEDIN),
-    ("square-odnoklassniki", &icons::brands::SQUARE_ODNOKLASSNIKI),
-    ("square-pied-piper", &icons::brands::SQUARE_PIED_PIPER),
-    ("square-pinterest", &icons::brands::SQUARE_PINTEREST),
-    ("square-reddit", &icons::brands::SQUARE_REDDIT),
-    ("square-snapchat", &icons::brands::SQUARE_SNAPCHAT),
-    ("square-steam", &icons::brands::SQUARE_STEAM),
-    ("square-threads", &icons::brands::SQUARE_THREADS),
-    ("square-tumblr", &icons::brands::SQUARE_TUMBLR),
-    ("square-twitter", &icons::brands::SQUARE_TWITTER),
-    ("square-upwork", &icons::brands::SQUARE_UPWORK),
-    ("square-viadeo", &icons::brands::SQUARE_VIADEO),
-    ("square-vimeo", &icons::brands::SQUARE_VIMEO),
-    ("square-web-awesome", &icons::brands::SQUARE_WEB_AWESOME),
-    ("square-web-awesome-stroke", &icons::brands::SQUARE_WEB_AWESOME_STROKE),
-    ("square-whatsapp", &icons::brands::SQUARE_WHATSAPP),
-    ("square-xing", &icons::brands::SQUARE_XING),
-    ("square-x-twitter", &icons::brands::SQUARE_X_TWITTER),
-    ("square-youtube", &icons::brands::SQUARE_YOUTUBE),
-    ("stackpath", &icons::brands::STACKPATH),
-    ("stack-exchange", &icons::brands::STACK_EXCHANGE),
-    ("stack-overflow", &icons::brands::STACK_OVERFLOW),
-    ("staylinked", &icons::brands::STAYLINKED),
-    ("steam", &icons::brands::STEAM),
-    ("steam-square", &icons::brands::STEAM_SQUARE),
-    ("steam-symbol", &icons::brands::STEAM_SYMBOL),
-    ("sticker-mule", &icons::brands::STICKER_MULE),
-    ("strava", &icons::brands::STRAVA),
-    ("stripe", &icons::brands::STRIPE),
-    ("stripe-s", &icons::brands::STRIPE_S),
-    ("stubber", &icons::brands::STUBBER),
-    ("studiovinari", &icons::brands::STUDIOVINARI),
-    ("stumbleupon", &icons::brands::STUMBLEUPON),
-    ("stumbleupon-circle", &icons::brands::STUMBLEUPON_CIRCLE),
-    ("superpowers", &icons::brands::SUPERPOWERS),
-    ("supple", &icons::brands::SUPPLE),
-    ("suse", &icons::brands::SUSE),
-    ("swift", &icons::brands::SWIFT),
-    ("symfony", &icons::brands::SYMFONY),
-    ("teamspeak", &icons::brands::TEAMSPEAK),
-    ("telegram", &icons::brands::TELEGRAM),
-    ("telegram-plane", &icons::brands::TELEGRAM_PLANE),
-    ("tencent-weibo", &icons::brands::TENCENT_WEIBO),
-    ("tex", &icons::brands::TEX),
-    ("themeco", &icons::brands::THEMECO),
-    ("themeisle", &icons::brands::THEMEISLE),
-    ("the-red-yeti", &icons::brands::THE_RED_YETI),
-    ("think-peaks", &icons::brands::THINK_PEAKS),
-    ("threads", &icons::brands::THREADS),
-    ("tidal", &icons::brands::TIDAL),
-    ("tiktok", &icons::brands::TIKTOK),
-    ("trade-federation", &icons::brands::TRADE_FEDERATION),
-    ("trello", &icons::brands::TRELLO),
-    ("tumblr", &icons::brands::TUMBLR),
-    ("tumblr-square", &icons::brands::TUMBLR_SQUARE),
-    ("twitch", &icons::brands::TWITCH),
-    ("twitter", &icons::brands::TWITTER),
-    ("twitter-square", &icons::brands::TWITTER_SQUARE),
-    ("typo3", &icons::brands::TYPO3),
-    ("uber", &icons::brands::UBER),
-    ("ubuntu", &icons::brands::UBUNTU),
-    ("uikit", &icons::brands::UIKIT),
-    ("umbraco", &icons::brands::UMBRACO),
-    ("uncharted", &icons::brands::UNCHARTED),
-    ("uniregistry", &icons::brands::UNIREGISTRY),
-    ("unity", &icons::brands::UNITY),
-    ("unsplash", &icons::brands::UNSPLASH),
-    ("untappd", &icons::brands::UNTAPPD),
-    ("ups", &icons::brands::UPS),
-    ("upwork", &icons::brands::UPWORK),
-    ("usb", &icons::brands::USB),
-    ("usps", &icons::brands::USPS),
-    ("ussunnah", &icons::brands::USSUNNAH),
-    ("vaadin", &icons::brands::VAADIN),
-    ("viacoin", &icons::brands::VIACOIN),
-    ("viadeo", &icons::brands::VIADEO),
-    ("viadeo-square", &icons::brands::VIADEO_SQUARE),
-    ("viber", &icons::brands::VIBER),
-    ("vimeo", &icons::brands::VIMEO),
-    ("vimeo-square", &icons::brands::VIMEO_SQUARE),
-    ("vimeo-v", &icons::brands::VIMEO_V),
-    ("vine", &icons::brands::VINE),
-    ("vk", &icons::brands::VK),
-    ("vnv", &icons::brands::VNV),
-    ("vsco", &icons::brands::VSCO),
-    ("vuejs", &icons::brands::VUEJS),
-    ("w3c", &icons::brands::W3C),
-    ("watchman-monitoring", &icons::brands::WATCHMAN_MONITORING),
-    ("waze", &icons::brands::WAZE),
-    ("webflow", &icons::brands::WEBFLOW),
-    ("web-awesome", &icons::brands::WEB_AWESOME),
-    ("weebly", &icons::brands::WEEBLY),
-    ("weibo", &icons::brands::WEIBO),
-    ("weixin", &icons::brands::WEIXIN),
-    ("whatsapp", &icons::brands::WHATSAPP),
-    ("whatsapp-square", &icons::brands::WHATSAPP_SQUARE),
-    ("whmcs", &icons::brands::WHMCS),
-    ("wikipedia-w", &icons::brands::WIKIPEDIA_W),
-    ("windows", &icons::brands::WINDOWS),
-    ("wirsindhandwerk", &icons::brands::WIRSINDHANDWERK),
-    ("wix", &icons::brands::WIX),
-    ("wizards-of-the-coast", &icons::brands::WIZARDS_OF_THE_COAST),
-    ("wodu", &icons::brands::WODU),
-    ("wolf-pack-battalion", &icons::brands::WOLF_PACK_BATTALION),
-    ("wordpress", &icons::brands::WORDPRESS),
-    ("wordpress-simple", &icons::brands::WORDPRESS_SIMPLE),
-    ("wpbeginner", &icons::brands::WPBEGINNER),
-    ("wpexplorer", &icons::brands::WPEXPLORER),
-    ("wpforms", &icons::brands::WPFORMS),
-    ("wpressr", &icons::brands::WPRESSR),
-    ("wsh", &icons::brands::WSH),
-    ("xbox", &icons::brands::XBOX),
-    ("xing", &icons::brands::XING),
-    ("xing-square", &icons::brands::XING_SQUARE),
-    ("x-twitter", &icons::brands::X_TWITTER),
-    ("yahoo", &icons::brands::YAHOO),
-    ("yammer", &icons::brands::YAMMER),
-    ("yandex", &icons::brands::YANDEX),
-    ("yandex-international", &icons::brands::YANDEX_INTERNATIONAL),
-    ("yarn", &icons::brands::YARN),
-    ("yelp", &icons::brands::YELP),
-    ("yoast", &icons::brands::YOAST),
-    ("youtube", &icons::brands::YOUTUBE),
-    ("youtube-square", &icons::brands::YOUTUBE_SQUARE),
-    ("y-combinator", &icons::brands::Y_COMBINATOR),
-    ("zhihu", &icons::brands::ZHIHU),
-];
-
-pub const REGULAR_ICONS: &[(&str, &Icon)] = &[
-    ("address-book", &icons::regular::ADDRESS_BOOK),
-    ("address-card", &icons::regular::ADDRESS_CARD),
-    ("alarm-clock", &icons::regular::ALARM_CLOCK),
-    ("angry", &icons::regular::ANGRY),
-    ("arrow-alt-circle-down", &icons::regular::ARROW_ALT_CIRCLE_DOWN),
-    ("arrow-alt-circle-left", &icons::regular::ARROW_ALT_CIRCLE_LEFT),
-    ("arrow-alt-circle-right", &icons::regular::ARROW_ALT_CIRCLE_RIGHT),
-    ("arrow-alt-circle-up", &icons::regular::ARROW_ALT_CIRCLE_UP),
-    ("bar-chart", &icons::regular::BAR_CHART),
-    ("bell", &icons::regular::BELL),
-    ("bell-slash", &icons::regular::BELL_SLASH),
-    ("bookmark", &icons::regular::BOOKMARK),
-    ("building", &icons::regular::BUILDING),
-    ("calendar", &icons::regular::CALENDAR),
-    ("calendar-alt", &icons::regular::CALENDAR_ALT),
-    ("calendar-check", &icons::regular::CALENDAR_CHECK),
-    ("calendar-days", &icons::regular::CALENDAR_DAYS),
-    ("calendar-minus", &icons::regular::CALENDAR_MINUS),
-    ("calendar-plus", &icons::regular::CALENDAR_PLUS),
-    ("calendar-times", &icons::regular::CALENDAR_TIMES),
-    ("calendar-xmark", &icons::regular::CALENDAR_XMARK),
-    ("camera", &icons::regular::CAMERA),
-    ("camera-alt", &icons::regular::CAMERA_ALT),
-    ("caret-square-down", &icons::regular::CARET_SQUARE_DOWN),
-    ("caret-square-left", &icons::regular::CARET_SQUARE_LEFT),
-    ("caret-square-right", &icons::regular::CARET_SQUARE_RIGHT),
-    ("caret-square-up", &icons::regular::CARET_SQUARE_UP),
-    ("chart-bar", &icons::regular::CHART_BAR),
-    ("check-circle", &icons::regular::CHECK_CIRCLE),
-    ("check-square", &icons::regular::CHECK_SQUARE),
-    ("chess-bishop", &icons::regular::CHESS_BISHOP),
-    ("chess-king", &icons::regular::CHESS_KING),
-    ("chess-knight", &icons::regular::CHESS_KNIGHT),
-    ("chess-pawn", &icons::regular::CHESS_PAWN),
-    ("chess-queen", &icons::regular::CHESS_QUEEN),
-    ("chess-rook", &icons::regular::CHESS_ROOK),
-    ("circle", &icons::regular::CIRCLE),
-    ("circle-check", &icons::regular::CIRCLE_CHECK),
-    ("circle-dot", &icons::regular::CIRCLE_DOT),
-    ("circle-down", &icons::regular::CIRCLE_DOWN),
-    ("circle-left", &icons::regular::CIRCLE_LEFT),
-    ("circle-pause", &icons::regular::CIRCLE_PAUSE),
-    ("circle-play", &icons::regular::CIRCLE_PLAY),
-    ("circle-question", &icons::regular::CIRCLE_QUESTION),
-    ("circle-right", &icons::regular::CIRCLE_RIGHT),
-    ("circle-stop", &icons::regular::CIRCLE_STOP),
-    ("circle-up", &icons::regular::CIRCLE_UP),
-    ("circle-user", &icons::regular::CIRCLE_USER),
-    ("circle-xmark", &icons::regular::CIRCLE_XMARK),
-    ("clipboard", &icons::regular::CLIPBOARD),
-    ("clock", &icons::regular::CLOCK),
-    ("clock-four", &icons::regular::CLOCK_FOUR),
-    ("clone", &icons::regular::CLONE),
-    ("closed-captioning", &icons::regular::CLOSED_CAPTIONING),
-    ("cloud", &icons::regular::CLOUD),
-    ("comment", &icons::regular::COMMENT),
-    ("commenting", &icons::regular::COMMENTING),
-    ("comments", &icons::regular::COMMENTS),
-    ("comment-alt", &icons::regular::COMMENT_ALT),
-    ("comment-dots", &icons::regular::COMMENT_DOTS),
-    ("compass", &icons::regular::COMPASS),
-    ("contact-book", &icons::regular::CONTACT_BOOK),
-    ("contact-card", &icons::regular::CONTACT_CARD),
-    ("copy", &icons::regular::COPY),
-    ("copyright", &icons::regular::COPYRIGHT),
-    ("credit-card", &icons::regular::CREDIT_CARD),
-    ("credit-card-alt", &icons::regular::CREDIT_CARD_ALT),
-    ("dizzy", &icons::regular::DIZZY),
-    ("dot-circle", &icons::regular::DOT_CIRCLE),
-    ("drivers-license", &icons::regular::DRIVERS_LICENSE),
-    ("edit", &icons::regular::EDIT),
-    ("envelope", &icons::regular::ENVELOPE),
-    ("envelope-open", &icons::regular::ENVELOPE_OPEN),
-    ("eye", &icons::regular::EYE),
-    ("eye-slash", &icons::regular::EYE_SLASH),
-    ("face-angry", &icons::regular::FACE_ANGRY),
-    ("face-dizzy", &icons::regular::FACE_DIZZY),
-    ("face-flushed", &icons::regular::FACE_FLUSHED),
-    ("face-frown", &icons::regular::FACE_FROWN),
-    ("face-frown-open", &icons::regular::FACE_FROWN_OPEN),
-    ("face-grimace", &icons::regular::FACE_GRIMACE),
-    ("face-grin", &icons::regular::FACE_GRIN),
-    ("face-grin-beam", &icons::regular::FACE_GRIN_BEAM),
-    ("face-grin-beam-sweat", &icons::regular::FACE_GRIN_BEAM_SWEAT),
-    ("face-grin-hearts", &icons::regular::FACE_GRIN_HEARTS),
-    ("face-grin-squint", &icons::regular::FACE_GRIN_SQUINT),
-    ("face-grin-squint-tears", &icons::regular::FACE_GRIN_SQUINT_TEARS),
-    ("face-grin-stars", &icons::regular::FACE_GRIN_STARS),
-    ("face-grin-tears", &icons::regular::FACE_GRIN_TEARS),
-    ("face-grin-tongue", &icons::regular::FACE_GRIN_TONGUE),
-    ("face-grin-tongue-squint", &icons::regular::FACE_GRIN_TONGUE_SQUINT),
-    ("face-grin-tongue-wink", &icons::regular::FACE_GRIN_TONGUE_WINK),
-    ("face-grin-wide", &icons::regular::FACE_GRIN_WIDE),
-    ("face-grin-wink", &icons::regular::FACE_GRIN_WINK),
-    ("face-kiss", &icons::regular::FACE_KISS),
-    ("face-kiss-beam", &icons::regular::FACE_KISS_BEAM),
-    ("face-kiss-wink-heart", &icons::regular::FACE_KISS_WINK_HEART),
-    ("face-laugh", &icons::regular::FACE_LAUGH),
-    ("face-laugh-beam", &icons::regular::FACE_LAUGH_BEAM),
-    ("face-laugh-squint", &icons::regular::FACE_LAUGH_SQUINT),
-    ("face-laugh-wink", &icons::regular::FACE_LAUGH_WINK),
-    ("face-meh", &icons::regular::FACE_MEH),
-    ("face-meh-blank", &icons::regular::FACE_MEH_BLANK),
-    ("face-rolling-eyes", &icons::regular::FACE_ROLLING_EYES),
-    ("face-sad-cry", &icons::regular::FACE_SAD_CRY),
-    ("face-sad-tear", &icons::regular::FACE_SAD_TEAR),
-    ("face-smile", &icons::regular::FACE_SMILE),
-    ("face-smile-beam", &icons::regular::FACE_SMILE_BEAM),
-    ("face-smile-wink", &icons::regular::FACE_SMILE_WINK),
-    ("face-surprise", &icons::regular::FACE_SURPRISE),
-    ("face-tired", &icons::regular::FACE_TIRED),
-    ("file", &icons::regular::FILE),
-    ("file-alt", &icons::regular::FILE_ALT),
-    ("file-archive", &icons::regular::FILE_ARCHIVE),
-    ("file-audio", &icons::regular::FILE_AUDIO),
-    ("file-clipboard", &icons::regular::FILE_CLIPBOARD),
-    ("file-code", &icons::regular::FILE_CODE),
-    ("file-excel", &icons::regular::FILE_EXCEL),
-    ("file-image", &icons::regular::FILE_IMAGE),
-    ("file-lines", &icons::regular::FILE_LINES),
-    ("file-pdf", &icons::regular::FILE_PDF),
-    ("file-powerpoint", &icons::regular::FILE_POWERPOINT),
-    ("file-text", &icons::regular::FILE_TEXT),
-    ("file-video", &icons::regular::FILE_VIDEO),
-    ("file-word", &icons::regular::FILE_WORD),
-    ("file-zipper", &icons::regular::FILE_ZIPPER),
-    ("flag", &icons::regular::FLAG),
-    ("floppy-disk", &icons::regular::FLOPPY_DISK),
-    ("flushed", &icons::regular::FLUSHED),
-    ("folder", &icons::regular::FOLDER),
-    ("folder-blank", &icons::regular::FOLDER_BLANK),
-    ("folder-closed", &icons::regular::FOLDER_CLOSED),
-    ("folder-open", &icons::regular::FOLDER_OPEN),
-    ("font-awesome", &icons::regular::FONT_AWESOME),
-    ("font-awesome-flag", &icons::regular::FONT_AWESOME_FLAG),
-    ("font-awesome-logo-full", &icons::regular::FONT_AWESOME_LOGO_FULL),
-    ("frown", &icons::regular::FROWN),
-    ("frown-open", &icons::regular::FROWN_OPEN),
-    ("futbol", &icons::regular::FUTBOL),
-    ("futbol-ball", &icons::regular::FUTBOL_BALL),
-    ("gem", &icons::regular::GEM),
-    ("grimace", &icons::regular::GRIMACE),
-    ("grin", &icons::regular::GRIN),
-    ("grin-alt", &icons::regular::GRIN_ALT),
-    ("grin-beam", &icons::regular::GRIN_BEAM),
-    ("grin-beam-sweat", &icons::regular::GRIN_BEAM_SWEAT),
-    ("grin-hearts", &icons::regular::GRIN_HEARTS),
-    ("grin-squint", &icons::regular::GRIN_SQUINT),
-    ("grin-squint-tears", &icons::regular::GRIN_SQUINT_TEARS),
-    ("grin-stars", &icons::regular::GRIN_STARS),
-    ("grin-tears", &icons::regular::GRIN_TEARS),
-    ("grin-tongue", &icons::regular::GRIN_TONGUE),
-    ("grin-tongue-squint", &icons::regular::GRIN_TONGUE_SQUINT),
-    ("grin-tongue-wink", &icons::regular::GRIN_TONGUE_WINK),
-    ("grin-wink", &icons::regular::GRIN_WINK),
-    ("hand", &icons::regular::HAND),
-    ("handshake", &icons::regular::HANDSHAKE),
-    ("handshake-alt", &icons::regular::HANDSHAKE_ALT),
-    ("handshake-simple", &icons::regular::HANDSHAKE_SIMPLE),
-    ("hand-back-fist", &icons::regular::HAND_BACK_FIST),
-    ("hand-lizard", &icons::regular::HAND_LIZARD),
-    ("hand-paper", &icons::regular::HAND_PAPER),
-    ("hand-peace", &icons::regular::HAND_PEACE),
-    ("hand-pointer", &icons::regular::HAND_POINTER),
-    ("hand-point-down", &icons::regular::HAND_POINT_DOWN),
-    ("hand-point-left", &icons::regular::HAND_POINT_LEFT),
-    ("hand-point-right", &icons::regular::HAND_POINT_RIGHT),
-    ("hand-point-up", &icons::regular::HAND_POINT_UP),
-    ("hand-rock", &icons::regular::HAND_ROCK),
-    ("hand-scissors", &icons::regular::HAND_SCISSORS),
-    ("hand-spock", &icons::regular::HAND_SPOCK),
-    ("hard-drive", &icons::regular::HARD_DRIVE),
-    ("hdd", &icons::regular::HDD),
-    ("headphones", &icons::regular::HEADPHONES),
-    ("headphones-alt", &icons::regular::HEADPHONES_ALT),
-    ("headphones-simple", &icons::regular::HEADPHONES_SIMPLE),
-    ("heart", &icons::regular::HEART),
-    ("home", &icons::regular::HOME),
-    ("home-alt", &icons::regular::HOME_ALT),
-    ("home-lg-alt", &icons::regular::HOME_LG_ALT),
-    ("hospital", &icons::regular::HOSPITAL),
-    ("hospital-alt", &icons::regular::HOSPITAL_ALT),
-    ("hospital-wide", &icons::regular::HOSPITAL_WIDE),
-    ("hourglass", &icons::regular::HOURGLASS),
-    ("hourglass-2", &icons::regular::HOURGLASS_2),
-    ("hourglass-empty", &icons::regular::HOURGLASS_EMPTY),
-    ("hourglass-half", &icons::regular::HOURGLASS_HALF),
-    ("house", &icons::regular::HOUSE),
-    ("id-badge", &icons::regular::ID_BADGE),
-    ("id-card", &icons::regular::ID_CARD),
-    ("image", &icons::regular::IMAGE),
-    ("images", &icons::regular::IMAGES),
-    ("keyboard", &icons::regular::KEYBOARD),
-    ("kiss", &icons::regular::KISS),
-    ("kiss-beam", &icons::regular::KISS_BEAM),
-    ("kiss-wink-heart", &icons::regular::KISS_WINK_HEART),
-    ("laugh", &icons::regular::LAUGH),
-    ("laugh-beam", &icons::regular::LAUGH_BEAM),
-    ("laugh-squint", &icons::regular::LAUGH_SQUINT),
-    ("laugh-wink", &icons::regular::LAUGH_WINK),
-    ("lemon", &icons::regular::LEMON),
-    ("life-ring", &icons::regular::LIFE_RING),
-    ("lightbulb", &icons::regular::LIGHTBULB),
-    ("list-alt", &icons::regular::LIST_ALT),
-    ("map", &icons::regular::MAP),
-    ("meh", &icons::regular::MEH),
-    ("meh-blank", &icons::regular::MEH_BLANK),
-    ("meh-rolling-eyes", &icons::regular::MEH_ROLLING_EYES),
-    ("message", &icons::regular::MESSAGE),
-    ("minus-square", &icons::regular::MINUS_SQUARE),
-    ("money-bill-1", &icons::regular::MONEY_BILL_1),
-    ("money-bill-alt", &icons::regular::MONEY_BILL_ALT),
-    ("moon", &icons::regular::MOON),
-    ("newspaper", &icons::regular::NEWSPAPER),
-    ("note-sticky", &icons::regular::NOTE_STICKY),
-    ("object-group", &icons::regular::OBJECT_GROUP),
-    ("object-ungroup", &icons::regular::OBJECT_UNGROUP),
-    ("paper-plane", &icons::regular::PAPER_PLANE),
-    ("paste", &icons::regular::PASTE),
-    ("pause-circle", &icons::regular::PAUSE_CIRCLE),
-    ("pen-to-square", &icons::regular::PEN_TO_SQUARE),
-    ("play-circle", &icons::regular::PLAY_CIRCLE),
-    ("plus-square", &icons::regular::PLUS_SQUARE),
-    ("question-circle", &icons::regular::QUESTION_CIRCLE),
-    ("rectangle-list", &icons::regular::RECTANGLE_LIST),
-    ("rectangle-times", &icons::regular::RECTANGLE_TIMES),
-    ("rectangle-xmark", &icons::regular::RECTANGLE_XMARK),
-    ("registered", &icons::regular::REGISTERED),
-    ("sad-cry", &icons::regular::SAD_CRY),
-    ("sad-tear", &icons::regular::SAD_TEAR),
-    ("save", &icons::regular::SAVE),
-    ("share-from-square", &icons::regular::SHARE_FROM_SQUARE),
-    ("share-square", &icons::regular::SHARE_SQUARE),
-    ("smile", &icons::regular::SMILE),
-    ("smile-beam", &icons::regular::SMILE_BEAM),
-    ("smile-wink", &icons::regular::SMILE_WINK),
-    ("snowflake", &icons::regular::SNOWFLAKE),
-    ("soccer-ball", &icons::regular::SOCCER_BALL),
-    ("square", &icons::regular::SQUARE),
-    ("square-caret-down", &icons::regular::SQUARE_CARET_DOWN),
-    ("square-caret-left", &icons::regular::SQUARE_CARET_LEFT),
-    ("square-caret-right", &icons::regular::SQUARE_CARET_RIGHT),
-    ("square-caret-up", &icons::regular::SQUARE_CARET_UP),
-    ("square-check", &icons::regular::SQUARE_CHECK),
-    ("square-full", &icons::regular::SQUARE_FULL),
-    ("square-minus", &icons::regular::SQUARE_MINUS),
-    ("square-plus", &icons::regular::SQUARE_PLUS),
-    ("star", &icons::regular::STAR),
-    ("star-half", &icons::regular::STAR_HALF),
-    ("star-half-alt", &icons::regular::STAR_HALF_ALT),
-    ("star-half-stroke", &icons::regular::STAR_HALF_STROKE),
-    ("sticky-note", &icons::regular::STICKY_NOTE),
-    ("stop-circle", &icons::regular::STOP_CIRCLE),
-    ("sun", &icons::regular::SUN),
-    ("surprise", &icons::regular::SURPRISE),
-    ("thumbs-down", &icons::regular::THUMBS_DOWN),
-    ("thumbs-up", &icons::regular::THUMBS_UP),
-    ("times-circle", &icons::regular::TIMES_CIRCLE),
-    ("times-rectangle", &icons::regular::TIMES_RECTANGLE),
-    ("tired", &icons::regular::TIRED),
-    ("trash-alt", &icons::regular::TRASH_ALT),
-    ("trash-can", &icons::regular::TRASH_CAN),
-    ("truck", &icons::regular::TRUCK),
-    ("user", &icons::regular::USER),
-    ("user-alt", &icons::regular::USER_ALT),
-    ("user-circle", &icons::regular::USER_CIRCLE),
-    ("user-large", &icons::regular::USER_LARGE),
-    ("vcard", &icons::regular::VCARD),
-    ("window-close", &icons::regular::WINDOW_CLOSE),
-    ("window-maximize", &icons::regular::WINDOW_MAXIMIZE),
-    ("window-minimize", &icons::regular::WINDOW_MINIMIZE),
-    ("window-restore", &icons::regular::WINDOW_RESTORE),
-    ("xmark-circle", &icons::regular::XMARK_CIRCLE),
-];
-
-pub const SOLID_ICONS: &[(&str, &Icon)] = &[
-    ("a", &icons::solid::A),
-    ("ad", &icons::solid::AD),
-    ("add", &icons::solid::ADD),
-    ("address-book", &icons::solid::ADDRESS_BOOK),
-    ("address-card", &icons::solid::ADDRESS_CARD),
-    ("adjust", &icons::solid::ADJUST),
-    ("air-freshener", &icons::solid::AIR_FRESHENER),
-    ("alarm-clock", &icons::solid::ALARM_CLOCK),
-    ("align-center", &icons::solid::ALIGN_CENTER),
-    ("align-justify", &icons::solid::ALIGN_JUSTIFY),
-    ("align-left", &icons::solid::ALIGN_LEFT),
-    ("align-right", &icons::solid::ALIGN_RIGHT),
-    ("allergies", &icons::solid::ALLERGIES),
-    ("ambulance", &icons::solid::AMBULANCE),
-    ("american-sign-language-interpreting", &icons::solid::AMERICAN_SIGN_LANGUAGE_INTERPRETING),
-    ("anchor", &icons::solid::ANCHOR),
-    ("anchor-circle-check", &icons::solid::ANCHOR_CIRCLE_CHECK),
-    ("anchor-circle-exclamation", &icons::solid::ANCHOR_CIRCLE_EXCLAMATION),
-    ("anchor-circle-xmark", &icons::solid::ANCHOR_CIRCLE_XMARK),
-    ("anchor-lock", &icons::solid::ANCHOR_LOCK),
-    ("angles-down", &icons::solid::ANGLES_DOWN),
-    ("angles-left", &icons::solid::ANGLES_LEFT),
-    ("angles-right", &icons::solid::ANGLES_RIGHT),
-    ("angles-up", &icons::solid::ANGLES_UP),
-    ("angle-double-down", &icons::solid::ANGLE_DOUBLE_DOWN),
-    ("angle-double-left", &icons::solid::ANGLE_DOUBLE_LEFT),
-    ("angle-double-right", &icons::solid::ANGLE_DOUBLE_RIGHT),
-    ("angle-double-up", &icons::solid::ANGLE_DOUBLE_UP),
-    ("angle-down", &icons::solid::ANGLE_DOWN),
-    ("angle-left", &icons::solid::ANGLE_LEFT),
-    ("angle-right", &icons::solid::ANGLE_RIGHT),
-    ("angle-up", &icons::solid::ANGLE_UP),
-    ("angry", &icons::solid::ANGRY),
-    ("ankh", &icons::solid::ANKH),
-    ("apple-alt", &icons::solid::APPLE_ALT),
-    ("apple-whole", &icons::solid::APPLE_WHOLE),
-    ("archive", &icons::solid::ARCHIVE),
-    ("archway", &icons::solid::ARCHWAY),
-    ("area-chart", &icons::solid::AREA_CHART),
-    ("arrows", &icons::solid::ARROWS),
-    ("arrows-alt", &icons::solid::ARROWS_ALT),
-    ("arrows-alt-h", &icons::solid::ARROWS_ALT_H),
-    ("arrows-alt-v", &icons::solid::ARROWS_ALT_V),
-    ("arrows-down-to-line", &icons::solid::ARROWS_DOWN_TO_LINE),
-    ("arrows-down-to-people", &icons::solid::ARROWS_DOWN_TO_PEOPLE),
-    ("arrows-h", &icons::solid::ARROWS_H),
-    ("arrows-left-right", &icons::solid::ARROWS_LEFT_RIGHT),
-    ("arrows-left-right-to-line", &icons::solid::ARROWS_LEFT_RIGHT_TO_LINE),
-    ("arrows-rotate", &icons::solid::ARROWS_ROTATE),
-    ("arrows-spin", &icons::solid::ARROWS_SPIN),
-    ("arrows-split-up-and-left", &icons::solid::ARROWS_SPLIT_UP_AND_LEFT),
-    ("arrows-to-circle", &icons::solid::ARROWS_TO_CIRCLE),
-    ("arrows-to-dot", &icons::solid::ARROWS_TO_DOT),
-    ("arrows-to-eye", &icons::solid::ARROWS_TO_EYE),
-    ("arrows-turn-right", &icons::solid::ARROWS_TURN_RIGHT),
-    ("arrows-turn-to-dots", &icons::solid::ARROWS_TURN_TO_DOTS),
-    ("arrows-up-down", &icons::solid::ARROWS_UP_DOWN),
-    ("arrows-up-down-left-right", &icons::solid::ARROWS_UP_DOWN_LEFT_RIGHT),
-    ("arrows-up-to-line", &icons::solid::ARROWS_UP_TO_LINE),
-    ("arrows-v", &icons::solid::ARROWS_V),
-    ("arrow-alt-circle-down", &icons::solid::ARROW_ALT_CIRCLE_DOWN),
-    ("arrow-alt-circle-left", &icons::solid::ARROW_ALT_CIRCLE_LEFT),
-    ("arrow-alt-circle-right", &icons::solid::ARROW_ALT_CIRCLE_RIGHT),
-    ("arrow-alt-circle-up", &icons::solid::ARROW_ALT_CIRCLE_UP),
-    ("arrow-circle-down", &icons::solid::ARROW_CIRCLE_DOWN),
-    ("arrow-circle-left", &icons::solid::ARROW_CIRCLE_LEFT),
-    ("arrow-circle-right", &icons::solid::ARROW_CIRCLE_RIGHT),
-    ("arrow-circle-up", &icons::solid::ARROW_CIRCLE_UP),
-    ("arrow-down", &icons::solid::ARROW_DOWN),
-    ("arrow-down-1-9", &icons::solid::ARROW_DOWN_1_9),
-    ("arrow-down-9-1", &icons::solid::ARROW_DOWN_9_1),
-    ("arrow-down-a-z", &icons::solid::ARROW_DOWN_A_Z),
-    ("arrow-down-long", &icons::solid::ARROW_DOWN_LONG),
-    ("arrow-down-short-wide", &icons::solid::ARROW_DOWN_SHORT_WIDE),
-    ("arrow-down-up-across-line", &icons::solid::ARROW_DOWN_UP_ACROSS_LINE),
-    ("arrow-down-up-lock", &icons::solid::ARROW_DOWN_UP_LOCK),
-    ("arrow-down-wide-short", &icons::solid::ARROW_DOWN_WIDE_SHORT),
-    ("arrow-down-z-a", &icons::solid::ARROW_DOWN_Z_A),
-    ("arrow-left", &icons::solid::ARROW_LEFT),
-    ("arrow-left-long", &icons::solid::ARROW_LEFT_LONG),
-    ("arrow-left-rotate", &icons::solid::ARROW_LEFT_ROTATE),
-    ("arrow-pointer", &icons::solid::ARROW_POINTER),
-    ("arrow-right", &icons::solid::ARROW_RIGHT),
-    ("arrow-right-arrow-left", &icons::solid::ARROW_RIGHT_ARROW_LEFT),
-    ("arrow-right-from-bracket", &icons::solid::ARROW_RIGHT_FROM_BRACKET),
-    ("arrow-right-from-file", &icons::solid::ARROW_RIGHT_FROM_FILE),
-    ("arrow-right-long", &icons::solid::ARROW_RIGHT_LONG),
-    ("arrow-right-rotate", &icons::solid::ARROW_RIGHT_ROTATE),
-    ("arrow-right-to-bracket", &icons::solid::ARROW_RIGHT_TO_BRACKET),
-    ("arrow-right-to-city", &icons::solid::ARROW_RIGHT_TO_CITY),
-    ("arrow-right-to-file", &icons::solid::ARROW_RIGHT_TO_FILE),
-    ("arrow-rotate-back", &icons::solid::ARROW_ROTATE_BACK),
-    ("arrow-rotate-backward", &icons::solid::ARROW_ROTATE_BACKWARD),
-    ("arrow-rotate-forward", &icons::solid::ARROW_ROTATE_FORWARD),
-    ("arrow-rotate-left", &icons::solid::ARROW_ROTATE_LEFT),
-    ("arrow-rotate-right", &icons::solid::ARROW_ROTATE_RIGHT),
-    ("arrow-trend-down", &icons::solid::ARROW_TREND_DOWN),
-    ("arrow-trend-up", &icons::solid::ARROW_TREND_UP),
-    ("arrow-turn-down", &icons::solid::ARROW_TURN_DOWN),
-    ("arrow-turn-up", &icons::solid::ARROW_TURN_UP),
-    ("arrow-up", &icons::solid::ARROW_UP),
-    ("arrow-up-1-9", &icons::solid::ARROW_UP_1_9),
-    ("arrow-up-9-1", &icons::solid::ARROW_UP_9_1),
-    ("arrow-up-a-z", &icons::solid::ARROW_UP_A_Z),
-    ("arrow-up-from-bracket", &icons::solid::ARROW_UP_FROM_BRACKET),
-    ("arrow-up-from-ground-water", &icons::solid::ARROW_UP_FROM_GROUND_WATER),
-    ("arrow-up-from-water-pump", &icons::solid::ARROW_UP_FROM_WATER_PUMP),
-    ("arrow-up-long", &icons::solid::ARROW_UP_LONG),
-    ("arrow-up-right-dots", &icons::solid::ARROW_UP_RIGHT_DOTS),
-    ("arrow-up-right-from-square", &icons::solid::ARROW_UP_RIGHT_FROM_SQUARE),
-    ("arrow-up-short-wide", &icons::solid::ARROW_UP_SHORT_WIDE),
-    ("arrow-up-wide-short", &icons::solid::ARROW_UP_WIDE_SHORT),
-    ("arrow-up-z-a", &icons::solid::ARROW_UP_Z_A),
-    ("asl-interpreting", &icons::solid::ASL_INTERPRETING),
-    ("assistive-listening-systems", &icons::solid::ASSISTIVE_LISTENING_SYSTEMS),
-    ("asterisk", &icons::solid::ASTERISK),
-    ("at", &icons::solid::AT),
-    ("atlas", &icons::solid::ATLAS),
-    ("atom", &icons::solid::ATOM),
-    ("audio-description", &icons::solid::AUDIO_DESCRIPTION),
-    ("austral-sign", &icons::solid::AUSTRAL_SIGN),
-    ("automobile", &icons::solid::AUTOMOBILE),
-    ("award", &icons::solid::AWARD),
-    ("b", &icons::solid::B),
-    ("baby", &icons::solid::BABY),
-    ("baby-carriage", &icons::solid::BABY_CARRIAGE),
-    ("backspace", &icons::solid::BACKSPACE),
-    ("backward", &icons::solid::BACKWARD),
-    ("backward-fast", &icons::solid::BACKWARD_FAST),
-    ("backward-step", &icons::solid::BACKWARD_STEP),
-    ("bacon", &icons::solid::BACON),
-    ("bacteria", &icons::solid::BACTERIA),
-    ("bacterium", &icons::solid::BACTERIUM),
-    ("bag-shopping", &icons::solid::BAG_SHOPPING),
-    ("bahai", &icons::solid::BAHAI),
-    ("baht-sign", &icons::solid::BAHT_SIGN),
-    ("balance-scale", &icons::solid::BALANCE_SCALE),
-    ("balance-scale-left", &icons::solid::BALANCE_SCALE_LEFT),
-    ("balance-scale-right", &icons::solid::BALANCE_SCALE_RIGHT),
-    ("ban", &icons::solid::BAN),
-    ("bandage", &icons::solid::BANDAGE),
-    ("band-aid", &icons::solid::BAND_AID),
-    ("bangladeshi-taka-sign", &icons::solid::BANGLADESHI_TAKA_SIGN),
-    ("bank", &icons::solid::BANK),
-    ("ban-smoking", &icons::solid::BAN_SMOKING),
-    ("barcode", &icons::solid::BARCODE),
-    ("bars", &icons::solid::BARS),
-    ("bars-progress", &icons::solid::BARS_PROGRESS),
-    ("bars-staggered", &icons::solid::BARS_STAGGERED),
-    ("bar-chart", &icons::solid::BAR_CHART),
-    ("baseball", &icons::solid::BASEBALL),
-    ("baseball-ball", &icons::solid::BASEBALL_BALL),
-    ("baseball-bat-ball", &icons::solid::BASEBALL_BAT_BALL),
-    ("basketball", &icons::solid::BASKETBALL),
-    ("basketball-ball", &icons::solid::BASKETBALL_BALL),
-    ("basket-shopping", &icons::solid::BASKET_SHOPPING),
-    ("bath", &icons::solid::BATH),
-    ("bathtub", &icons::solid::BATHTUB),
-    ("battery", &icons::solid::BATTERY),
-    ("battery-0", &icons::solid::BATTERY_0),
-    ("battery-2", &icons::solid::BATTERY_2),
-    ("battery-3", &icons::solid::BATTERY_3),
-    ("battery-4", &icons::solid::BATTERY_4),
-    ("battery-5", &icons::solid::BATTERY_5),
-    ("battery-car", &icons::solid::BATTERY_CAR),
-    ("battery-empty", &icons::solid::BATTERY_EMPTY),
-    ("battery-full", &icons::solid::BATTERY_FULL),
-    ("battery-half", &icons::solid::BATTERY_HALF),
-    ("battery-quarter", &icons::solid::BATTERY_QUARTER),
-    ("battery-three-quarters", &icons::solid::BATTERY_THREE_QUARTERS),
-    ("bed", &icons::solid::BED),
-    ("bed-pulse", &icons::solid::BED_PULSE),
-    ("beer", &icons::solid::BEER),
-    ("beer-mug-empty", &icons::solid::BEER_MUG_EMPTY),
-    ("bell", &icons::solid::BELL),
-    ("bell-concierge", &icons::solid::BELL_CONCIERGE),
-    ("bell-slash", &icons::solid::BELL_SLASH),
-    ("bezier-curve", &icons::solid::BEZIER_CURVE),
-    ("bible", &icons::solid::BIBLE),
-    ("bicycle", &icons::solid::BICYCLE),
-    ("biking", &icons::solid::BIKING),
-    ("binoculars", &icons::solid::BINOCULARS),
-    ("biohazard", &icons::solid::BIOHAZARD),
-    ("birthday-cake", &icons::solid::BIRTHDAY_CAKE),
-    ("bitcoin-sign", &icons::solid::BITCOIN_SIGN),
-    ("blackboard", &icons::solid::BLACKBOARD),
-    ("blender", &icons::solid::BLENDER),
-    ("blender-phone", &icons::solid::BLENDER_PHONE),
-    ("blind", &icons::solid::BLIND),
-    ("blog", &icons::solid::BLOG),
-    ("bold", &icons::solid::BOLD),
-    ("bolt", &icons::solid::BOLT),
-    ("bolt-lightning", &icons::solid::BOLT_LIGHTNING),
-    ("bomb", &icons::solid::BOMB),
-    ("bone", &icons::solid::BONE),
-    ("bong", &icons::solid::BONG),
-    ("book", &icons::solid::BOOK),
-    ("bookmark", &icons::solid::BOOKMARK),
-    ("book-atlas", &icons::solid::BOOK_ATLAS),
-    ("book-bible", &icons::solid::BOOK_BIBLE),
-    ("book-bookmark", &icons::solid::BOOK_BOOKMARK),
-    ("book-dead", &icons::solid::BOOK_DEAD),
-    ("book-journal-whills", &icons::solid::BOOK_JOURNAL_WHILLS),
-    ("book-medical", &icons::solid::BOOK_MEDICAL),
-    ("book-open", &icons::solid::BOOK_OPEN),
-    ("book-open-reader", &icons::solid::BOOK_OPEN_READER),
-    ("book-quran", &icons::solid::BOOK_QURAN),
-    ("book-reader", &icons::solid::BOOK_READER),
-    ("book-skull", &icons::solid::BOOK_SKULL),
-    ("book-tanakh", &icons::solid::BOOK_TANAKH),
-    ("border-all", &icons::solid::BORDER_ALL),
-    ("border-none", &icons::solid::BORDER_NONE),
-    ("border-style", &icons::solid::BORDER_STYLE),
-    ("border-top-left", &icons::solid::BORDER_TOP_LEFT),
-    ("bore-hole", &icons::solid::BORE_HOLE),
-    ("bottle-droplet", &icons::solid::BOTTLE_DROPLET),
-    ("bottle-water", &icons::solid::BOTTLE_WATER),
-    ("bowling-ball", &icons::solid::BOWLING_BALL),
-    ("bowl-food", &icons::solid::BOWL_FOOD),
-    ("bowl-rice", &icons::solid::BOWL_RICE),
-    ("box", &icons::solid::BOX),
-    ("boxes", &icons::solid::BOXES),
-    ("boxes-alt", &icons::solid::BOXES_ALT),
-    ("boxes-packing", &icons::solid::BOXES_PACKING),
-    ("boxes-stacked", &icons::solid::BOXES_STACKED),
-    ("box-archive", &icons::solid::BOX_ARCHIVE),
-    ("box-open", &icons::solid::BOX_OPEN),
-    ("box-tissue", &icons::solid::BOX_TISSUE),
-    ("braille", &icons::solid::BRAILLE),
-    ("brain", &icons::solid::BRAIN),
-    ("brazilian-real-sign", &icons::solid::BRAZILIAN_REAL_SIGN),
-    ("bread-slice", &icons::solid::BREAD_SLICE),
-    ("bridge", &icons::solid::BRIDGE),
-    ("bridge-circle-check", &icons::solid::BRIDGE_CIRCLE_CHECK),
-    ("bridge-circle-exclamation", &icons::solid::BRIDGE_CIRCLE_EXCLAMATION),
-    ("bridge-circle-xmark", &icons::solid::BRIDGE_CIRCLE_XMARK),
-    ("bridge-lock", &icons::solid::BRIDGE_LOCK),
-    ("bridge-water", &icons::solid::BRIDGE_WATER),
-    ("briefcase", &icons::solid::BRIEFCASE),
-    ("briefcase-clock", &icons::solid::BRIEFCASE_CLOCK),
-    ("briefcase-medical", &icons::solid::BRIEFCASE_MEDICAL),
-    ("broadcast-tower", &icons::solid::BROADCAST_TOWER),
-    ("broom", &icons::solid::BROOM),
-    ("broom-ball", &icons::solid::BROOM_BALL),
-    ("brush", &icons::solid::BRUSH),
-    ("bucket", &icons::solid::BUCKET),
-    ("bug", &icons::solid::BUG),
-    ("bugs", &icons::solid::BUGS),
-    ("bug-slash", &icons::solid::BUG_SLASH),
-    ("building", &icons::solid::BUILDING),
-    ("building-circle-arrow-right", &icons::solid::BUILDING_CIRCLE_ARROW_RIGHT),
-    ("building-circle-check", &icons::solid::BUILDING_CIRCLE_CHECK),
-    ("building-circle-exclamation", &icons::solid::BUILDING_CIRCLE_EXCLAMATION),
-    ("building-circle-xmark", &icons::solid::BUILDING_CIRCLE_XMARK),
-    ("building-columns", &icons::solid::BUILDING_COLUMNS),
-    ("building-flag", &icons::solid::BUILDING_FLAG),
-    ("building-lock", &icons::solid::BUILDING_LOCK),
-    ("building-ngo", &icons::solid::BUILDING_NGO),
-    ("building-shield", &icons::solid::BUILDING_SHIELD),
-    ("building-un", &icons::solid::BUILDING_UN),
-    ("building-user", &icons::solid::BUILDING_USER),
-    ("building-wheat", &icons::solid::BUILDING_WHEAT),
-    ("bullhorn", &icons::solid::BULLHORN),
-    ("bullseye", &icons::solid::BULLSEYE),
-    ("burger", &icons::solid::BURGER),
-    ("burn", &icons::solid::BURN),
-    ("burst", &icons::solid::BURST),
-    ("bus", &icons::solid::BUS),
-    ("business-time", &icons::solid::BUSINESS_TIME),
-    ("bus-alt", &icons::solid::BUS_ALT),
-    ("bus-side", &icons::solid::BUS_SIDE),
-    ("bus-simple", &icons::solid::BUS_SIMPLE),
-    ("c", &icons::solid::C),
-    ("cab", &icons::solid::CAB),
-    ("cable-car", &icons::solid::CABLE_CAR),
-    ("cake", &icons::solid::CAKE),
-    ("cake-candles", &icons::solid::CAKE_CANDLES),
-    ("calculator", &icons::solid::CALCULATOR),
-    ("calendar", &icons::solid::CALENDAR),
-    ("calendar-alt", &icons::solid::CALENDAR_ALT),
-    ("calendar-check", &icons::solid::CALENDAR_CHECK),
-    ("calendar-day", &icons::solid::CALENDAR_DAY),
-    ("calendar-days", &icons::solid::CALENDAR_DAYS),
-    ("calendar-minus", &icons::solid::CALENDAR_MINUS),
-    ("calendar-plus", &icons::solid::CALENDAR_PLUS),
-    ("calendar-times", &icons::solid::CALENDAR_TIMES),
-    ("calendar-week", &icons::solid::CALENDAR_WEEK),
-    ("calendar-xmark", &icons::solid::CALENDAR_XMARK),
-    ("camera", &icons::solid::CAMERA),
-    ("camera-alt", &icons::solid::CAMERA_ALT),
-    ("camera-retro", &icons::solid::CAMERA_RETRO),
-    ("camera-rotate", &icons::solid::CAMERA_ROTATE),
-    ("campground", &icons::solid::CAMPGROUND),
-    ("cancel", &icons::solid::CANCEL),
-    ("candy-cane", &icons::solid::CANDY_CANE),
-    ("cannabis", &icons::solid::CANNABIS),
-    ("capsules", &icons::solid::CAPSULES),
-    ("car", &icons::solid::CAR),
-    ("caravan", &icons::solid::CARAVAN),
-    ("caret-down", &icons::solid::CARET_DOWN),
-    ("caret-left", &icons::solid::CARET_LEFT),
-    ("caret-right", &icons::solid::CARET_RIGHT),
-    ("caret-square-down", &icons::solid::CARET_SQUARE_DOWN),
-    ("caret-square-left", &icons::solid::CARET_SQUARE_LEFT),
-    ("caret-square-right", &icons::solid::CARET_SQUARE_RIGHT),
-    ("caret-square-up", &icons::solid::CARET_SQUARE_UP),
-    ("caret-up", &icons::solid::CARET_UP),
-    ("carriage-baby", &icons::solid::CARRIAGE_BABY),
-    ("carrot", &icons::solid::CARROT),
-    ("cart-arrow-down", &icons::solid::CART_ARROW_DOWN),
-    ("cart-flatbed", &icons::solid::CART_FLATBED),
-    ("cart-flatbed-suitcase", &icons::solid::CART_FLATBED_SUITCASE),
-    ("cart-plus", &icons::solid::CART_PLUS),
-    ("cart-shopping", &icons::solid::CART_SHOPPING),
-    ("car-alt", &icons::solid::CAR_ALT),
-    ("car-battery", &icons::solid::CAR_BATTERY),
-    ("car-burst", &icons::solid::CAR_BURST),
-    ("car-crash", &icons::solid::CAR_CRASH),
-    ("car-on", &icons::solid::CAR_ON),
-    ("car-rear", &icons::solid::CAR_REAR),
-    ("car-side", &icons::solid::CAR_SIDE),
-    ("car-tunnel", &icons::solid::CAR_TUNNEL),
-    ("cash-register", &icons::solid::CASH_REGISTER),
-    ("cat", &icons::solid::CAT),
-    ("cedi-sign", &icons::solid::CEDI_SIGN),
-    ("cent-sign", &icons::solid::CENT_SIGN),
-    ("certificate", &icons::solid::CERTIFICATE),
-    ("chain", &icons::solid::CHAIN),
-    ("chain-broken", &icons::solid::CHAIN_BROKEN),
-    ("chain-slash", &icons::solid::CHAIN_SLASH),
-    ("chair", &icons::solid::CHAIR),
-    ("chalkboard", &icons::solid::CHALKBOARD),
-    ("chalkboard-teacher", &icons::solid::CHALKBOARD_TEACHER),
-    ("chalkboard-user", &icons::solid::CHALKBOARD_USER),
-    ("champagne-glasses", &icons::solid::CHAMPAGNE_GLASSES),
-    ("charging-station", &icons::solid::CHARGING_STATION),
-    ("chart-area", &icons::solid::CHART_AREA),
-    ("chart-bar", &icons::solid::CHART_BAR),
-    ("chart-column", &icons::solid::CHART_COLUMN),
-    ("chart-diagram", &icons::solid::CHART_DIAGRAM),
-    ("chart-gantt", &icons::solid::CHART_GANTT),
-    ("chart-line", &icons::solid::CHART_LINE),
-    ("chart-pie", &icons::solid::CHART_PIE),
-    ("chart-simple", &icons::solid::CHART_SIMPLE),
-    ("check", &icons::solid::CHECK),
-    ("check-circle", &icons::solid::CHECK_CIRCLE),
-    ("check-double", &icons::solid::CHECK_DOUBLE),
-    ("check-square", &icons::solid::CHECK_SQUARE),
-    ("check-to-slot", &icons::solid::CHECK_TO_SLOT),
-    ("cheese", &icons::solid::CHEESE),
-    ("chess", &icons::solid::CHESS),
-    ("chess-bishop", &icons::solid::CHESS_BISHOP),
-    ("chess-board", &icons::solid::CHESS_BOARD),
-    ("chess-king", &icons::solid::CHESS_KING),
-    ("chess-knight", &icons::solid::CHESS_KNIGHT),
-    ("chess-pawn", &icons::solid::CHESS_PAWN),
-    ("chess-queen", &icons::solid::CHESS_QUEEN),
-    ("chess-rook", &icons::solid::CHESS_ROOK),
-    ("chevron-circle-down", &icons::solid::CHEVRON_CIRCLE_DOWN),
-    ("chevron-circle-left", &icons::solid::CHEVRON_CIRCLE_LEFT),
-    ("chevron-circle-right", &icons::solid::CHEVRON_CIRCLE_RIGHT),
-    ("chevron-circle-up", &icons::solid::CHEVRON_CIRCLE_UP),
-    ("chevron-down", &icons::solid::CHEVRON_DOWN),
-    ("chevron-left", &icons::solid::CHEVRON_LEFT),
-    ("chevron-right", &icons::solid::CHEVRON_RIGHT),
-    ("chevron-up", &icons::solid::CHEVRON_UP),
-    ("child", &icons::solid::CHILD),
-    ("children", &icons::solid::CHILDREN),
-    ("child-combatant", &icons::solid::CHILD_COMBATANT),
-    ("child-dress", &icons::solid::CHILD_DRESS),
-    ("child-reaching", &icons::solid::CHILD_REACHING),
-    ("child-rifle", &icons::solid::CHILD_RIFLE),
-    ("church", &icons::solid::CHURCH),
-    ("circle", &icons::solid::CIRCLE),
-    ("circle-arrow-down", &icons::solid::CIRCLE_ARROW_DOWN),
-    ("circle-arrow-left", &icons::solid::CIRCLE_ARROW_LEFT),
-    ("circle-arrow-right", &icons::solid::CIRCLE_ARROW_RIGHT),
-    ("circle-arrow-up", &icons::solid::CIRCLE_ARROW_UP),
-    ("circle-check", &icons::solid::CIRCLE_CHECK),
-    ("circle-chevron-down", &icons::solid::CIRCLE_CHEVRON_DOWN),
-    ("circle-chevron-left", &icons::solid::CIRCLE_CHEVRON_LEFT),
-    ("circle-chevron-right", &icons::solid::CIRCLE_CHEVRON_RIGHT),
-    ("circle-chevron-up", &icons::solid::CIRCLE_CHEVRON_UP),
-    ("circle-dollar-to-slot", &icons::solid::CIRCLE_DOLLAR_TO_SLOT),
-    ("circle-dot", &icons::solid::CIRCLE_DOT),
-    ("circle-down", &icons::solid::CIRCLE_DOWN),
-    ("circle-exclamation", &icons::solid::CIRCLE_EXCLAMATION),
-    ("circle-h", &icons::solid::CIRCLE_H),
-    ("circle-half-stroke", &icons::solid::CIRCLE_HALF_STROKE),
-    ("circle-info", &icons::solid::CIRCLE_INFO),
-    ("circle-left", &icons::solid::CIRCLE_LEFT),
-    ("circle-minus", &icons::solid::CIRCLE_MINUS),
-    ("circle-nodes", &icons::solid::CIRCLE_NODES),
-    ("circle-notch", &icons::solid::CIRCLE_NOTCH),
-    ("circle-pause", &icons::solid::CIRCLE_PAUSE),
-    ("circle-play", &icons::solid::CIRCLE_PLAY),
-    ("circle-plus", &icons::solid::CIRCLE_PLUS),
-    ("circle-question", &icons::solid::CIRCLE_QUESTION),
-    ("circle-radiation", &icons::solid::CIRCLE_RADIATION),
-    ("circle-right", &icons::solid::CIRCLE_RIGHT),
-    ("circle-stop", &icons::solid::CIRCLE_STOP),
-    ("circle-up", &icons::solid::CIRCLE_UP),
-    ("circle-user", &icons::solid::CIRCLE_USER),
-    ("circle-xmark", &icons::solid::CIRCLE_XMARK),
-    ("city", &icons::solid::CITY),
-    ("clapperboard", &icons::solid::CLAPPERBOARD),
-    ("clinic-medical", &icons::solid::CLINIC_MEDICAL),
-    ("clipboard", &icons::solid::CLIPBOARD),
-    ("clipboard-check", &icons::solid::CLIPBOARD_CHECK),
-    ("clipboard-list", &icons::solid::CLIPBOARD_LIST),
-    ("clipboard-question", &icons::solid::CLIPBOARD_QUESTION),
-    ("clipboard-user", &icons::solid::CLIPBOARD_USER),
-    ("clock", &icons::solid::CLOCK),
-    ("clock-four", &icons::solid::CLOCK_FOUR),
-    ("clock-rotate-left", &icons::solid::CLOCK_ROTATE_LEFT),
-    ("clone", &icons::solid::CLONE),
-    ("close", &icons::solid::CLOSE),
-    ("closed-captioning", &icons::solid::CLOSED_CAPTIONING),
-    ("cloud", &icons::solid::CLOUD),
-    ("cloud-arrow-down", &icons::solid::CLOUD_ARROW_DOWN),
-    ("cloud-arrow-up", &icons::solid::CLOUD_ARROW_UP),
-    ("cloud-bolt", &icons::solid::CLOUD_BOLT),
-    ("cloud-download", &icons::solid::CLOUD_DOWNLOAD),
-    ("cloud-download-alt", &icons::solid::CLOUD_DOWNLOAD_ALT),
-    ("cloud-meatball", &icons::solid::CLOUD_MEATBALL),
-    ("cloud-moon", &icons::solid::CLOUD_MOON),
-    ("cloud-moon-rain", &icons::solid::CLOUD_MOON_RAIN),
-    ("cloud-rain", &icons::solid::CLOUD_RAIN),
-    ("cloud-showers-heavy", &icons::solid::CLOUD_SHOWERS_HEAVY),
-    ("cloud-showers-water", &icons::solid::CLOUD_SHOWERS_WATER),
-    ("cloud-sun", &icons::solid::CLOUD_SUN),
-    ("cloud-sun-rain", &icons::solid::CLOUD_SUN_RAIN),
-    ("cloud-upload", &icons::solid::CLOUD_UPLOAD),
-    ("cloud-upload-alt", &icons::solid::CLOUD_UPLOAD_ALT),
-    ("clover", &icons::solid::CLOVER),
-    ("cny", &icons::solid::CNY),
-    ("cocktail", &icons::solid::COCKTAIL),
-    ("code", &icons::solid::CODE),
-    ("code-branch", &icons::solid::CODE_BRANCH),
-    ("code-commit", &icons::solid::CODE_COMMIT),
-    ("code-compare", &icons::solid::CODE_COMPARE),
-    ("code-fork", &icons::solid::CODE_FORK),
-    ("code-merge", &icons::solid::CODE_MERGE),
-    ("code-pull-request", &icons::solid::CODE_PULL_REQUEST),
-    ("coffee", &icons::solid::COFFEE),
-    ("cog", &icons::solid::COG),
-    ("cogs", &icons::solid::COGS),
-    ("coins", &icons::solid::COINS),
-    ("colon-sign", &icons::solid::COLON_SIGN),
-    ("columns", &icons::solid::COLUMNS),
-    ("comment", &icons::solid::COMMENT),
-    ("commenting", &icons::solid::COMMENTING),
-    ("comments", &icons::solid::COMMENTS),
-    ("comments-dollar", &icons::solid::COMMENTS_DOLLAR),
-    ("comment-alt", &icons::solid::COMMENT_ALT),
-    ("comment-dollar", &icons::solid::COMMENT_DOLLAR),
-    ("comment-dots", &icons::solid::COMMENT_DOTS),
-    ("comment-medical", &icons::solid::COMMENT_MEDICAL),
-    ("comment-nodes", &icons::solid::COMMENT_NODES),
-    ("comment-slash", &icons::solid::COMMENT_SLASH),
-    ("comment-sms", &icons::solid::COMMENT_SMS),
-    ("compact-disc", &icons::solid::COMPACT_DISC),
-    ("compass", &icons::solid::COMPASS),
-    ("compass-drafting", &icons::solid::COMPASS_DRAFTING),
-    ("compress", &icons::solid::COMPRESS),
-    ("compress-alt", &icons::solid::COMPRESS_ALT),
-    ("compress-arrows-alt", &icons::solid::COMPRESS_ARROWS_ALT),
-    ("computer", &icons::solid::COMPUTER),
-    ("computer-mouse", &icons::solid::COMPUTER_MOUSE),
-    ("concierge-bell", &icons::solid::CONCIERGE_BELL),
-    ("contact-book", &icons::solid::CONTACT_BOOK),
-    ("contact-card", &icons::solid::CONTACT_CARD),
-    ("cookie", &icons::solid::COOKIE),
-    ("cookie-bite", &icons::solid::COOKIE_BITE),
-    ("copy", &icons::solid::COPY),
-    ("copyright", &icons::solid::COPYRIGHT),
-    ("couch", &icons::solid::COUCH),
-    ("cow", &icons::solid::COW),
-    ("credit-card", &icons::solid::CREDIT_CARD),
-    ("credit-card-alt", &icons::solid::CREDIT_CARD_ALT),
-    ("crop", &icons::solid::CROP),
-    ("crop-alt", &icons::solid::CROP_ALT),
-    ("crop-simple", &icons::solid::CROP_SIMPLE),
-    ("cross", &icons::solid::CROSS),
-    ("crosshairs", &icons::solid::CROSSHAIRS),
-    ("crow", &icons::solid::CROW),
-    ("crown", &icons::solid::CROWN),
-    ("crutch", &icons::solid::CRUTCH),
-    ("cruzeiro-sign", &icons::solid::CRUZEIRO_SIGN),
-    ("cube", &icons::solid::CUBE),
-    ("cubes", &icons::solid::CUBES),
-    ("cubes-stacked", &icons::solid::CUBES_STACKED),
-    ("cut", &icons::solid::CUT),
-    ("cutlery", &icons::solid::CUTLERY),
-    ("d", &icons::solid::D),
-    ("dashboard", &icons::solid::DASHBOARD),
-    ("database", &icons::solid::DATABASE),
-    ("deaf", &icons::solid::DEAF),
-    ("deafness", &icons::solid::DEAFNESS),
-    ("dedent", &icons::solid::DEDENT),
-    ("delete-left", &icons::solid::DELETE_LEFT),
-    ("democrat", &icons::solid::DEMOCRAT),
-    ("desktop", &icons::solid::DESKTOP),
-    ("desktop-alt", &icons::solid::DESKTOP_ALT),
-    ("dharmachakra", &icons::solid::DHARMACHAKRA),
-    ("diagnoses", &icons::solid::DIAGNOSES),
-    ("diagram-next", &icons::solid::DIAGRAM_NEXT),
-    ("diagram-predecessor", &icons::solid::DIAGRAM_PREDECESSOR),
-    ("diagram-project", &icons::solid::DIAGRAM_PROJECT),
-    ("diagram-successor", &icons::solid::DIAGRAM_SUCCESSOR),
-    ("diamond", &icons::solid::DIAMOND),
-    ("diamond-turn-right", &icons::solid::DIAMOND_TURN_RIGHT),
-    ("dice", &icons::solid::DICE),
-    ("dice-d20", &icons::solid::DICE_D20),
-    ("dice-d6", &icons::solid::DICE_D6),
-    ("dice-five", &icons::solid::DICE_FIVE),
-    ("dice-four", &icons::solid::DICE_FOUR),
-    ("dice-one", &icons::solid::DICE_ONE),
-    ("dice-six", &icons::solid::DICE_SIX),
-    ("dice-three", &icons::solid::DICE_THREE),
-    ("dice-two", &icons::solid::DICE_TWO),
-    ("digging", &icons::solid::DIGGING),
-    ("digital-tachograph", &icons::solid::DIGITAL_TACHOGRAPH),
-    ("directions", &icons::solid::DIRECTIONS),
-    ("disease", &icons::solid::DISEASE),
-    ("display", &icons::solid::DISPLAY),
-    ("divide", &icons::solid::DIVIDE),
-    ("dizzy", &icons::solid::DIZZY),
-    ("dna", &icons::solid::DNA),
-    ("dog", &icons::solid::DOG),
-    ("dollar", &icons::solid::DOLLAR),
-    ("dollar-sign", &icons::solid::DOLLAR_SIGN),
-    ("dolly", &icons::solid::DOLLY),
-    ("dolly-box", &icons::solid::DOLLY_BOX),
-    ("dolly-flatbed", &icons::solid::DOLLY_FLATBED),
-    ("donate", &icons::solid::DONATE),
-    ("dong-sign", &icons::solid::DONG_SIGN),
-    ("door-closed", &icons::solid::DOOR_CLOSED),
-    ("door-open", &icons::solid::DOOR_OPEN),
-    ("dot-circle", &icons::solid::DOT_CIRCLE),
-    ("dove", &icons::solid::DOVE),
-    ("download", &icons::solid::DOWNLOAD),
-    ("down-left-and-up-right-to-center", &icons::solid::DOWN_LEFT_AND_UP_RIGHT_TO_CENTER),
-    ("down-long", &icons::solid::DOWN_LONG),
-    ("drafting-compass", &icons::solid::DRAFTING_COMPASS),
-    ("dragon", &icons::solid::DRAGON),
-    ("draw-polygon", &icons::solid::DRAW_POLYGON),
-    ("drivers-license", &icons::solid::DRIVERS_LICENSE),
-    ("droplet", &icons::solid::DROPLET),
-    ("droplet-slash", &icons::solid::DROPLET_SLASH),
-    ("drum", &icons::solid::DRUM),
-    ("drumstick-bite", &icons::solid::DRUMSTICK_BITE),
-    ("drum-steelpan", &icons::solid::DRUM_STEELPAN),
-    ("dumbbell", &icons::solid::DUMBBELL),
-    ("dumpster", &icons::solid::DUMPSTER),
-    ("dumpster-fire", &icons::solid::DUMPSTER_FIRE),
-    ("dungeon", &icons::solid::DUNGEON),
-    ("e", &icons::solid::E),
-    ("earth", &icons::solid::EARTH),
-    ("earth-africa", &icons::solid::EARTH_AFRICA),
-    ("earth-america", &icons::solid::EARTH_AMERICA),
-    ("earth-americas", &icons::solid::EARTH_AMERICAS),
-    ("earth-asia", &icons::solid::EARTH_ASIA),
-    ("earth-europe", &icons::solid::EARTH_EUROPE),
-    ("earth-oceania", &icons::solid::EARTH_OCEANIA),
-    ("ear-deaf", &icons::solid::EAR_DEAF),
-    ("ear-listen", &icons::solid::EAR_LISTEN),
-    ("edit", &icons::solid::EDIT),
-    ("egg", &icons::solid::EGG),
-    ("eject", &icons::solid::EJECT),
-    ("elevator", &icons::solid::ELEVATOR),
-    ("ellipsis", &icons::solid::ELLIPSIS),
-    ("ellipsis-h", &icons::solid::ELLIPSIS_H),
-    ("ellipsis-v", &icons::solid::ELLIPSIS_V),
-    ("ellipsis-vertical", &icons::solid::ELLIPSIS_VERTICAL),
-    ("envelope", &icons::solid::ENVELOPE),
-    ("envelopes-bulk", &icons::solid::ENVELOPES_BULK),
-    ("envelope-circle-check", &icons::solid::ENVELOPE_CIRCLE_CHECK),
-    ("envelope-open", &icons::solid::ENVELOPE_OPEN),
-    ("envelope-open-text", &icons::solid::ENVELOPE_OPEN_TEXT),
-    ("envelope-square", &icons::solid::ENVELOPE_SQUARE),
-    ("equals", &icons::solid::EQUALS),
-    ("eraser", &icons::solid::ERASER),
-    ("ethernet", &icons::solid::ETHERNET),
-    ("eur", &icons::solid::EUR),
-    ("euro", &icons::solid::EURO),
-    ("euro-sign", &icons::solid::EURO_SIGN),
-    ("exchange", &icons::solid::EXCHANGE),
-    ("exchange-alt", &icons::solid::EXCHANGE_ALT),
-    ("exclamation", &icons::solid::EXCLAMATION),
-    ("exclamation-circle", &icons::solid::EXCLAMATION_CIRCLE),
-    ("exclamation-triangle", &icons::solid::EXCLAMATION_TRIANGLE),
-    ("expand", &icons::solid::EXPAND),
-    ("expand-alt", &icons::solid::EXPAND_ALT),
-    ("expand-arrows-alt", &icons::solid::EXPAND_ARROWS_ALT),
-    ("explosion", &icons::solid::EXPLOSION),
-    ("external-link", &icons::solid::EXTERNAL_LINK),
-    ("external-link-alt", &icons::solid::EXTERNAL_LINK_ALT),
-    ("external-link-square", &icons::solid::EXTERNAL_LINK_SQUARE),
-    ("external-link-square-alt", &icons::solid::EXTERNAL_LINK_SQUARE_ALT),
-    ("eye", &icons::solid::EYE),
-    ("eyedropper", &icons::solid::EYEDROPPER),
-    ("eye-dropper", &icons::solid::EYE_DROPPER),
-    ("eye-dropper-empty", &icons::solid::EYE_DROPPER_EMPTY),
-    ("eye-low-vision", &icons::solid::EYE_LOW_VISION),
-    ("eye-slash", &icons::solid::EYE_SLASH),
-    ("f", &icons::solid::F),
-    ("face-angry", &icons::solid::FACE_ANGRY),
-    ("face-dizzy", &icons::solid::FACE_DIZZY),
-    ("face-flushed", &icons::solid::FACE_FLUSHED),
-    ("face-frown", &icons::solid::FACE_FROWN),
-    ("face-frown-open", &icons::solid::FACE_FROWN_OPEN),
-    ("face-grimace", &icons::solid::FACE_GRIMACE),
-    ("face-grin", &icons::solid::FACE_GRIN),
-    ("face-grin-beam", &icons::solid::FACE_GRIN_BEAM),
-    ("face-grin-beam-sweat", &icons::solid::FACE_GRIN_BEAM_SWEAT),
-    ("face-grin-hearts", &icons::solid::FACE_GRIN_HEARTS),
-    ("face-grin-squint", &icons::solid::FACE_GRIN_SQUINT),
-    ("face-grin-squint-tears", &icons::solid::FACE_GRIN_SQUINT_TEARS),
-    ("face-grin-stars", &icons::solid::FACE_GRIN_STARS),
-    ("face-grin-tears", &icons::solid::FACE_GRIN_TEARS),
-    ("face-grin-tongue", &icons::solid::FACE_GRIN_TONGUE),
-    ("face-grin-tongue-squint", &icons::solid::FACE_GRIN_TONGUE_SQUINT),
-    ("face-grin-tongue-wink", &icons::solid::FACE_GRIN_TONGUE_WINK),
-    ("face-grin-wide", &icons::solid::FACE_GRIN_WIDE),
-    ("face-grin-wink", &icons::solid::FACE_GRIN_WINK),
-    ("face-kiss", &icons::solid::FACE_KISS),
-    ("face-kiss-beam", &icons::solid::FACE_KISS_BEAM),
-    ("face-kiss-wink-heart", &icons::solid::FACE_KISS_WINK_HEART),
-    ("face-laugh", &icons::solid::FACE_LAUGH),
-    ("face-laugh-beam", &icons::solid::FACE_LAUGH_BEAM),
-    ("face-laugh-squint", &icons::solid::FACE_LAUGH_SQUINT),
-    ("face-laugh-wink", &icons::solid::FACE_LAUGH_WINK),
-    ("face-meh", &icons::solid::FACE_MEH),
-    ("face-meh-blank", &icons::solid::FACE_MEH_BLANK),
-    ("face-rolling-eyes", &icons::solid::FACE_ROLLING_EYES),
-    ("face-sad-cry", &icons::solid::FACE_SAD_CRY),
-    ("face-sad-tear", &icons::solid::FACE_SAD_TEAR),
-    ("face-smile", &icons::solid::FACE_SMILE),
-    ("face-smile-beam", &icons::solid::FACE_SMILE_BEAM),
-    ("face-smile-wink", &icons::solid::FACE_SMILE_WINK),
-    ("face-surprise", &icons::solid::FACE_SURPRISE),
-    ("face-tired", &icons::solid::FACE_TIRED),
-    ("fan", &icons::solid::FAN),
-    ("fast-backward", &icons::solid::FAST_BACKWARD),
-    ("fast-forward", &icons::solid::FAST_FORWARD),
-    ("faucet", &icons::solid::FAUCET),
-    ("faucet-drip", &icons::solid::FAUCET_DRIP),
-    ("fax", &icons::solid::FAX),
-    ("feather", &icons::solid::FEATHER),
-    ("feather-alt", &icons::solid::FEATHER_ALT),
-    ("feather-pointed", &icons::solid::FEATHER_POINTED),
-    ("feed", &icons::solid::FEED),
-    ("female", &icons::solid::FEMALE),
-    ("ferry", &icons::solid::FERRY),
-    ("fighter-jet", &icons::solid::FIGHTER_JET),
-    ("file", &icons::solid::FILE),
-    ("file-alt", &icons::solid::FILE_ALT),
-    ("file-archive", &icons::solid::FILE_ARCHIVE),
-    ("file-arrow-down", &icons::solid::FILE_ARROW_DOWN),
-    ("file-arrow-up", &icons::solid::FILE_ARROW_UP),
-    ("file-audio", &icons::solid::FILE_AUDIO),
-    ("file-circle-check", &icons::solid::FILE_CIRCLE_CHECK),
-    ("file-circle-exclamation", &icons::solid::FILE_CIRCLE_EXCLAMATION),
-    ("file-circle-minus", &icons::solid::FILE_CIRCLE_MINUS),
-    ("file-circle-plus", &icons::solid::FILE_CIRCLE_PLUS),
-    ("file-circle-question", &icons::solid::FILE_CIRCLE_QUESTION),
-    ("file-circle-xmark", &icons::solid::FILE_CIRCLE_XMARK),
-    ("file-clipboard", &icons::solid::FILE_CLIPBOARD),
-    ("file-code", &icons::solid::FILE_CODE),
-    ("file-contract", &icons::solid::FILE_CONTRACT),
-    ("file-csv", &icons::solid::FILE_CSV),
-    ("file-download", &icons::solid::FILE_DOWNLOAD),
-    ("file-edit", &icons::solid::FILE_EDIT),
-    ("file-excel", &icons::solid::FILE_EXCEL),
-    ("file-export", &icons::solid::FILE_EXPORT),
-    ("file-fragment", &icons::solid::FILE_FRAGMENT),
-    ("file-half-dashed", &icons::solid::FILE_HALF_DASHED),
-    ("file-image", &icons::solid::FILE_IMAGE),
-    ("file-import", &icons::solid::FILE_IMPORT),
-    ("file-invoice", &icons::solid::FILE_INVOICE),
-    ("file-invoice-dollar", &icons::solid::FILE_INVOICE_DOLLAR),
-    ("file-lines", &icons::solid::FILE_LINES),
-    ("file-medical", &icons::solid::FILE_MEDICAL),
-    ("file-medical-alt", &icons::solid::FILE_MEDICAL_ALT),
-    ("file-pdf", &icons::solid::FILE_PDF),
-    ("file-pen", &icons::solid::FILE_PEN),
-    ("file-powerpoint", &icons::solid::FILE_POWERPOINT),
-    ("file-prescription", &icons::solid::FILE_PRESCRIPTION),
-    ("file-shield", &icons::solid::FILE_SHIELD),
-    ("file-signature", &icons::solid::FILE_SIGNATURE),
-    ("file-text", &icons::solid::FILE_TEXT),
-    ("file-upload", &icons::solid::FILE_UPLOAD),
-    ("file-video", &icons::solid::FILE_VIDEO),
-    ("file-waveform", &icons::solid::FILE_WAVEFORM),
-    ("file-word", &icons::solid::FILE_WORD),
-    ("file-zipper", &icons::solid::FILE_ZIPPER),
-    ("fill", &icons::solid::FILL),
-    ("fill-drip", &icons::solid::FILL_DRIP),
-    ("film", &icons::solid::FILM),
-    ("film-alt", &icons::solid::FILM_ALT),
-    ("film-simple", &icons::solid::FILM_SIMPLE),
-    ("filter", &icons::solid::FILTER),
-    ("filter-circle-dollar", &icons::solid::FILTER_CIRCLE_DOLLAR),
-    ("filter-circle-xmark", &icons::solid::FILTER_CIRCLE_XMARK),
-    ("fingerprint", &icons::solid::FINGERPRINT),
-    ("fire", &icons::solid::FIRE),
-    ("fire-alt", &icons::solid::FIRE_ALT),
-    ("fire-burner", &icons::solid::FIRE_BURNER),
-    ("fire-extinguisher", &icons::solid::FIRE_EXTINGUISHER),
-    ("fire-flame-curved", &icons::solid::FIRE_FLAME_CURVED),
-    ("fire-flame-simple", &icons::solid::FIRE_FLAME_SIMPLE),
-    ("first-aid", &icons::solid::FIRST_AID),
-    ("fish", &icons::solid::FISH),
-    ("fish-fins", &icons::solid::FISH_FINS),
-    ("fist-raised", &icons::solid::FIST_RAISED),
-    ("flag", &icons::solid::FLAG),
-    ("flag-checkered", &icons::solid::FLAG_CHECKERED),
-    ("flag-usa", &icons::solid::FLAG_USA),
-    ("flask", &icons::solid::FLASK),
-    ("flask-vial", &icons::solid::FLASK_VIAL),
-    ("floppy-disk", &icons::solid::FLOPPY_DISK),
-    ("florin-sign", &icons::solid::FLORIN_SIGN),
-    ("flushed", &icons::solid::FLUSHED),
-    ("folder", &icons::solid::FOLDER),
-    ("folder-blank", &icons::solid::FOLDER_BLANK),
-    ("folder-closed", &icons::solid::FOLDER_CLOSED),
-    ("folder-minus", &icons::solid::FOLDER_MINUS),
-    ("folder-open", &icons::solid::FOLDER_OPEN),
-    ("folder-plus", &icons::solid::FOLDER_PLUS),
-    ("folder-tree", &icons::solid::FOLDER_TREE),
-    ("font", &icons::solid::FONT),
-    ("font-awesome", &icons::solid::FONT_AWESOME),
-    ("font-awesome-flag", &icons::solid::FONT_AWESOME_FLAG),
-    ("font-awesome-logo-full", &icons::solid::FONT_AWESOME_LOGO_FULL),
-    ("football", &icons::solid::FOOTBALL),
-    ("football-ball", &icons::solid::FOOTBALL_BALL),
-    ("forward", &icons::solid::FORWARD),
-    ("forward-fast", &icons::solid::FORWARD_FAST),
-    ("forward-step", &icons::solid::FORWARD_STEP),
-    ("franc-sign", &icons::solid::FRANC_SIGN),
-    ("frog", &icons::solid::FROG),
-    ("frown", &icons::solid::FROWN),
-    ("frown-open", &icons::solid::FROWN_OPEN),
-    ("funnel-dollar", &icons::solid::FUNNEL_DOLLAR),
-    ("futbol", &icons::solid::FUTBOL),
-    ("futbol-ball", &icons::solid::FUTBOL_BALL),
-    ("g", &icons::solid::G),
-    ("gamepad", &icons::solid::GAMEPAD),
-    ("gas-pump", &icons::solid::GAS_PUMP),
-    ("gauge", &icons::solid::GAUGE),
-    ("gauge-high", &icons::solid::GAUGE_HIGH),
-    ("gauge-med", &icons::solid::GAUGE_MED),
-    ("gauge-simple", &icons::solid::GAUGE_SIMPLE),
-    ("gauge-simple-high", &icons::solid::GAUGE_SIMPLE_HIGH),
-    ("gauge-simple-med", &icons::solid::GAUGE_SIMPLE_MED),
-    ("gavel", &icons::solid::GAVEL),
-    ("gbp", &icons::solid::GBP),
-    ("gear", &icons::solid::GEAR),
-    ("gears", &icons::solid::GEARS),
-    ("gem", &icons::solid::GEM),
-    ("genderless", &icons::solid::GENDERLESS),
-    ("ghost", &icons::solid::GHOST),
-    ("gift", &icons::solid::GIFT),
-    ("gifts", &icons::solid::GIFTS),
-    ("glasses", &icons::solid::GLASSES),
-    ("glass-cheers", &icons::solid::GLASS_CHEERS),
-    ("glass-martini", &icons::solid::GLASS_MARTINI),
-    ("glass-martini-alt", &icons::solid::GLASS_MARTINI_ALT),
-    ("glass-water", &icons::solid::GLASS_WATER),
-    ("glass-water-droplet", &icons::solid::GLASS_WATER_DROPLET),
-    ("glass-whiskey", &icons::solid::GLASS_WHISKEY),
-    ("globe", &icons::solid::GLOBE),
-    ("globe-africa", &icons::solid::GLOBE_AFRICA),
-    ("globe-americas", &icons::solid::GLOBE_AMERICAS),
-    ("globe-asia", &icons::solid::GLOBE_ASIA),
-    ("globe-europe", &icons::solid::GLOBE_EUROPE),
-    ("globe-oceania", &icons::solid::GLOBE_OCEANIA),
-    ("golf-ball", &icons::solid::GOLF_BALL),
-    ("golf-ball-tee", &icons::solid::GOLF_BALL_TEE),
-    ("gopuram", &icons::solid::GOPURAM),
-    ("graduation-cap", &icons::solid::GRADUATION_CAP),
-    ("greater-than", &icons::solid::GREATER_THAN),
-    ("greater-than-equal", &icons::solid::GREATER_THAN_EQUAL),
-    ("grid-horizontal", &icons::solid::GRID_HORIZONTAL),
-    ("grid-vertical", &icons::solid::GRID_VERTICAL),
-    ("grimace", &icons::solid::GRIMACE),
-    ("grin", &icons::solid::GRIN),
-    ("grin-alt", &icons::solid::GRIN_ALT),
-    ("grin-beam", &icons::solid::GRIN_BEAM),
-    ("grin-beam-sweat", &icons::solid::GRIN_BEAM_SWEAT),
-    ("grin-hearts", &icons::solid::GRIN_HEARTS),
-    ("grin-squint", &icons::solid::GRIN_SQUINT),
-    ("grin-squint-tears", &icons::solid::GRIN_SQUINT_TEARS),
-    ("grin-stars", &icons::solid::GRIN_STARS),
-    ("grin-tears", &icons::solid::GRIN_TEARS),
-    ("grin-tongue", &icons::solid::GRIN_TONGUE),
-    ("grin-tongue-squint", &icons::solid::GRIN_TONGUE_SQUINT),
-    ("grin-tongue-wink", &icons::solid::GRIN_TONGUE_WINK),
-    ("grin-wink", &icons::solid::GRIN_WINK),
-    ("grip", &icons::solid::GRIP),
-    ("grip-horizontal", &icons::solid::GRIP_HORIZONTAL),
-    ("grip-lines", &icons::solid::GRIP_LINES),
-    ("grip-lines-vertical", &icons::solid::GRIP_LINES_VERTICAL),
-    ("grip-vertical", &icons::solid::GRIP_VERTICAL),
-    ("group-arrows-rotate", &icons::solid::GROUP_ARROWS_ROTATE),
-    ("guarani-sign", &icons::solid::GUARANI_SIGN),
-    ("guitar", &icons::solid::GUITAR),
-    ("gun", &icons::solid::GUN),
-    ("h", &icons::solid::H),
-    ("hamburger", &icons::solid::HAMBURGER),
-    ("hammer", &icons::solid::HAMMER),
-    ("hamsa", &icons::solid::HAMSA),
-    ("hand", &icons::solid::HAND),
-    ("handcuffs", &icons::solid::HANDCUFFS),
-    ("hands", &icons::solid::HANDS),
-    ("handshake", &icons::solid::HANDSHAKE),
-    ("handshake-alt", &icons::solid::HANDSHAKE_ALT),
-    ("handshake-alt-slash", &icons::solid::HANDSHAKE_ALT_SLASH),
-    ("handshake-angle", &icons::solid::HANDSHAKE_ANGLE),
-    ("handshake-simple", &icons::solid::HANDSHAKE_SIMPLE),
-    ("handshake-simple-slash", &icons::solid::HANDSHAKE_SIMPLE_SLASH),
-    ("handshake-slash", &icons::solid::HANDSHAKE_SLASH),
-    ("hands-american-sign-language-interpreting", &icons::solid::HANDS_AMERICAN_SIGN_LANGUAGE_INTERPRETING),
-    ("hands-asl-interpreting", &icons::solid::HANDS_ASL_INTERPRETING),
-    ("hands-bound", &icons::solid::HANDS_BOUND),
-    ("hands-bubbles", &icons::solid::HANDS_BUBBLES),
-    ("hands-clapping", &icons::solid::HANDS_CLAPPING),
-    ("hands-helping", &icons::solid::HANDS_HELPING),
-    ("hands-holding", &icons::solid::HANDS_HOLDING),
-    ("hands-holding-child", &icons::solid::HANDS_HOLDING_CHILD),
-    ("hands-holding-circle", &icons::solid::HANDS_HOLDING_CIRCLE),
-    ("hands-praying", &icons::solid::HANDS_PRAYING),
-    ("hands-wash", &icons::solid::HANDS_WASH),
-    ("hand-back-fist", &icons::solid::HAND_BACK_FIST),
-    ("hand-dots", &icons::solid::HAND_DOTS),
-    ("hand-fist", &icons::solid::HAND_FIST),
-    ("hand-holding", &icons::solid::HAND_HOLDING),
-    ("hand-holding-dollar", &icons::solid::HAND_HOLDING_DOLLAR),
-    ("hand-holding-droplet", &icons::solid::HAND_HOLDING_DROPLET),
-    ("hand-holding-hand", &icons::solid::HAND_HOLDING_HAND),
-    ("hand-holding-heart", &icons::solid::HAND_HOLDING_HEART),
-    ("hand-holding-medical", &icons::solid::HAND_HOLDING_MEDICAL),
-    ("hand-holding-usd", &icons::solid::HAND_HOLDING_USD),
-    ("hand-holding-water", &icons::solid::HAND_HOLDING_WATER),
-    ("hand-lizard", &icons::solid::HAND_LIZARD),
-    ("hand-middle-finger", &icons::solid::HAND_MIDDLE_FINGER),
-    ("hand-paper", &icons::solid::HAND_PAPER),
-    ("hand-peace", &icons::solid::HAND_PEACE),
-    ("hand-pointer", &icons::solid::HAND_POINTER),
-    ("hand-point-down", &icons::solid::HAND_POINT_DOWN),
-    ("hand-point-left", &icons::solid::HAND_POINT_LEFT),
-    ("hand-point-right", &icons::solid::HAND_POINT_RIGHT),
-    ("hand-point-up", &icons::solid::HAND_POINT_UP),
-    ("hand-rock", &icons::solid::HAND_ROCK),
-    ("hand-scissors", &icons::solid::HAND_SCISSORS),
-    ("hand-sparkles", &icons::solid::HAND_SPARKLES),
-    ("hand-spock", &icons::solid::HAND_SPOCK),
-    ("hanukiah", &icons::solid::HANUKIAH),
-    ("hard-drive", &icons::solid::HARD_DRIVE),
-    ("hard-hat", &icons::solid::HARD_HAT),
-    ("hard-of-hearing", &icons::solid::HARD_OF_HEARING),
-    ("hashtag", &icons::solid::HASHTAG),
-    ("hat-cowboy", &icons::solid::HAT_COWBOY),
-    ("hat-cowboy-side", &icons::solid::HAT_COWBOY_SIDE),
-    ("hat-hard", &icons::solid::HAT_HARD),
-    ("hat-wizard", &icons::solid::HAT_WIZARD),
-    ("haykal", &icons::solid::HAYKAL),
-    ("hdd", &icons::solid::HDD),
-    ("header", &icons::solid::HEADER),
-    ("heading", &icons::solid::HEADING),
-    ("headphones", &icons::solid::HEADPHONES),
-    ("headphones-alt", &icons::solid::HEADPHONES_ALT),
-    ("headphones-simple", &icons::solid::HEADPHONES_SIMPLE),
-    ("headset", &icons::solid::HEADSET),
-    ("head-side-cough", &icons::solid::HEAD_SIDE_COUGH),
-    ("head-side-cough-slash", &icons::solid::HEAD_SIDE_COUGH_SLASH),
-    ("head-side-mask", &icons::solid::HEAD_SIDE_MASK),
-    ("head-side-virus", &icons::solid::HEAD_SIDE_VIRUS),
-    ("heart", &icons::solid::HEART),
-    ("heartbeat", &icons::solid::HEARTBEAT),
-    ("heart-broken", &icons::solid::HEART_BROKEN),
-    ("heart-circle-bolt", &icons::solid::HEART_CIRCLE_BOLT),
-    ("heart-circle-check", &icons::solid::HEART_CIRCLE_CHECK),
-    ("heart-circle-exclamation", &icons::solid::HEART_CIRCLE_EXCLAMATION),
-    ("heart-circle-minus", &icons::solid::HEART_CIRCLE_MINUS),
-    ("heart-circle-plus", &icons::solid::HEART_CIRCLE_PLUS),
-    ("heart-circle-xmark", &icons::solid::HEART_CIRCLE_XMARK),
-    ("heart-crack", &icons::solid::HEART_CRACK),
-    ("heart-music-camera-bolt", &icons::solid::HEART_MUSIC_CAMERA_BOLT),
-    ("heart-pulse", &icons::solid::HEART_PULSE),
-    ("helicopter", &icons::solid::HELICOPTER),
-    ("helicopter-symbol", &icons::solid::HELICOPTER_SYMBOL),
-    ("helmet-safety", &icons::solid::HELMET_SAFETY),
-    ("helmet-un", &icons::solid::HELMET_UN),
-    ("heptagon", &icons::solid::HEPTAGON),
-    ("hexagon", &icons::solid::HEXAGON),
-    ("hexagon-nodes", &icons::solid::HEXAGON_NODES),
-    ("hexagon-nodes-bolt", &icons::solid::HEXAGON_NODES_BOLT),
-    ("highlighter", &icons::solid::HIGHLIGHTER),
-    ("hiking", &icons::solid::HIKING),
-    ("hill-avalanche", &icons::solid::HILL_AVALANCHE),
-    ("hill-rockslide", &icons::solid::HILL_ROCKSLIDE),
-    ("hippo", &icons::solid::HIPPO),
-    ("history", &icons::solid::HISTORY),
-    ("hockey-puck", &icons::solid::HOCKEY_PUCK),
-    ("holly-berry", &icons::solid::HOLLY_BERRY),
-    ("home", &icons::solid::HOME),
-    ("home-alt", &icons::solid::HOME_ALT),
-    ("home-lg", &icons::solid::HOME_LG),
-    ("home-lg-alt", &icons::solid::HOME_LG_ALT),
-    ("home-user", &icons::solid::HOME_USER),
-    ("horse", &icons::solid::HORSE),
-    ("horse-head", &icons::solid::HORSE_HEAD),
-    ("hospital", &icons::solid::HOSPITAL),
-    ("hospital-alt", &icons::solid::HOSPITAL_ALT),
-    ("hospital-symbol", &icons::solid::HOSPITAL_SYMBOL),
-    ("hospital-user", &icons::solid::HOSPITAL_USER),
-    ("hospital-wide", &icons::solid::HOSPITAL_WIDE),
-    ("hotdog", &icons::solid::HOTDOG),
-    ("hotel", &icons::solid::HOTEL),
-    ("hot-tub", &icons::solid::HOT_TUB),
-    ("hot-tub-person", &icons::solid::HOT_TUB_PERSON),
-    ("hourglass", &icons::solid::HOURGLASS),
-    ("hourglass-1", &icons::solid::HOURGLASS_1),
-    ("hourglass-2", &icons::solid::HOURGLASS_2),
-    ("hourglass-3", &icons::solid::HOURGLASS_3),
-    ("hourglass-empty", &icons::solid::HOURGLASS_EMPTY),
-    ("hourglass-end", &icons::solid::HOURGLASS_END),
-    ("hourglass-half", &icons::solid::HOURGLASS_HALF),
-    ("hourglass-start", &icons::solid::HOURGLASS_START),
-    ("house", &icons::solid::HOUSE),
-    ("house-chimney", &icons::solid::HOUSE_CHIMNEY),
-    ("house-chimney-crack", &icons::solid::HOUSE_CHIMNEY_CRACK),
-    ("house-chimney-medical", &icons::solid::HOUSE_CHIMNEY_MEDICAL),
-    ("house-chimney-user", &icons::solid::HOUSE_CHIMNEY_USER),
-    ("house-chimney-window", &icons::solid::HOUSE_CHIMNEY_WINDOW),
-    ("house-circle-check", &icons::solid::HOUSE_CIRCLE_CHECK),
-    ("house-circle-exclamation", &icons::solid::HOUSE_CIRCLE_EXCLAMATION),
-    ("house-circle-xmark", &icons::solid::HOUSE_CIRCLE_XMARK),
-    ("house-crack", &icons::solid::HOUSE_CRACK),
-    ("house-damage", &icons::solid::HOUSE_DAMAGE),
-    ("house-fire", &icons::solid::HOUSE_FIRE),
-    ("house-flag", &icons::solid::HOUSE_FLAG),
-    ("house-flood-water", &icons::solid::HOUSE_FLOOD_WATER),
-    ("house-flood-water-circle-arrow-right", &icons::solid::HOUSE_FLOOD_WATER_CIRCLE_ARROW_RIGHT),
-    ("house-laptop", &icons::solid::HOUSE_LAPTOP),
-    ("house-lock", &icons::solid::HOUSE_LOCK),
-    ("house-medical", &icons::solid::HOUSE_MEDICAL),
-    ("house-medical-circle-check", &icons::solid::HOUSE_MEDICAL_CIRCLE_CHECK),
-    ("house-medical-circle-exclamation", &icons::solid::HOUSE_MEDICAL_CIRCLE_EXCLAMATION),
-    ("house-medical-circle-xmark", &icons::solid::HOUSE_MEDICAL_CIRCLE_XMARK),
-    ("house-medical-flag", &icons::solid::HOUSE_MEDICAL_FLAG),
-    ("house-signal", &icons::solid::HOUSE_SIGNAL),
-    ("house-tsunami", &icons::solid::HOUSE_TSUNAMI),
-    ("house-user", &icons::solid::HOUSE_USER),
-    ("hryvnia", &icons::solid::HRYVNIA),
-    ("hryvnia-sign", &icons::solid::HRYVNIA_SIGN),
-    ("hurricane", &icons::solid::HURRICANE),
-    ("h-square", &icons::solid::H_SQUARE),
-    ("i", &icons::solid::I),
-    ("ice-cream", &icons::solid::ICE_CREAM),
-    ("icicles", &icons::solid::ICICLES),
-    ("icons", &icons::solid::ICONS),
-    ("icon-0", &icons::solid::ICON_0),
-    ("icon-1", &icons::solid::ICON_1),
-    ("icon-2", &icons::solid::ICON_2),
-    ("icon-3", &icons::solid::ICON_3),
-    ("icon-4", &icons::solid::ICON_4),
-    ("icon-5", &icons::solid::ICON_5),
-    ("icon-6", &icons::solid::ICON_6),
-    ("icon-7", &icons::solid::ICON_7),
-    ("icon-8", &icons::solid::ICON_8),
-    ("icon-9", &icons::solid::ICON_9),
-    ("id-badge", &icons::solid::ID_BADGE),
-    ("id-card", &icons::solid::ID_CARD),
-    ("id-card-alt", &icons::solid::ID_CARD_ALT),
-    ("id-card-clip", &icons::solid::ID_CARD_CLIP),
-    ("igloo", &icons::solid::IGLOO),
-    ("ils", &icons::solid::ILS),
-    ("image", &icons::solid::IMAGE),
-    ("images", &icons::solid::IMAGES),
-    ("image-portrait", &icons::solid::IMAGE_PORTRAIT),
-    ("inbox", &icons::solid::INBOX),
-    ("indent", &icons::solid::INDENT),
-    ("indian-rupee", &icons::solid::INDIAN_RUPEE),
-    ("indian-rupee-sign", &icons::solid::INDIAN_RUPEE_SIGN),
-    ("industry", &icons::solid::INDUSTRY),
-    ("infinity", &icons::solid::INFINITY),
-    ("info", &icons::solid::INFO),
-    ("info-circle", &icons::solid::INFO_CIRCLE),
-    ("inr", &icons::solid::INR),
-    ("institution", &icons::solid::INSTITUTION),
-    ("italic", &icons::solid::ITALIC),
-    ("i-cursor", &icons::solid::I_CURSOR),
-    ("j", &icons::solid::J),
-    ("jar", &icons::solid::JAR),
-    ("jar-wheat", &icons::solid::JAR_WHEAT),
-    ("jedi", &icons::solid::JEDI),
-    ("jet-fighter", &icons::solid::JET_FIGHTER),
-    ("jet-fighter-up", &icons::solid::JET_FIGHTER_UP),
-    ("joint", &icons::solid::JOINT),
-    ("journal-whills", &icons::solid::JOURNAL_WHILLS),
-    ("jpy", &icons::solid::JPY),
-    ("jug-detergent", &icons::solid::JUG_DETERGENT),
-    ("k", &icons::solid::K),
-    ("kaaba", &icons::solid::KAABA),
-    ("key", &icons::solid::KEY),
-    ("keyboard", &icons::solid::KEYBOARD),
-    ("khanda", &icons::solid::KHANDA),
-    ("kip-sign", &icons::solid::KIP_SIGN),
-    ("kiss", &icons::solid::KISS),
-    ("kiss-beam", &icons::solid::KISS_BEAM),
-    ("kiss-wink-heart", &icons::solid::KISS_WINK_HEART),
-    ("kitchen-set", &icons::solid::KITCHEN_SET),
-    ("kit-medical", &icons::solid::KIT_MEDICAL),
-    ("kiwi-bird", &icons::solid::KIWI_BIRD),
-    ("krw", &icons::solid::KRW),
-    ("l", &icons::solid::L),
-    ("ladder-water", &icons::solid::LADDER_WATER),
-    ("landmark", &icons::solid::LANDMARK),
-    ("landmark-alt", &icons::solid::LANDMARK_ALT),
-    ("landmark-dome", &icons::solid::LANDMARK_DOME),
-    ("landmark-flag", &icons::solid::LANDMARK_FLAG),
-    ("land-mine-on", &icons::solid::LAND_MINE_ON),
-    ("language", &icons::solid::LANGUAGE),
-    ("laptop", &icons::solid::LAPTOP),
-    ("laptop-code", &icons::solid::LAPTOP_CODE),
-    ("laptop-file", &icons::solid::LAPTOP_FILE),
-    ("laptop-house", &icons::solid::LAPTOP_HOUSE),
-    ("laptop-medical", &icons::solid::LAPTOP_MEDICAL),
-    ("lari-sign", &icons::solid::LARI_SIGN),
-    ("laugh", &icons::solid::LAUGH),
-    ("laugh-beam", &icons::solid::LAUGH_BEAM),
-    ("laugh-squint", &icons::solid::LAUGH_SQUINT),
-    ("laugh-wink", &icons::solid::LAUGH_WINK),
-    ("layer-group", &icons::solid::LAYER_GROUP),
-    ("leaf", &icons::solid::LEAF),
-    ("left-long", &icons::solid::LEFT_LONG),
-    ("left-right", &icons::solid::LEFT_RIGHT),
-    ("legal", &icons::solid::LEGAL),
-    ("lemon", &icons::solid::LEMON),
-    ("less-than", &icons::solid::LESS_THAN),
-    ("less-than-equal", &icons::solid::LESS_THAN_EQUAL),
-    ("level-down", &icons::solid::LEVEL_DOWN),
-    ("level-down-alt", &icons::solid::LEVEL_DOWN_ALT),
-    ("level-up", &icons::solid::LEVEL_UP),
-    ("level-up-alt", &icons::solid::LEVEL_UP_ALT),
-    ("life-ring", &icons::solid::LIFE_RING),
-    ("lightbulb", &icons::solid::LIGHTBULB),
-    ("lines-leaning", &icons::solid::LINES_LEANING),
-    ("line-chart", &icons::solid::LINE_CHART),
-    ("link", &icons::solid::LINK),
-    ("link-slash", &icons::solid::LINK_SLASH),
-    ("lira-sign", &icons::solid::LIRA_SIGN),
-    ("list", &icons::solid::LIST),
-    ("list-1-2", &icons::solid::LIST_1_2),
-    ("list-alt", &icons::solid::LIST_ALT),
-    ("list-check", &icons::solid::LIST_CHECK),
-    ("list-dots", &icons::solid::LIST_DOTS),
-    ("list-numeric", &icons::solid::LIST_NUMERIC),
-    ("list-ol", &icons::solid::LIST_OL),
-    ("list-squares", &icons::solid::LIST_SQUARES),
-    ("list-ul", &icons::solid::LIST_UL),
-    ("litecoin-sign", &icons::solid::LITECOIN_SIGN),
-    ("location", &icons::solid::LOCATION),
-    ("location-arrow", &icons::solid::LOCATION_ARROW),
-    ("location-crosshairs", &icons::solid::LOCATION_CROSSHAIRS),
-    ("location-dot", &icons::solid::LOCATION_DOT),
-    ("location-pin", &icons::solid::LOCATION_PIN),
-    ("location-pin-lock", &icons::solid::LOCATION_PIN_LOCK),
-    ("lock", &icons::solid::LOCK),
-    ("lock-open", &icons::solid::LOCK_OPEN),
-    ("locust", &icons::solid::LOCUST),
-    ("long-arrow-alt-down", &icons::solid::LONG_ARROW_ALT_DOWN),
-    ("long-arrow-alt-left", &icons::solid::LONG_ARROW_ALT_LEFT),
-    ("long-arrow-alt-right", &icons::solid::LONG_ARROW_ALT_RIGHT),
-    ("long-arrow-alt-up", &icons::solid::LONG_ARROW_ALT_UP),
-    ("long-arrow-down", &icons::solid::LONG_ARROW_DOWN),
-    ("long-arrow-left", &icons::solid::LONG_ARROW_LEFT),
-    ("long-arrow-right", &icons::solid::LONG_ARROW_RIGHT),
-    ("long-arrow-up", &icons::solid::LONG_ARROW_UP),
-    ("low-vision", &icons::solid::LOW_VISION),
-    ("luggage-cart", &icons::solid::LUGGAGE_CART),
-    ("lungs", &icons::solid::LUNGS),
-    ("lungs-virus", &icons::solid::LUNGS_VIRUS),
-    ("m", &icons::solid::M),
-    ("magic", &icons::solid::MAGIC),
-    ("magic-wand-sparkles", &icons::solid::MAGIC_WAND_SPARKLES),
-    ("magnet", &icons::solid::MAGNET),
-    ("magnifying-glass", &icons::solid::MAGNIFYING_GLASS),
-    ("magnifying-glass-arrow-right", &icons::solid::MAGNIFYING_GLASS_ARROW_RIGHT),
-    ("magnifying-glass-chart", &icons::solid::MAGNIFYING_GLASS_CHART),
-    ("magnifying-glass-dollar", &icons::solid::MAGNIFYING_GLASS_DOLLAR),
-    ("magnifying-glass-location", &icons::solid::MAGNIFYING_GLASS_LOCATION),
-    ("magnifying-glass-minus", &icons::solid::MAGNIFYING_GLASS_MINUS),
-    ("magnifying-glass-plus", &icons::solid::MAGNIFYING_GLASS_PLUS),
-    ("mail-bulk", &icons::solid::MAIL_BULK),
-    ("mail-forward", &icons::solid::MAIL_FORWARD),
-    ("mail-reply", &icons::solid::MAIL_REPLY),
-    ("mail-reply-all", &icons::solid::MAIL_REPLY_ALL),
-    ("male", &icons::solid::MALE),
-    ("manat-sign", &icons::solid::MANAT_SIGN),
-    ("map", &icons::solid::MAP),
-    ("map-location", &icons::solid::MAP_LOCATION),
-    ("map-location-dot", &icons::solid::MAP_LOCATION_DOT),
-    ("map-marked", &icons::solid::MAP_MARKED),
-    ("map-marked-alt", &icons::solid::MAP_MARKED_ALT),
-    ("map-marker", &icons::solid::MAP_MARKER),
-    ("map-marker-alt", &icons::solid::MAP_MARKER_ALT),
-    ("map-pin", &icons::solid::MAP_PIN),
-    ("map-signs", &icons::solid::MAP_SIGNS),
-    ("marker", &icons::solid::MARKER),
-    ("mars", &icons::solid::MARS),
-    ("mars-and-venus", &icons::solid::MARS_AND_VENUS),
-    ("mars-and-venus-burst", &icons::solid::MARS_AND_VENUS_BURST),
-    ("mars-double", &icons::solid::MARS_DOUBLE),
-    ("mars-stroke", &icons::solid::MARS_STROKE),
-    ("mars-stroke-h", &icons::solid::MARS_STROKE_H),
-    ("mars-stroke-right", &icons::solid::MARS_STROKE_RIGHT),
-    ("mars-stroke-up", &icons::solid::MARS_STROKE_UP),
-    ("mars-stroke-v", &icons::solid::MARS_STROKE_V),
-    ("martini-glass", &icons::solid::MARTINI_GLASS),
-    ("martini-glass-citrus", &icons::solid::MARTINI_GLASS_CITRUS),
-    ("martini-glass-empty", &icons::solid::MARTINI_GLASS_EMPTY),
-    ("mask", &icons::solid::MASK),
-    ("masks-theater", &icons::solid::MASKS_THEATER),
-    ("mask-face", &icons::solid::MASK_FACE),
-    ("mask-ventilator", &icons::solid::MASK_VENTILATOR),
-    ("mattress-pillow", &icons::solid::MATTRESS_PILLOW),
-    ("maximize", &icons::solid::MAXIMIZE),
-    ("medal", &icons::solid::MEDAL),
-    ("medkit", &icons::solid::MEDKIT),
-    ("meh", &icons::solid::MEH),
-    ("meh-blank", &icons::solid::MEH_BLANK),
-    ("meh-rolling-eyes", &icons::solid::MEH_ROLLING_EYES),
-    ("memory", &icons::solid::MEMORY),
-    ("menorah", &icons::solid::MENORAH),
-    ("mercury", &icons::solid::MERCURY),
-    ("message", &icons::solid::MESSAGE),
-    ("meteor", &icons::solid::METEOR),
-    ("microchip", &icons::solid::MICROCHIP),
-    ("microphone", &icons::solid::MICROPHONE),
-    ("microphone-alt", &icons::solid::MICROPHONE_ALT),
-    ("microphone-alt-slash", &icons::solid::MICROPHONE_ALT_SLASH),
-    ("microphone-lines", &icons::solid::MICROPHONE_LINES),
-    ("microphone-lines-slash", &icons::solid::MICROPHONE_LINES_SLASH),
-    ("microphone-slash", &icons::solid::MICROPHONE_SLASH),
-    ("microscope", &icons::solid::MICROSCOPE),
-    ("mill-sign", &icons::solid::MILL_SIGN),
-    ("minimize", &icons::solid::MINIMIZE),
-    ("minus", &icons::solid::MINUS),
-    ("minus-circle", &icons::solid::MINUS_CIRCLE),
-    ("minus-square", &icons::solid::MINUS_SQUARE),
-    ("mitten", &icons::solid::MITTEN),
-    ("mobile", &icons::solid::MOBILE),
-    ("mobile-alt", &icons::solid::MOBILE_ALT),
-    ("mobile-android", &icons::solid::MOBILE_ANDROID),
-    ("mobile-android-alt", &icons::solid::MOBILE_ANDROID_ALT),
-    ("mobile-button", &icons::solid::MOBILE_BUTTON),
-    ("mobile-phone", &icons::solid::MOBILE_PHONE),
-    ("mobile-retro", &icons::solid::MOBILE_RETRO),
-    ("mobile-screen", &icons::solid::MOBILE_SCREEN),
-    ("mobile-screen-button", &icons::solid::MOBILE_SCREEN_BUTTON),
-    ("mobile-vibrate", &icons::solid::MOBILE_VIBRATE),
-    ("money-bill", &icons::solid::MONEY_BILL),
-    ("money-bills", &icons::solid::MONEY_BILLS),
-    ("money-bill-1", &icons::solid::MONEY_BILL_1),
-    ("money-bill-1-wave", &icons::solid::MONEY_BILL_1_WAVE),
-    ("money-bill-alt", &icons::solid::MONEY_BILL_ALT),
-    ("money-bill-transfer", &icons::solid::MONEY_BILL_TRANSFER),
-    ("money-bill-trend-up", &icons::solid::MONEY_BILL_TREND_UP),
-    ("money-bill-wave", &icons::solid::MONEY_BILL_WAVE),
-    ("money-bill-wave-alt", &icons::solid::MONEY_BILL_WAVE_ALT),
-    ("money-bill-wheat", &icons::solid::MONEY_BILL_WHEAT),
-    ("money-check", &icons::solid::MONEY_CHECK),
-    ("money-check-alt", &icons::solid::MONEY_CHECK_ALT),
-    ("money-check-dollar", &icons::solid::MONEY_CHECK_DOLLAR),
-    ("monument", &icons::solid::MONUMENT),
-    ("moon", &icons::solid::MOON),
-    ("mortar-board", &icons::solid::MORTAR_BOARD),
-    ("mortar-pestle", &icons::solid::MORTAR_PESTLE),
-    ("mosque", &icons::solid::MOSQUE),
-    ("mosquito", &icons::solid::MOSQUITO),
-    ("mosquito-net", &icons::solid::MOSQUITO_NET),
-    ("motorcycle", &icons::solid::MOTORCYCLE),
-    ("mound", &icons::solid::MOUND),
-    ("mountain", &icons::solid::MOUNTAIN),
-    ("mountain-city", &icons::solid::MOUNTAIN_CITY),
-    ("mountain-sun", &icons::solid::MOUNTAIN_SUN),
-    ("mouse", &icons::solid::MOUSE),
-    ("mouse-pointer", &icons::solid::MOUSE_POINTER),
-    ("mug-hot", &icons::solid::MUG_HOT),
-    ("mug-saucer", &icons::solid::MUG_SAUCER),
-    ("multiply", &icons::solid::MULTIPLY),
-    ("museum", &icons::solid::MUSEUM),
-    ("music", &icons::solid::MUSIC),
-    ("n", &icons::solid::N),
-    ("naira-sign", &icons::solid::NAIRA_SIGN),
-    ("navicon", &icons::solid::NAVICON),
-    ("network-wired", &icons::solid::NETWORK_WIRED),
-    ("neuter", &icons::solid::NEUTER),
-    ("newspaper", &icons::solid::NEWSPAPER),
-    ("non-binary", &icons::solid::NON_BINARY),
-    ("notdef", &icons::solid::NOTDEF),
-    ("notes-medical", &icons::solid::NOTES_MEDICAL),
-    ("note-sticky", &icons::solid::NOTE_STICKY),
-    ("not-equal", &icons::solid::NOT_EQUAL),
-    ("o", &icons::solid::O),
-    ("object-group", &icons::solid::OBJECT_GROUP),
-    ("object-ungroup", &icons::solid::OBJECT_UNGROUP),
-    ("octagon", &icons::solid::OCTAGON),
-    ("oil-can", &icons::solid::OIL_CAN),
-    ("oil-well", &icons::solid::OIL_WELL),
-    ("om", &icons::solid::OM),
-    ("otter", &icons::solid::OTTER),
-    ("outdent", &icons::solid::OUTDENT),
-    ("p", &icons::solid::P),
-    ("pager", &icons::solid::PAGER),
-    ("paintbrush", &icons::solid::PAINTBRUSH),
-    ("paint-brush", &icons::solid::PAINT_BRUSH),
-    ("paint-roller", &icons::solid::PAINT_ROLLER),
-    ("palette", &icons::solid::PALETTE),
-    ("pallet", &icons::solid::PALLET),
-    ("panorama", &icons::solid::PANORAMA),
-    ("paperclip", &icons::solid::PAPERCLIP),
-    ("paper-plane", &icons::solid::PAPER_PLANE),
-    ("parachute-box", &icons::solid::PARACHUTE_BOX),
-    ("paragraph", &icons::solid::PARAGRAPH),
-    ("parking", &icons::solid::PARKING),
-    ("passport", &icons::solid::PASSPORT),
-    ("pastafarianism", &icons::solid::PASTAFARIANISM),
-    ("paste", &icons::solid::PASTE),
-    ("pause", &icons::solid::PAUSE),
-    ("pause-circle", &icons::solid::PAUSE_CIRCLE),
-    ("paw", &icons::solid::PAW),
-    ("peace", &icons::solid::PEACE),
-    ("pen", &icons::solid::PEN),
-    ("pencil", &icons::solid::PENCIL),
-    ("pencil-alt", &icons::solid::PENCIL_ALT),
-    ("pencil-ruler", &icons::solid::PENCIL_RULER),
-    ("pencil-square", &icons::solid::PENCIL_SQUARE),
-    ("pentagon", &icons::solid::PENTAGON),
-    ("pen-alt", &icons::solid::PEN_ALT),
-    ("pen-clip", &icons::solid::PEN_CLIP),
-    ("pen-fancy", &icons::solid::PEN_FANCY),
-    ("pen-nib", &icons::solid::PEN_NIB),
-    ("pen-ruler", &icons::solid::PEN_RULER),
-    ("pen-square", &icons::solid::PEN_SQUARE),
-    ("pen-to-square", &icons::solid::PEN_TO_SQUARE),
-    ("people-arrows", &icons::solid::PEOPLE_ARROWS),
-    ("people-arrows-left-right", &icons::solid::PEOPLE_ARROWS_LEFT_RIGHT),
-    ("people-carry", &icons::solid::PEOPLE_CARRY),
-    ("people-carry-box", &icons::solid::PEOPLE_CARRY_BOX),
-    ("people-group", &icons::solid::PEOPLE_GROUP),
-    ("people-line", &icons::solid::PEOPLE_LINE),
-    ("people-pulling", &icons::solid::PEOPLE_PULLING),
-    ("people-robbery", &icons::solid::PEOPLE_ROBBERY),
-    ("people-roof", &icons::solid::PEOPLE_ROOF),
-    ("pepper-hot", &icons::solid::PEPPER_HOT),
-    ("percent", &icons::solid::PERCENT),
-    ("percentage", &icons::solid::PERCENTAGE),
-    ("person", &icons::solid::PERSON),
-    ("person-arrow-down-to-line", &icons::solid::PERSON_ARROW_DOWN_TO_LINE),
-    ("person-arrow-up-from-line", &icons::solid::PERSON_ARROW_UP_FROM_LINE),
-    ("person-biking", &icons::solid::PERSON_BIKING),
-    ("person-booth", &icons::solid::PERSON_BOOTH),
-    ("person-breastfeeding", &icons::solid::PERSON_BREASTFEEDING),
-    ("person-burst", &icons::solid::PERSON_BURST),
-    ("person-cane", &icons::solid::PERSON_CANE),
-    ("person-chalkboard", &icons::solid::PERSON_CHALKBOARD),
-    ("person-circle-check", &icons::solid::PERSON_CIRCLE_CHECK),
-    ("person-circle-exclamation", &icons::solid::PERSON_CIRCLE_EXCLAMATION),
-    ("person-circle-minus", &icons::solid::PERSON_CIRCLE_MINUS),
-    ("person-circle-plus", &icons::solid::PERSON_CIRCLE_PLUS),
-    ("person-circle-question", &icons::solid::PERSON_CIRCLE_QUESTION),
-    ("person-circle-xmark", &icons::solid::PERSON_CIRCLE_XMARK),
-    ("person-digging", &icons::solid::PERSON_DIGGING),
-    ("person-dots-from-line", &icons::solid::PERSON_DOTS_FROM_LINE),
-    ("person-dress", &icons::solid::PERSON_DRESS),
-    ("person-dress-burst", &icons::solid::PERSON_DRESS_BURST),
-    ("person-drowning", &icons::solid::PERSON_DROWNING),
-    ("person-falling", &icons::solid::PERSON_FALLING),
-    ("person-falling-burst", &icons::solid::PERSON_FALLING_BURST),
-    ("person-half-dress", &icons::solid::PERSON_HALF_DRESS),
-    ("person-harassing", &icons::solid::PERSON_HARASSING),
-    ("person-hiking", &icons::solid::PERSON_HIKING),
-    ("person-military-pointing", &icons::solid::PERSON_MILITARY_POINTING),
-    ("person-military-rifle", &icons::solid::PERSON_MILITARY_RIFLE),
-    ("person-military-to-person", &icons::solid::PERSON_MILITARY_TO_PERSON),
-    ("person-praying", &icons::solid::PERSON_PRAYING),
-    ("person-pregnant", &icons::solid::PERSON_PREGNANT),
-    ("person-rays", &icons::solid::PERSON_RAYS),
-    ("person-rifle", &icons::solid::PERSON_RIFLE),
-    ("person-running", &icons::solid::PERSON_RUNNING),
-    ("person-shelter", &icons::solid::PERSON_SHELTER),
-    ("person-skating", &icons::solid::PERSON_SKATING),
-    ("person-skiing", &icons::solid::PERSON_SKIING),
-    ("person-skiing-nordic", &icons::solid::PERSON_SKIING_NORDIC),
-    ("person-snowboarding", &icons::solid::PERSON_SNOWBOARDING),
-    ("person-swimming", &icons::solid::PERSON_SWIMMING),
-    ("person-through-window", &icons::solid::PERSON_THROUGH_WINDOW),
-    ("person-walking", &icons::solid::PERSON_WALKING),
-    ("person-walking-arrow-loop-left", &icons::solid::PERSON_WALKING_ARROW_LOOP_LEFT),
-    ("person-walking-arrow-right", &icons::solid::PERSON_WALKING_ARROW_RIGHT),
-    ("person-walking-dashed-line-arrow-right", &icons::solid::PERSON_WALKING_DASHED_LINE_ARROW_RIGHT),
-    ("person-walking-luggage", &icons::solid::PERSON_WALKING_LUGGAGE),
-    ("person-walking-with-cane", &icons::solid::PERSON_WALKING_WITH_CANE),
-    ("peseta-sign", &icons::solid::PESETA_SIGN),
-    ("peso-sign", &icons::solid::PESO_SIGN),
-    ("phone", &icons::solid::PHONE),
-    ("phone-alt", &icons::solid::PHONE_ALT),
-    ("phone-flip", &icons::solid::PHONE_FLIP),
-    ("phone-slash", &icons::solid::PHONE_SLASH),
-    ("phone-square", &icons::solid::PHONE_SQUARE),
-    ("phone-square-alt", &icons::solid::PHONE_SQUARE_ALT),
-    ("phone-volume", &icons::solid::PHONE_VOLUME),
-    ("photo-film", &icons::solid::PHOTO_FILM),
-    ("photo-video", &icons::solid::PHOTO_VIDEO),
-    ("pie-chart", &icons::solid::PIE_CHART),
-    ("piggy-bank", &icons::solid::PIGGY_BANK),
-    ("pills", &icons::solid::PILLS),
-    ("ping-pong-paddle-ball", &icons::solid::PING_PONG_PADDLE_BALL),
-    ("pizza-slice", &icons::solid::PIZZA_SLICE),
-    ("place-of-worship", &icons::solid::PLACE_OF_WORSHIP),
-    ("plane", &icons::solid::PLANE),
-    ("plane-arrival", &icons::solid::PLANE_ARRIVAL),
-    ("plane-circle-check", &icons::solid::PLANE_CIRCLE_CHECK),
-    ("plane-circle-exclamation", &icons::solid::PLANE_CIRCLE_EXCLAMATION),
-    ("plane-circle-xmark", &icons::solid::PLANE_CIRCLE_XMARK),
-    ("plane-departure", &icons::solid::PLANE_DEPARTURE),
-    ("plane-lock", &icons::solid::PLANE_LOCK),
-    ("plane-slash", &icons::solid::PLANE_SLASH),
-    ("plane-up", &icons::solid::PLANE_UP),
-    ("plant-wilt", &icons::solid::PLANT_WILT),
-    ("plate-wheat", &icons::solid::PLATE_WHEAT),
-    ("play", &icons::solid::PLAY),
-    ("play-circle", &icons::solid::PLAY_CIRCLE),
-    ("plug", &icons::solid::PLUG),
-    ("plug-circle-bolt", &icons::solid::PLUG_CIRCLE_BOLT),
-    ("plug-circle-check", &icons::solid::PLUG_CIRCLE_CHECK),
-    ("plug-circle-exclamation", &icons::solid::PLUG_CIRCLE_EXCLAMATION),
-    ("plug-circle-minus", &icons::solid::PLUG_CIRCLE_MINUS),
-    ("plug-circle-plus", &icons::solid::PLUG_CIRCLE_PLUS),
-    ("plug-circle-xmark", &icons::solid::PLUG_CIRCLE_XMARK),
-    ("plus", &icons::solid::PLUS),
-    ("plus-circle", &icons::solid::PLUS_CIRCLE),
-    ("plus-minus", &icons::solid::PLUS_MINUS),
-    ("plus-square", &icons::solid::PLUS_SQUARE),
-    ("podcast", &icons::solid::PODCAST),
-    ("poll", &icons::solid::POLL),
-    ("poll-h", &icons::solid::POLL_H),
-    ("poo", &icons::solid::POO),
-    ("poop", &icons::solid::POOP),
-    ("poo-bolt", &icons::solid::POO_BOLT),
-    ("poo-storm", &icons::solid::POO_STORM),
-    ("portrait", &icons::solid::PORTRAIT),
-    ("pound-sign", &icons::solid::POUND_SIGN),
-    ("power-off", &icons::solid::POWER_OFF),
-    ("pray", &icons::solid::PRAY),
-    ("praying-hands", &icons::solid::PRAYING_HANDS),
-    ("prescription", &icons::solid::PRESCRIPTION),
-    ("prescription-bottle", &icons::solid::PRESCRIPTION_BOTTLE),
-    ("prescription-bottle-alt", &icons::solid::PRESCRIPTION_BOTTLE_ALT),
-    ("prescription-bottle-medical", &icons::solid::PRESCRIPTION_BOTTLE_MEDICAL),
-    ("print", &icons::solid::PRINT),
-    ("procedures", &icons::solid::PROCEDURES),
-    ("project-diagram", &icons::solid::PROJECT_DIAGRAM),
-    ("pump-medical", &icons::solid::PUMP_MEDICAL),
-    ("pump-soap", &icons::solid::PUMP_SOAP),
-    ("puzzle-piece", &icons::solid::PUZZLE_PIECE),
-    ("q", &icons::solid::Q),
-    ("qrcode", &icons::solid::QRCODE),
-    ("question", &icons::solid::QUESTION),
-    ("question-circle", &icons::solid::QUESTION_CIRCLE),
-    ("quidditch", &icons::solid::QUIDDITCH),
-    ("quidditch-broom-ball", &icons::solid::QUIDDITCH_BROOM_BALL),
-    ("quote-left", &icons::solid::QUOTE_LEFT),
-    ("quote-left-alt", &icons::solid::QUOTE_LEFT_ALT),
-    ("quote-right", &icons::solid::QUOTE_RIGHT),
-    ("quote-right-alt", &icons::solid::QUOTE_RIGHT_ALT),
-    ("quran", &icons::solid::QURAN),
-    ("r", &icons::solid::R),
-    ("radiation", &icons::solid::RADIATION),
-    ("radiation-alt", &icons::solid::RADIATION_ALT),
-    ("radio", &icons::solid::RADIO),
-    ("rainbow", &icons::solid::RAINBOW),
-    ("random", &icons::solid::RANDOM),
-    ("ranking-star", &icons::solid::RANKING_STAR),
-    ("receipt", &icons::solid::RECEIPT),
-    ("record-vinyl", &icons::solid::RECORD_VINYL),
-    ("rectangle-ad", &icons::solid::RECTANGLE_AD),
-    ("rectangle-list", &icons::solid::RECTANGLE_LIST),
-    ("rectangle-times", &icons::solid::RECTANGLE_TIMES),
-    ("rectangle-xmark", &icons::solid::RECTANGLE_XMARK),
-    ("recycle", &icons::solid::RECYCLE),
-    ("redo", &icons::solid::REDO),
-    ("redo-alt", &icons::solid::REDO_ALT),
-    ("refresh", &icons::solid::REFRESH),
-    ("registered", &icons::solid::REGISTERED),
-    ("remove", &icons::solid::REMOVE),
-    ("remove-format", &icons::solid::REMOVE_FORMAT),
-    ("reorder", &icons::solid::REORDER),
-    ("repeat", &icons::solid::REPEAT),
-    ("reply", &icons::solid::REPLY),
-    ("reply-all", &icons::solid::REPLY_ALL),
-    ("republican", &icons::solid::REPUBLICAN),
-    ("restroom", &icons::solid::RESTROOM),
-    ("retweet", &icons::solid::RETWEET),
-    ("ribbon", &icons::solid::RIBBON),
-    ("right-from-bracket", &icons::solid::RIGHT_FROM_BRACKET),
-    ("right-left", &icons::solid::RIGHT_LEFT),
-    ("right-long", &icons::solid::RIGHT_LONG),
-    ("right-to-bracket", &icons::solid::RIGHT_TO_BRACKET),
-    ("ring", &icons::solid::RING),
-    ("rmb", &icons::solid::RMB),
-    ("road", &icons::solid::ROAD),
-    ("road-barrier", &icons::solid::ROAD_BARRIER),
-    ("road-bridge", &icons::solid::ROAD_BRIDGE),
-    ("road-circle-check", &icons::solid::ROAD_CIRCLE_CHECK),
-    ("road-circle-exclamation", &icons::solid::ROAD_CIRCLE_EXCLAMATION),
-    ("road-circle-xmark", &icons::solid::ROAD_CIRCLE_XMARK),
-    ("road-lock", &icons::solid::ROAD_LOCK),
-    ("road-spikes", &icons::solid::ROAD_SPIKES),
-    ("robot", &icons::solid::ROBOT),
-    ("rocket", &icons::solid::ROCKET),
-    ("rod-asclepius", &icons::solid::ROD_ASCLEPIUS),
-    ("rod-snake", &icons::solid::ROD_SNAKE),
-    ("rotate", &icons::solid::ROTATE),
-    ("rotate-back", &icons::solid::ROTATE_BACK),
-    ("rotate-backward", &icons::solid::ROTATE_BACKWARD),
-    ("rotate-forward", &icons::solid::ROTATE_FORWARD),
-    ("rotate-left", &icons::solid::ROTATE_LEFT),
-    ("rotate-right", &icons::solid::ROTATE_RIGHT),
-    ("rouble", &icons::solid::ROUBLE),
-    ("route", &icons::solid::ROUTE),
-    ("rss", &icons::solid::RSS),
-    ("rss-square", &icons::solid::RSS_SQUARE),
-    ("rub", &icons::solid::RUB),
-    ("ruble", &icons::solid::RUBLE),
-    ("ruble-sign", &icons::solid::RUBLE_SIGN),
-    ("rug", &icons::solid::RUG),
-    ("ruler", &icons::solid::RULER),
-    ("ruler-combined", &icons::solid::RULER_COMBINED),
-    ("ruler-horizontal", &icons::solid::RULER_HORIZONTAL),
-    ("ruler-vertical", &icons::solid::RULER_VERTICAL),
-    ("running", &icons::solid::RUNNING),
-    ("rupee", &icons::solid::RUPEE),
-    ("rupee-sign", &icons::solid::RUPEE_SIGN),
-    ("rupiah-sign", &icons::solid::RUPIAH_SIGN),
-    ("s", &icons::solid::S),
-    ("sack-dollar", &icons::solid::SACK_DOLLAR),
-    ("sack-xmark", &icons::solid::SACK_XMARK),
-    ("sad-cry", &icons::solid::SAD_CRY),
-    ("sad-tear", &icons::solid::SAD_TEAR),
-    ("sailboat", &icons::solid::SAILBOAT),
-    ("satellite", &icons::solid::SATELLITE),
-    ("satellite-dish", &icons::solid::SATELLITE_DISH),
-    ("save", &icons::solid::SAVE),
-    ("scale-balanced", &icons::solid::SCALE_BALANCED),
-    ("scale-unbalanced", &icons::solid::SCALE_UNBALANCED),
-    ("scale-unbalanced-flip", &icons::solid::SCALE_UNBALANCED_FLIP),
-    ("school", &icons::solid::SCHOOL),
-    ("school-circle-check", &icons::solid::SCHOOL_CIRCLE_CHECK),
-    ("school-circle-exclamation", &icons::solid::SCHOOL_CIRCLE_EXCLAMATION),
-    ("school-circle-xmark", &icons::solid::SCHOOL_CIRCLE_XMARK),
-    ("school-flag", &icons::solid::SCHOOL_FLAG),
-    ("school-lock", &icons::solid::SCHOOL_LOCK),
-    ("scissors", &icons::solid::SCISSORS),
-    ("screwdriver", &icons::solid::SCREWDRIVER),
-    ("screwdriver-wrench", &icons::solid::SCREWDRIVER_WRENCH),
-    ("scroll", &icons::solid::SCROLL),
-    ("scroll-torah", &icons::solid::SCROLL_TORAH),
-    ("sd-card", &icons::solid::SD_CARD),
-    ("search", &icons::solid::SEARCH),
-    ("search-dollar", &icons::solid::SEARCH_DOLLAR),
-    ("search-location", &icons::solid::SEARCH_LOCATION),
-    ("search-minus", &icons::solid::SEARCH_MINUS),
-    ("search-plus", &icons::solid::SEARCH_PLUS),
-    ("section", &icons::solid::SECTION),
-    ("seedling", &icons::solid::SEEDLING),
-    ("septagon", &icons::solid::SEPTAGON),
-    ("server", &icons::solid::SERVER),
-    ("shapes", &icons::solid::SHAPES),
-    ("share", &icons::solid::SHARE),
-    ("share-alt", &icons::solid::SHARE_ALT),
-    ("share-alt-square", &icons::solid::SHARE_ALT_SQUARE),
-    ("share-from-square", &icons::solid::SHARE_FROM_SQUARE),
-    ("share-nodes", &icons::solid::SHARE_NODES),
-    ("share-square", &icons::solid::SHARE_SQUARE),
-    ("sheet-plastic", &icons::solid::SHEET_PLASTIC),
-    ("shekel", &icons::solid::SHEKEL),
-    ("shekel-sign", &icons::solid::SHEKEL_SIGN),
-    ("sheqel", &icons::solid::SHEQEL),
-    ("sheqel-sign", &icons::solid::SHEQEL_SIGN),
-    ("shield", &icons::solid::SHIELD),
-    ("shield-alt", &icons::solid::SHIELD_ALT),
-    ("shield-blank", &icons::solid::SHIELD_BLANK),
-    ("shield-cat", &icons::solid::SHIELD_CAT),
-    ("shield-dog", &icons::solid::SHIELD_DOG),
-    ("shield-halved", &icons::solid::SHIELD_HALVED),
-    ("shield-heart", &icons::solid::SHIELD_HEART),
-    ("shield-virus", &icons::solid::SHIELD_VIRUS),
-    ("ship", &icons::solid::SHIP),
-    ("shipping-fast", &icons::solid::SHIPPING_FAST),
-    ("shirt", &icons::solid::SHIRT),
-    ("shoe-prints", &icons::solid::SHOE_PRINTS),
-    ("shop", &icons::solid::SHOP),
-    ("shopping-bag", &icons::solid::SHOPPING_BAG),
-    ("shopping-basket", &icons::solid::SHOPPING_BASKET),
-    ("shopping-cart", &icons::solid::SHOPPING_CART),
-    ("shop-lock", &icons::solid::SHOP_LOCK),
-    ("shop-slash", &icons::solid::SHOP_SLASH),
-    ("shower", &icons::solid::SHOWER),
-    ("shrimp", &icons::solid::SHRIMP),
-    ("shuffle", &icons::solid::SHUFFLE),
-    ("shuttle-space", &icons::solid::SHUTTLE_SPACE),
-    ("shuttle-van", &icons::solid::SHUTTLE_VAN),
-    ("sign", &icons::solid::SIGN),
-    ("signal", &icons::solid::SIGNAL),
-    ("signal-5", &icons::solid::SIGNAL_5),
-    ("signal-perfect", &icons::solid::SIGNAL_PERFECT),
-    ("signature", &icons::solid::SIGNATURE),
-    ("signing", &icons::solid::SIGNING),
-    ("signs-post", &icons::solid::SIGNS_POST),
-    ("sign-hanging", &icons::solid::SIGN_HANGING),
-    ("sign-in", &icons::solid::SIGN_IN),
-    ("sign-in-alt", &icons::solid::SIGN_IN_ALT),
-    ("sign-language", &icons::solid::SIGN_LANGUAGE),
-    ("sign-out", &icons::solid::SIGN_OUT),
-    ("sign-out-alt", &icons::solid::SIGN_OUT_ALT),
-    ("sim-card", &icons::solid::SIM_CARD),
-    ("single-quote-left", &icons::solid::SINGLE_QUOTE_LEFT),
-    ("single-quote-right", &icons::solid::SINGLE_QUOTE_RIGHT),
-    ("sink", &icons::solid::SINK),
-    ("sitemap", &icons::solid::SITEMAP),
-    ("skating", &icons::solid::SKATING),
-    ("skiing", &icons::solid::SKIING),
-    ("skiing-nordic", &icons::solid::SKIING_NORDIC),
-    ("skull", &icons::solid::SKULL),
-    ("skull-crossbones", &icons::solid::SKULL_CROSSBONES),
-    ("slash", &icons::solid::SLASH),
-    ("sleigh", &icons::solid::SLEIGH),
-    ("sliders", &icons::solid::SLIDERS),
-    ("sliders-h", &icons::solid::SLIDERS_H),
-    ("smile", &icons::solid::SMILE),
-    ("smile-beam", &icons::solid::SMILE_BEAM),
-    ("smile-wink", &icons::solid::SMILE_WINK),
-    ("smog", &icons::solid::SMOG),
-    ("smoking", &icons::solid::SMOKING),
-    ("smoking-ban", &icons::solid::SMOKING_BAN),
-    ("sms", &icons::solid::SMS),
-    ("snowboarding", &icons::solid::SNOWBOARDING),
-    ("snowflake", &icons::solid::SNOWFLAKE),
-    ("snowman", &icons::solid::SNOWMAN),
-    ("snowplow", &icons::solid::SNOWPLOW),
-    ("soap", &icons::solid::SOAP),
-    ("soccer-ball", &icons::solid::SOCCER_BALL),
-    ("socks", &icons::solid::SOCKS),
-    ("solar-panel", &icons::solid::SOLAR_PANEL),
-    ("sort", &icons::solid::SORT),
-    ("sort-alpha-asc", &icons::solid::SORT_ALPHA_ASC),
-    ("sort-alpha-desc", &icons::solid::SORT_ALPHA_DESC),
-    ("sort-alpha-down", &icons::solid::SORT_ALPHA_DOWN),
-    ("sort-alpha-down-alt", &icons::solid::SORT_ALPHA_DOWN_ALT),
-    ("sort-alpha-up", &icons::solid::SORT_ALPHA_UP),
-    ("sort-alpha-up-alt", &icons::solid::SORT_ALPHA_UP_ALT),
-    ("sort-amount-asc", &icons::solid::SORT_AMOUNT_ASC),
-    ("sort-amount-desc", &icons::solid::SORT_AMOUNT_DESC),
-    ("sort-amount-down", &icons::solid::SORT_AMOUNT_DOWN),
-    ("sort-amount-down-alt", &icons::solid::SORT_AMOUNT_DOWN_ALT),
-    ("sort-amount-up", &icons::solid::SORT_AMOUNT_UP),
-    ("sort-amount-up-alt", &icons::solid::SORT_AMOUNT_UP_ALT),
-    ("sort-asc", &icons::solid::SORT_ASC),
-    ("sort-desc", &icons::solid::SORT_DESC),
-    ("sort-down", &icons::solid::SORT_DOWN),
-    ("sort-numeric-asc", &icons::solid::SORT_NUMERIC_ASC),
-    ("sort-numeric-desc", &icons::solid::SORT_NUMERIC_DESC),
-    ("sort-numeric-down", &icons::solid::SORT_NUMERIC_DOWN),
-    ("sort-numeric-down-alt", &icons::solid::SORT_NUMERIC_DOWN_ALT),
-    ("sort-numeric-up", &icons::solid::SORT_NUMERIC_UP),
-    ("sort-numeric-up-alt", &icons::solid::SORT_NUMERIC_UP_ALT),
-    ("sort-up", &icons::solid::SORT_UP),
-    ("spa", &icons::solid::SPA),
-    ("space-shuttle", &icons::solid::SPACE_SHUTTLE),
-    ("spaghetti-monster-flying", &icons::solid::SPAGHETTI_MONSTER_FLYING),
-    ("spell-check", &icons::solid::SPELL_CHECK),
-    ("spider", &icons::solid::SPIDER),
-    ("spinner", &icons::solid::SPINNER),
-    ("spiral", &icons::solid::SPIRAL),
-    ("splotch", &icons::solid::SPLOTCH),
-    ("spoon", &icons::solid::SPOON),
-    ("spray-can", &icons::solid::SPRAY_CAN),
-    ("spray-can-sparkles", &icons::solid::SPRAY_CAN_SPARKLES),
-    ("sprout", &icons::solid::SPROUT),
-    ("square", &icons::solid::SQUARE),
-    ("square-arrow-up-right", &icons::solid::SQUARE_ARROW_UP_RIGHT),
-    ("square-binary", &icons::solid::SQUARE_BINARY),
-    ("square-caret-down", &icons::solid::SQUARE_CARET_DOWN),
-    ("square-caret-left", &icons::solid::SQUARE_CARET_LEFT),
-    ("square-caret-right", &icons::solid::SQUARE_CARET_RIGHT),
-    ("square-caret-up", &icons::solid::SQUARE_CARET_UP),
-    ("square-check", &icons::solid::SQUARE_CHECK),
-    ("square-envelope", &icons::solid::SQUARE_ENVELOPE),
-    ("square-full", &icons::solid::SQUARE_FULL),
-    ("square-h", &icons::solid::SQUARE_H),
-    ("square-minus", &icons::solid::SQUARE_MINUS),
-    ("square-nfi", &icons::solid::SQUARE_NFI),
-    ("square-parking", &icons::solid::SQUARE_PARKING),
-    ("square-pen", &icons::solid::SQUARE_PEN),
-    ("square-person-confined", &icons::solid::SQUARE_PERSON_CONFINED),
-    ("square-phone", &icons::solid::SQUARE_PHONE),
-    ("square-phone-flip", &icons::solid::SQUARE_PHONE_FLIP),
-    ("square-plus", &icons::solid::SQUARE_PLUS),
-    ("square-poll-horizontal", &icons::solid::SQUARE_POLL_HORIZONTAL),
-    ("square-poll-vertical", &icons::solid::SQUARE_POLL_VERTICAL),
-    ("square-root-alt", &icons::solid::SQUARE_ROOT_ALT),
-    ("square-root-variable", &icons::solid::SQUARE_ROOT_VARIABLE),
-    ("square-rss", &icons::solid::SQUARE_RSS),
-    ("square-share-nodes", &icons::solid::SQUARE_SHARE_NODES),
-    ("square-up-right", &icons::solid::SQUARE_UP_RIGHT),
-    ("square-virus", &icons::solid::SQUARE_VIRUS),
-    ("square-xmark", &icons::solid::SQUARE_XMARK),
-    ("staff-aesculapius", &icons::solid::STAFF_AESCULAPIUS),
-    ("staff-snake", &icons::solid::STAFF_SNAKE),
-    ("stairs", &icons::solid::STAIRS),
-    ("stamp", &icons::solid::STAMP),
-    ("stapler", &icons::solid::STAPLER),
-    ("star", &icons::solid::STAR),
-    ("star-and-crescent", &icons::solid::STAR_AND_CRESCENT),
-    ("star-half", &icons::solid::STAR_HALF),
-    ("star-half-alt", &icons::solid::STAR_HALF_ALT),
-    ("star-half-stroke", &icons::solid::STAR_HALF_STROKE),
-    ("star-of-david", &icons::solid::STAR_OF_DAVID),
-    ("star-of-life", &icons::solid::STAR_OF_LIFE),
-    ("step-backward", &icons::solid::STEP_BACKWARD),
-    ("step-forward", &icons::solid::STEP_FORWARD),
-    ("sterling-sign", &icons::solid::STERLING_SIGN),
-    ("stethoscope", &icons::solid::STETHOSCOPE),
-    ("sticky-note", &icons::solid::STICKY_NOTE),
-    ("stop", &icons::solid::STOP),
-    ("stopwatch", &icons::solid::STOPWATCH),
-    ("stopwatch-20", &icons::solid::STOPWATCH_20),
-    ("stop-circle", &icons::solid::STOP_CIRCLE),
-    ("store", &icons::solid::STORE),
-    ("store-alt", &icons::solid::STORE_ALT),
-    ("store-alt-slash", &icons::solid::STORE_ALT_SLASH),
-    ("store-slash", &icons::solid::STORE_SLASH),
-    ("stream", &icons::solid::STREAM),
-    ("street-view", &icons::solid::STREET_VIEW),
-    ("strikethrough", &icons::solid::STRIKETHROUGH),
-    ("stroopwafel", &icons::solid::STROOPWAFEL),
-    ("subscript", &icons::solid::SUBSCRIPT),
-    ("subtract", &icons::solid::SUBTRACT),
-    ("subway", &icons::solid::SUBWAY),
-    ("suitcase", &icons::solid::SUITCASE),
-    ("suitcase-medical", &icons::solid::SUITCASE_MEDICAL),
-    ("suitcase-rolling", &icons::solid::SUITCASE_ROLLING),
-    ("sun", &icons::solid::SUN),
-    ("sun-plant-wilt", &icons::solid::SUN_PLANT_WILT),
-    ("superscript", &icons::solid::SUPERSCRIPT),
-    ("surprise", &icons::solid::SURPRISE),
-    ("swatchbook", &icons::solid::SWATCHBOOK),
-    ("swimmer", &icons::solid::SWIMMER),
-    ("swimming-pool", &icons::solid::SWIMMING_POOL),
-    ("synagogue", &icons::solid::SYNAGOGUE),
-    ("sync", &icons::solid::SYNC),
-    ("sync-alt", &icons::solid::SYNC_ALT),
-    ("syringe", &icons::solid::SYRINGE),
-    ("t", &icons::solid::T),
-    ("table", &icons::solid::TABLE),
-    ("tablet", &icons::solid::TABLET),
-    ("tablets", &icons::solid::TABLETS),
-    ("tablet-alt", &icons::solid::TABLET_ALT),
-    ("tablet-android", &icons::solid::TABLET_ANDROID),
-    ("tablet-button", &icons::solid::TABLET_BUTTON),
-    ("tablet-screen-button", &icons::solid::TABLET_SCREEN_BUTTON),
-    ("table-cells", &icons::solid::TABLE_CELLS),
-    ("table-cells-column-lock", &icons::solid::TABLE_CELLS_COLUMN_LOCK),
-    ("table-cells-large", &icons::solid::TABLE_CELLS_LARGE),
-    ("table-cells-row-lock", &icons::solid::TABLE_CELLS_ROW_LOCK),
-    ("table-cells-row-unlock", &icons::solid::TABLE_CELLS_ROW_UNLOCK),
-    ("table-columns", &icons::solid::TABLE_COLUMNS),
-    ("table-list", &icons::solid::TABLE_LIST),
-    ("table-tennis", &icons::solid::TABLE_TENNIS),
-    ("table-tennis-paddle-ball", &icons::solid::TABLE_TENNIS_PADDLE_BALL),
-    ("tachograph-digital", &icons::solid::TACHOGRAPH_DIGITAL),
-    ("tachometer", &icons::solid::TACHOMETER),
-    ("tachometer-alt", &icons::solid::TACHOMETER_ALT),
-    ("tachometer-alt-average", &icons::solid::TACHOMETER_ALT_AVERAGE),
-    ("tachometer-alt-fast", &icons::solid::TACHOMETER_ALT_FAST),
-    ("tachometer-average", &icons::solid::TACHOMETER_AVERAGE),
-    ("tachometer-fast", &icons::solid::TACHOMETER_FAST),
-    ("tag", &icons::solid::TAG),
-    ("tags", &icons::solid::TAGS),
-    ("tanakh", &icons::solid::TANAKH),
-    ("tape", &icons::solid::TAPE),
-    ("tarp", &icons::solid::TARP),
-    ("tarp-droplet", &icons::solid::TARP_DROPLET),
-    ("tasks", &icons::solid::TASKS),
-    ("tasks-alt", &icons::solid::TASKS_ALT),
-    ("taxi", &icons::solid::TAXI),
-    ("teeth", &icons::solid::TEETH),
-    ("teeth-open", &icons::solid::TEETH_OPEN),
-    ("teletype", &icons::solid::TELETYPE),
-    ("television", &icons::solid::TELEVISION),
-    ("temperature-0", &icons::solid::TEMPERATURE_0),
-    ("temperature-1", &icons::solid::TEMPERATURE_1),
-    ("temperature-2", &icons::solid::TEMPERATURE_2),
-    ("temperature-3", &icons::solid::TEMPERATURE_3),
-    ("temperature-4", &icons::solid::TEMPERATURE_4),
-    ("temperature-arrow-down", &icons::solid::TEMPERATURE_ARROW_DOWN),
-    ("temperature-arrow-up", &icons::solid::TEMPERATURE_ARROW_UP),
-    ("temperature-down", &icons::solid::TEMPERATURE_DOWN),
-    ("temperature-empty", &icons::solid::TEMPERATURE_EMPTY),
-    ("temperature-full", &icons::solid::TEMPERATURE_FULL),
-    ("temperature-half", &icons::solid::TEMPERATURE_HALF),
-    ("temperature-high", &icons::solid::TEMPERATURE_HIGH),
-    ("temperature-low", &icons::solid::TEMPERATURE_LOW),
-    ("temperature-quarter", &icons::solid::TEMPERATURE_QUARTER),
-    ("temperature-three-quarters", &icons::solid::TEMPERATURE_THREE_QUARTERS),
-    ("temperature-up", &icons::solid::TEMPERATURE_UP),
-    ("tenge", &icons::solid::TENGE),
-    ("tenge-sign", &icons::solid::TENGE_SIGN),
-    ("tent", &icons::solid::TENT),
-    ("tents", &icons::solid::TENTS),
-    ("tent-arrows-down", &icons::solid::TENT_ARROWS_DOWN),
-    ("tent-arrow-down-to-line", &icons::solid::TENT_ARROW_DOWN_TO_LINE),
-    ("tent-arrow-left-right", &icons::solid::TENT_ARROW_LEFT_RIGHT),
-    ("tent-arrow-turn-left", &icons::solid::TENT_ARROW_TURN_LEFT),
-    ("terminal", &icons::solid::TERMINAL),
-    ("text-height", &icons::solid::TEXT_HEIGHT),
-    ("text-slash", &icons::solid::TEXT_SLASH),
-    ("text-width", &icons::solid::TEXT_WIDTH),
-    ("th", &icons::solid::TH),
-    ("theater-masks", &icons::solid::THEATER_MASKS),
-    ("thermometer", &icons::solid::THERMOMETER),
-    ("thermometer-0", &icons::solid::THERMOMETER_0),
-    ("thermometer-1", &icons::solid::THERMOMETER_1),
-    ("thermometer-2", &icons::solid::THERMOMETER_2),
-    ("thermometer-3", &icons::solid::THERMOMETER_3),
-    ("thermometer-4", &icons::solid::THERMOMETER_4),
-    ("thermometer-empty", &icons::solid::THERMOMETER_EMPTY),
-    ("thermometer-full", &icons::solid::THERMOMETER_FULL),
-    ("thermometer-half", &icons::solid::THERMOMETER_HALF),
-    ("thermometer-quarter", &icons::solid::THERMOMETER_QUARTER),
-    ("thermometer-three-quarters", &icons::solid::THERMOMETER_THREE_QUARTERS),
-    ("thumbs-down", &icons::solid::THUMBS_DOWN),
-    ("thumbs-up", &icons::solid::THUMBS_UP),
-    ("thumbtack", &icons::solid::THUMBTACK),
-    ("thumbtack-slash", &icons::solid::THUMBTACK_SLASH),
-    ("thumb-tack", &icons::solid::THUMB_TACK),
-    ("thumb-tack-slash", &icons::solid::THUMB_TACK_SLASH),
-    ("thunderstorm", &icons::solid::THUNDERSTORM),
-    ("th-large", &icons::solid::TH_LARGE),
-    ("th-list", &icons::solid::TH_LIST),
-    ("ticket", &icons::solid::TICKET),
-    ("ticket-alt", &icons::solid::TICKET_ALT),
-    ("ticket-simple", &icons::solid::TICKET_SIMPLE),
-    ("timeline", &icons::solid::TIMELINE),
-    ("times", &icons::solid::TIMES),
-    ("times-circle", &icons::solid::TIMES_CIRCLE),
-    ("times-rectangle", &icons::solid::TIMES_RECTANGLE),
-    ("times-square", &icons::solid::TIMES_SQUARE),
-    ("tint", &icons::solid::TINT),
-    ("tint-slash", &icons::solid::TINT_SLASH),
-    ("tired", &icons::solid::TIRED),
-    ("toggle-off", &icons::solid::TOGGLE_OFF),
-    ("toggle-on", &icons::solid::TOGGLE_ON),
-    ("toilet", &icons::solid::TOILET),
-    ("toilets-portable", &icons::solid::TOILETS_PORTABLE),
-    ("toilet-paper", &icons::solid::TOILET_PAPER),
-    ("toilet-paper-alt", &icons::solid::TOILET_PAPER_ALT),
-    ("toilet-paper-blank", &icons::solid::TOILET_PAPER_BLANK),
-    ("toilet-paper-slash", &icons::solid::TOILET_PAPER_SLASH),
-    ("toilet-portable", &icons::solid::TOILET_PORTABLE),
-    ("toolbox", &icons::solid::TOOLBOX),
-    ("tools", &icons::solid::TOOLS),
-    ("tooth", &icons::solid::TOOTH),
-    ("torah", &icons::solid::TORAH),
-    ("torii-gate", &icons::solid::TORII_GATE),
-    ("tornado", &icons::solid::TORNADO),
-    ("tower-broadcast", &icons::solid::TOWER_BROADCAST),
-    ("tower-cell", &icons::solid::TOWER_CELL),
-    ("tower-observation", &icons::solid::TOWER_OBSERVATION),
-    ("tractor", &icons::solid::TRACTOR),
-    ("trademark", &icons::solid::TRADEMARK),
-    ("traffic-light", &icons::solid::TRAFFIC_LIGHT),
-    ("trailer", &icons::solid::TRAILER),
-    ("train", &icons::solid::TRAIN),
-    ("train-subway", &icons::solid::TRAIN_SUBWAY),
-    ("train-tram", &icons::solid::TRAIN_TRAM),
-    ("tram", &icons::solid::TRAM),
-    ("transgender", &icons::solid::TRANSGENDER),
-    ("transgender-alt", &icons::solid::TRANSGENDER_ALT),
-    ("trash", &icons::solid::TRASH),
-    ("trash-alt", &icons::solid::TRASH_ALT),
-    ("trash-arrow-up", &icons::solid::TRASH_ARROW_UP),
-    ("trash-can", &icons::solid::TRASH_CAN),
-    ("trash-can-arrow-up", &icons::solid::TRASH_CAN_ARROW_UP),
-    ("trash-restore", &icons::solid::TRASH_RESTORE),
-    ("trash-restore-alt", &icons::solid::TRASH_RESTORE_ALT),
-    ("tree", &icons::solid::TREE),
-    ("tree-city", &icons::solid::TREE_CITY),
-    ("triangle-circle-square", &icons::solid::TRIANGLE_CIRCLE_SQUARE),
-    ("triangle-exclamation", &icons::solid::TRIANGLE_EXCLAMATION),
-    ("trophy", &icons::solid::TROPHY),
-    ("trowel", &icons::solid::TROWEL),
-    ("trowel-bricks", &icons::solid::TROWEL_BRICKS),
-    ("truck", &icons::solid::TRUCK),
-    ("truck-arrow-right", &icons::solid::TRUCK_ARROW_RIGHT),
-    ("truck-droplet", &icons::solid::TRUCK_DROPLET),
-    ("truck-fast", &icons::solid::TRUCK_FAST),
-    ("truck-field", &icons::solid::TRUCK_FIELD),
-    ("truck-field-un", &icons::solid::TRUCK_FIELD_UN),
-    ("truck-front", &icons::solid::TRUCK_FRONT),
-    ("truck-loading", &icons::solid::TRUCK_LOADING),
-    ("truck-medical", &icons::solid::TRUCK_MEDICAL),
-    ("truck-monster", &icons::solid::TRUCK_MONSTER),
-    ("truck-moving", &icons::solid::TRUCK_MOVING),
-    ("truck-pickup", &icons::solid::TRUCK_PICKUP),
-    ("truck-plane", &icons::solid::TRUCK_PLANE),
-    ("truck-ramp-box", &icons::solid::TRUCK_RAMP_BOX),
-    ("try", &icons::solid::TRY),
-    ("tshirt", &icons::solid::TSHIRT),
-    ("tty", &icons::solid::TTY),
-    ("turkish-lira", &icons::solid::TURKISH_LIRA),
-    ("turkish-lira-sign", &icons::solid::TURKISH_LIRA_SIGN),
-    ("turn-down", &icons::solid::TURN_DOWN),
-    ("turn-up", &icons::solid::TURN_UP),
-    ("tv", &icons::solid::TV),
-    ("tv-alt", &icons::solid::TV_ALT),
-    ("t-shirt", &icons::solid::T_SHIRT),
-    ("u", &icons::solid::U),
-    ("umbrella", &icons::solid::UMBRELLA),
-    ("umbrella-beach", &icons::solid::UMBRELLA_BEACH),
-    ("underline", &icons::solid::UNDERLINE),
-    ("undo", &icons::solid::UNDO),
-    ("undo-alt", &icons::solid::UNDO_ALT),
-    ("universal-access", &icons::solid::UNIVERSAL_ACCESS),
-    ("university", &icons::solid::UNIVERSITY),
-    ("unlink", &icons::solid::UNLINK),
-    ("unlock", &icons::solid::UNLOCK),
-    ("unlock-alt", &icons::solid::UNLOCK_ALT),
-    ("unlock-keyhole", &icons::solid::UNLOCK_KEYHOLE),
-    ("unsorted", &icons::solid::UNSORTED),
-    ("upload", &icons::solid::UPLOAD),
-    ("up-down", &icons::solid::UP_DOWN),
-    ("up-down-left-right", &icons::solid::UP_DOWN_LEFT_RIGHT),
-    ("up-long", &icons::solid::UP_LONG),
-    ("up-right-and-down-left-from-center", &icons::solid::UP_RIGHT_AND_DOWN_LEFT_FROM_CENTER),
-    ("up-right-from-square", &icons::solid::UP_RIGHT_FROM_SQUARE),
-    ("usd", &icons::solid::USD),
-    ("user", &icons::solid::USER),
-    ("users", &icons::solid::USERS),
-    ("users-between-lines", &icons::solid::USERS_BETWEEN_LINES),
-    ("users-cog", &icons::solid::USERS_COG),
-    ("users-gear", &icons::solid::USERS_GEAR),
-    ("users-line", &icons::solid::USERS_LINE),
-    ("users-rays", &icons::solid::USERS_RAYS),
-    ("users-rectangle", &icons::solid::USERS_RECTANGLE),
-    ("users-slash", &icons::solid::USERS_SLASH),
-    ("users-viewfinder", &icons::solid::USERS_VIEWFINDER),
-    ("user-alt", &icons::solid::USER_ALT),
-    ("user-alt-slash", &icons::solid::USER_ALT_SLASH),
-    ("user-astronaut", &icons::solid::USER_ASTRONAUT),
-    ("user-check", &icons::solid::USER_CHECK),
-    ("user-circle", &icons::solid::USER_CIRCLE),
-    ("user-clock", &icons::solid::USER_CLOCK),
-    ("user-cog", &icons::solid::USER_COG),
-    ("user-doctor", &icons::solid::USER_DOCTOR),
-    ("user-edit", &icons::solid::USER_EDIT),
-    ("user-friends", &icons::solid::USER_FRIENDS),
-    ("user-gear", &icons::solid::USER_GEAR),
-    ("user-graduate", &icons::solid::USER_GRADUATE),
-    ("user-group", &icons::solid::USER_GROUP),
-    ("user-injured", &icons::solid::USER_INJURED),
-    ("user-large", &icons::solid::USER_LARGE),
-    ("user-large-slash", &icons::solid::USER_LARGE_SLASH),
-    ("user-lock", &icons::solid::USER_LOCK),
-    ("user-md", &icons::solid::USER_MD),
-    ("user-minus", &icons::solid::USER_MINUS),
-    ("user-ninja", &icons::solid::USER_NINJA),
-    ("user-nurse", &icons::solid::USER_NURSE),
-    ("user-pen", &icons::solid::USER_PEN),
-    ("user-plus", &icons::solid::USER_PLUS),
-    ("user-secret", &icons::solid::USER_SECRET),
-    ("user-shield", &icons::solid::USER_SHIELD),
-    ("user-slash", &icons::solid::USER_SLASH),
-    ("user-tag", &icons::solid::USER_TAG),
-    ("user-tie", &icons::solid::USER_TIE),
-    ("user-times", &icons::solid::USER_TIMES),
-    ("user-xmark", &icons::solid::USER_XMARK),
-    ("utensils", &icons::solid::UTENSILS),
-    ("utensil-spoon", &icons::solid::UTENSIL_SPOON),
-    ("v", &icons::solid::V),
-    ("van-shuttle", &icons::solid::VAN_SHUTTLE),
-    ("vault", &icons::solid::VAULT),
-    ("vcard", &icons::solid::VCARD),
-    ("vector-polygon", &icons::solid::VECTOR_POLYGON),
-    ("venus", &icons::solid::VENUS),
-    ("venus-double", &icons::solid::VENUS_DOUBLE),
-    ("venus-mars", &icons::solid::VENUS_MARS),
-    ("vest", &icons::solid::VEST),
-    ("vest-patches", &icons::solid::VEST_PATCHES),
-    ("vial", &icons::solid::VIAL),
-    ("vials", &icons::solid::VIALS),
-    ("vial-circle-check", &icons::solid::VIAL_CIRCLE_CHECK),
-    ("vial-virus", &icons::solid::VIAL_VIRUS),
-    ("video", &icons::solid::VIDEO),
-    ("video-camera", &icons::solid::VIDEO_CAMERA),
-    ("video-slash", &icons::solid::VIDEO_SLASH),
-    ("vihara", &icons::solid::VIHARA),
-    ("virus", &icons::solid::VIRUS),
-    ("viruses", &icons::solid::VIRUSES),
-    ("virus-covid", &icons::solid::VIRUS_COVID),
-    ("virus-covid-slash", &icons::solid::VIRUS_COVID_SLASH),
-    ("virus-slash", &icons::solid::VIRUS_SLASH),
-    ("voicemail", &icons::solid::VOICEMAIL),
-    ("volcano", &icons::solid::VOLCANO),
-    ("volleyball", &icons::solid::VOLLEYBALL),
-    ("volleyball-ball", &icons::solid::VOLLEYBALL_BALL),
-    ("volume-control-phone", &icons::solid::VOLUME_CONTROL_PHONE),
-    ("volume-down", &icons::solid::VOLUME_DOWN),
-    ("volume-high", &icons::solid::VOLUME_HIGH),
-    ("volume-low", &icons::solid::VOLUME_LOW),
-    ("volume-mute", &icons::solid::VOLUME_MUTE),
-    ("volume-off", &icons::solid::VOLUME_OFF),
-    ("volume-times", &icons::solid::VOLUME_TIMES),
-    ("volume-up", &icons::solid::VOLUME_UP),
-    ("volume-xmark", &icons::solid::VOLUME_XMARK),
-    ("vote-yea", &icons::solid::VOTE_YEA),
-    ("vr-cardboard", &icons::solid::VR_CARDBOARD),
-    ("w", &icons::solid::W),
-    ("walkie-talkie", &icons::solid::WALKIE_TALKIE),
-    ("walking", &icons::solid::WALKING),
-    ("wallet", &icons::solid::WALLET),
-    ("wand-magic", &icons::solid::WAND_MAGIC),
-    ("wand-magic-sparkles", &icons::solid::WAND_MAGIC_SPARKLES),
-    ("wand-sparkles", &icons::solid::WAND_SPARKLES),
-    ("warehouse", &icons::solid::WAREHOUSE),
-    ("warning", &icons::solid::WARNING),
-    ("water", &icons::solid::WATER),
-    ("water-ladder", &icons::solid::WATER_LADDER),
-    ("wave-square", &icons::solid::WAVE_SQUARE),
-    ("web-awesome", &icons::solid::WEB_AWESOME),
-    ("weight", &icons::solid::WEIGHT),
-    ("weight-hanging", &icons::solid::WEIGHT_HANGING),
-    ("weight-scale", &icons::solid::WEIGHT_SCALE),
-    ("wheat-alt", &icons::solid::WHEAT_ALT),
-    ("wheat-awn", &icons::solid::WHEAT_AWN),
-    ("wheat-awn-circle-exclamation", &icons::solid::WHEAT_AWN_CIRCLE_EXCLAMATION),
-    ("wheelchair", &icons::solid::WHEELCHAIR),
-    ("wheelchair-alt", &icons::solid::WHEELCHAIR_ALT),
-    ("wheelchair-move", &icons::solid::WHEELCHAIR_MOVE),
-    ("whiskey-glass", &icons::solid::WHISKEY_GLASS),
-    ("wifi", &icons::solid::WIFI),
-    ("wifi-3", &icons::solid::WIFI_3),
-    ("wifi-strong", &icons::solid::WIFI_STRONG),
-    ("wind", &icons::solid::WIND),
-    ("window-close", &icons::solid::WINDOW_CLOSE),
-    ("window-maximize", &icons::solid::WINDOW_MAXIMIZE),
-    ("window-minimize", &icons::solid::WINDOW_MINIMIZE),
-    ("window-restore", &icons::solid::WINDOW_RESTORE),
-    ("wine-bottle", &icons::solid::WINE_BOTTLE),
-    ("wine-glass", &icons::solid::WINE_GLASS),
-    ("wine-glass-alt", &icons::solid::WINE_GLASS_ALT),
-    ("wine-glass-empty", &icons::solid::WINE_GLASS_EMPTY),
-    ("won", &icons::solid::WON),
-    ("won-sign", &icons::solid::WON_SIGN),
-    ("worm", &icons::solid::WORM),
-    ("wrench", &icons::solid::WRENCH),
-    ("x", &icons::solid::X),
-    ("xmark", &icons::solid::XMARK),
-    ("xmarks-lines", &icons::solid::XMARKS_LINES),
-    ("xmark-circle", &icons::solid::XMARK_CIRCLE),
-    ("xmark-square", &icons::solid::XMARK_SQUARE),
-    ("x-ray", &icons::solid::X_RAY),
-    ("y", &icons::solid::Y),
-    ("yen", &icons::solid::YEN),
-    ("yen-sign", &icons::solid::YEN_SIGN),
-    ("yin-yang", &icons::solid::YIN_YANG),
-    ("z", &icons::solid::Z),
-    ("zap", &icons::solid::ZAP),
-];
-
@@ -1,50 +1,40 @@
-use crate::all_icons::{BRANDS_ICONS, REGULAR_ICONS, SOLID_ICONS};
 use stylist::{css, yew::Global};
 use yew::prelude::*;
 use yew_shortcuts::{
-    FontAwesomeSvg,
+    FontAwesomeSvg, IconSpriteProvider,
     fontawesome::{self, icons},
 };
 
-// Include the generated icon counts
-include!(concat!(env!("OUT_DIR"), "/icon_counts.rs"));
+// Include the icon catalog generated from the manifests
+include!(concat!(env!("OUT_DIR"), "/icon_catalog.rs"));
 
 const ICONS_PER_PAGE: usize = 100;
 
-#[derive(Clone, Copy, PartialEq)]
-enum IconCategory {
-    Solid,
-    Regular,
-    Brands,
-}
-
-impl IconCategory {
-    fn name(&self) -> &'static str {
-        match self {
-            IconCategory::Solid => "Solid",
-            IconCategory::Regular => "Regular",
-            IconCategory::Brands => "Brands",
-        }
-    }
-
-    fn icons(&self) -> &'static [(&'static str, &'static fontawesome::Icon)] {
-        match self {
-            IconCategory::Solid => SOLID_ICONS,
-            IconCategory::Regular => REGULAR_ICONS,
-            IconCategory::Brands => BRANDS_ICONS,
-        }
-    }
-
-    fn count(&self) -> usize {
-        self.icons().len()
-    }
+/// Tab label for a category, e.g. `Sharp Solid` for `sharp_solid`
+fn category_label(category: &str) -> String {
+    category
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[function_component]
 pub fn App() -> Html {
     let search_query = use_state(String::new);
-    let copied_icon = use_state(|| None::<(String, bool)>);
-    let current_category = use_state(|| IconCategory::Solid);
+    let copied_icon = use_state(|| None::<(String, bool, &'static [&'static fontawesome::Icon])>);
+    let current_category = use_state(|| {
+        CATEGORIES
+            .iter()
+            .position(|(category, _)| *category == "solid")
+            .unwrap_or(0)
+    });
     let current_page = use_state(|| 0usize);
     let use_full = use_state(|| true);
 
@@ -61,14 +51,14 @@ pub fn App() -> Html {
     let copy_to_clipboard = {
         let copied_icon = copied_icon.clone();
         let use_full = use_full.clone();
-        Callback::from(move |(icon_name, code): (String, String)| {
+        Callback::from(move |(icon_name, code, similar): (String, String, &'static [&'static fontawesome::Icon])| {
             let window = web_sys::window().unwrap();
             let navigator = window.navigator();
             let clipboard = navigator.clipboard();
 
             let promise = clipboard.write_text(&code);
 
-            copied_icon.set(Some((icon_name, *use_full)));
+            copied_icon.set(Some((icon_name, *use_full, similar)));
 
             let copied_icon = copied_icon.clone();
             wasm_bindgen_futures::spawn_local(async move {
@@ -81,11 +71,12 @@ pub fn App() -> Html {
 
     let query = search_query.to_lowercase();
 
+    let (category, category_icons) = CATEGORIES[*current_category];
+
     // Filter icons based on search
-    let filtered_icons: Vec<(&str, &fontawesome::Icon)> = current_category
-        .icons()
+    let filtered_icons: Vec<CatalogIcon> = category_icons
         .iter()
-        .filter(|(name, _)| query.is_empty() || name.to_lowercase().contains(&query))
+        .filter(|(_, icon, _)| query.is_empty() || icon.name.contains(&query))
         .cloned()
         .collect();
 
@@ -100,7 +91,7 @@ pub fn App() -> Html {
     let on_category_change = {
         let current_category = current_category.clone();
         let current_page = current_page.clone();
-        Callback::from(move |category: IconCategory| {
+        Callback::from(move |category: usize| {
             current_category.set(category);
             current_page.set(0); // Reset to first page on category change
         })
@@ -372,6 +363,19 @@ pub fn App() -> Html {
                     color: white;
                 }
                 
+                .similar-icons {
+                    display: flex;
+                    justify-content: center;
+                    align-items: center;
+                    gap: 0.75rem;
+                    margin-top: 0.75rem;
+                    font-size: 1.5rem;
+                }
+
+                .similar-icons span:first-child {
+                    font-size: 0.85rem;
+                }
+
                 .pagination {
                     display: flex;
                     justify-content: center;
@@ -474,8 +478,9 @@ pub fn App() -> Html {
                 <div class="how-it-works">
                     <h2>{"How it works"}</h2>
                     <p>{"Each icon is defined as a "}<code>{"const"}</code>{" with its SVG path data:"}</p>
-                    <pre>{format!("pub const HOUSE: &Icon = &Icon {{\n    cropped: CroppedIcon {{ view_box: \"...\", d: r#\"{}...\"# }},\n    #[cfg(feature = \"full-svg\")]\n    full: FullIcon {{ d: r#\"...\"# }},\n}};",
-                        &icons::solid::HOUSE.cropped.d[..100.min(icons::solid::HOUSE.cropped.d.len())] // Show first 100 chars of path with ellipsis
+                    <pre>{format!("pub const HOUSE: &Icon = &Icon {{\n    cropped: CroppedIcon {{ view_box: \"...\", d: r#\"{}...\"# }},\n    #[cfg(feature = \"full-svg\")]\n    full: {:?},\n}};",
+                        &icons::solid::HOUSE.cropped.d[..100.min(icons::solid::HOUSE.cropped.d.len())], // Show first 100 chars of path with ellipsis
+                        icons::solid::HOUSE.full,
                     )}</pre>
                     <p>{"The Rust compiler's dead code elimination ensures that "}<strong>{"only the icons you import and use"}</strong>{" are included in the final WASM binary. Unused icons are completely eliminated at compile time!"}</p>
                 </div>
@@ -483,11 +488,11 @@ pub fn App() -> Html {
                 <div class="controls">
                     <div class="category-controls">
                         <div class="category-tabs">
-                            {[IconCategory::Solid, IconCategory::Regular, IconCategory::Brands].into_iter().map(|category| {
-                                let is_active = *current_category == category;
+                            {CATEGORIES.iter().enumerate().map(|(index, (name, category_icons))| {
+                                let is_active = *current_category == index;
                                 let onclick = {
                                     let on_category_change = on_category_change.clone();
-                                    Callback::from(move |_| on_category_change.emit(category))
+                                    Callback::from(move |_| on_category_change.emit(index))
                                 };
 
                                 html! {
@@ -495,7 +500,7 @@ pub fn App() -> Html {
                                         class={classes!("category-tab", is_active.then(|| "active"))}
                                         {onclick}
                                     >
-                                        {format!("{} ({})", category.name(), category.count())}
+                                        {format!("{} ({})", category_label(name), category_icons.len())}
                                     </button>
                                 }
                             }).collect::<Html>()}
@@ -525,13 +530,13 @@ pub fn App() -> Html {
 
                     <div class="search-container">
                         <FontAwesomeSvg
-                            icon={&icons::solid::MAGNIFYING_GLASS}
+                            icon={icons::solid::MAGNIFYING_GLASS}
                             classes={classes!("search-icon")}
                         />
                         <input
                             type="text"
                             class="search-input"
-                            placeholder={format!("Search {} icons...", current_category.name().to_lowercase())}
+                            placeholder={format!("Search {} icons...", category_label(category).to_lowercase())}
                             value={(*search_query).clone()}
                             oninput={on_search_input}
                         />
@@ -553,20 +558,21 @@ pub fn App() -> Html {
                     </div>
                 </div>
 
+                // Each icon on the page references one shared <symbol>
+                <IconSpriteProvider>
                 <div class="icon-grid">
-                    {page_icons.iter().map(|(name, icon)| {
-                        let full_icon_name = format!("{}::{}", current_category.name().to_lowercase(), name);
+                    {page_icons.iter().map(|(rust_name, icon, similar)| {
+                        let name = icon.name;
+                        let full_icon_name = format!("{}::{}", category, rust_name);
                         let code = if *use_full {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::{}}};\n\n<FontAwesomeSvg icon={{&icons::{}::{}}} full=true />",
-                                current_category.name().to_lowercase(),
-                                current_category.name().to_lowercase(),
-                                name
+                            format!("use yew_shortcuts::{{fontawesome::icons, FontAwesomeSvg}};\n\n<FontAwesomeSvg icon={{icons::{}::{}}} full=true />",
+                                category,
+                                rust_name
                             )
                         } else {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::{}}};\n\n<FontAwesomeSvg icon={{&icons::{}::{}}} />",
-                                current_category.name().to_lowercase(),
-                                current_category.name().to_lowercase(),
-                                name
+                            format!("use yew_shortcuts::{{fontawesome::icons, FontAwesomeSvg}};\n\n<FontAwesomeSvg icon={{icons::{}::{}}} />",
+                                category,
+                                rust_name
                             )
                         };
 
@@ -574,9 +580,10 @@ pub fn App() -> Html {
                             let full_icon_name = full_icon_name.clone();
                             let code = code.clone();
                             let copy_to_clipboard = copy_to_clipboard.clone();
+                            let similar = *similar;
                             Callback::from(move |e: MouseEvent| {
                                 e.stop_propagation();
-                                copy_to_clipboard.emit((full_icon_name.clone(), code.clone()))
+                                copy_to_clipboard.emit((full_icon_name.clone(), code.clone(), similar))
                             })
                         };
 
@@ -589,37 +596,37 @@ pub fn App() -> Html {
                         }
                     }).collect::<Html>()}
                 </div>
+                </IconSpriteProvider>
 
-                {if let Some((copied_name, is_full)) = &*copied_icon {
-                    let code = if copied_name.starts_with("solid::") {
-                        let icon_name = copied_name.strip_prefix("solid::").unwrap();
-                        if *is_full {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::solid}};\n\n<FontAwesomeSvg icon={{&solid::{}}} full=true />", icon_name)
-                        } else {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::solid}};\n\n<FontAwesomeSvg icon={{&solid::{}}} />", icon_name)
-                        }
-                    } else if copied_name.starts_with("regular::") {
-                        let icon_name = copied_name.strip_prefix("regular::").unwrap();
-                        if *is_full {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::regular}};\n\n<FontAwesomeSvg icon={{&regular::{}}} full=true />", icon_name)
-                        } else {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::regular}};\n\n<FontAwesomeSvg icon={{&regular::{}}} />", icon_name)
+                {if let Some((copied_name, is_full, similar)) = &*copied_icon {
+                    let code = match copied_name.split_once("::") {
+                        Some((category, icon_name)) if *is_full => {
+                            format!("use yew_shortcuts::{{fontawesome::icons, FontAwesomeSvg}};\n\n<FontAwesomeSvg icon={{icons::{}::{}}} full=true />", category, icon_name)
                         }
-                    } else if copied_name.starts_with("brands::") {
-                        let icon_name = copied_name.strip_prefix("brands::").unwrap();
-                        if *is_full {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::brands}};\n\n<FontAwesomeSvg icon={{&brands::{}}} full=true />", icon_name)
-                        } else {
-                            format!("use yew_shortcuts::{{FontAwesomeSvg, fontawesome::icons::brands}};\n\n<FontAwesomeSvg icon={{&brands::{}}} />", icon_name)
+                        Some((category, icon_name)) => {
+                            format!("use yew_shortcuts::{{fontawesome::icons, FontAwesomeSvg}};\n\n<FontAwesomeSvg icon={{icons::{}::{}}} />", category, icon_name)
                         }
-                    } else {
-                        String::new()
+                        None => String::new(),
                     };
 
                     html! {
                         <div class="copied-badge">
                             <div>{"✓ Copied to clipboard!"}</div>
                             <code>{code}</code>
+                            {if similar.is_empty() {
+                                html! {}
+                            } else {
+                                html! {
+                                    <div class="similar-icons">
+                                        <span>{"Looks similar:"}</span>
+                                        {similar.iter().map(|icon| html! {
+                                            <span title={format!("{} {}", icon.category, icon.name)}>
+                                                <FontAwesomeSvg icon={*icon} />
+                                            </span>
+                                        }).collect::<Html>()}
+                                    </div>
+                                }
+                            }}
                         </div>
                     }
                 } else {
@@ -1,5 +1,4 @@
 mod app;
-mod all_icons;
 
 use app::App;
 
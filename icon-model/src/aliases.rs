@@ -0,0 +1,84 @@
+//! Deprecated names of icons renamed between FontAwesome releases
+//!
+//! `icon-processor diff --aliases` keeps the table in
+//! `fontawesome/aliases.json`, keyed by category and then deprecated name:
+//!
+//! ```json
+//! { "solid": { "HOME": "HOUSE" } }
+//! ```
+//!
+//! Generation reads the table from there for the rustdoc notes and the
+//! catalog; the generated `aliases.rs` is never read back.
+
+use std::collections::BTreeMap;
+
+/// Deprecated name → current name, keyed by (category, deprecated name)
+pub type AliasTable = BTreeMap<(String, String), String>;
+
+/// Parses the contents of `aliases.json`
+pub fn parse_aliases(json: &str) -> Result<AliasTable, serde_json::Error> {
+    let by_category: BTreeMap<String, BTreeMap<String, String>> = serde_json::from_str(json)?;
+    Ok(by_category
+        .into_iter()
+        .flat_map(|(category, renames)| {
+            renames
+                .into_iter()
+                .map(move |(old_name, new_name)| ((category.clone(), old_name), new_name))
+        })
+        .collect())
+}
+
+/// Serializes the table in the layout [`parse_aliases`] reads
+pub fn aliases_to_json(aliases: &AliasTable) -> String {
+    let mut by_category: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+    for ((category, old_name), new_name) in aliases {
+        by_category.entry(category).or_default().insert(old_name, new_name);
+    }
+    let mut json = serde_json::to_string_pretty(&by_category).expect("string maps always serialize");
+    json.push('\n');
+    json
+}
+
+/// Deprecated names per icon, keyed by (category, current Rust name)
+pub fn deprecated_names(aliases: &AliasTable) -> BTreeMap<(String, String), Vec<String>> {
+    let mut names: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+    for ((category, old_name), new_name) in aliases {
+        names
+            .entry((category.clone(), new_name.clone()))
+            .or_default()
+            .push(old_name.clone());
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias(category: &str, old_name: &str, new_name: &str) -> ((String, String), String) {
+        ((category.to_string(), old_name.to_string()), new_name.to_string())
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let aliases = AliasTable::from([
+            alias("solid", "HOME", "HOUSE"),
+            alias("solid", "HOME_ALT", "HOUSE"),
+            alias("regular", "FILE_ALT", "FILE_LINES"),
+        ]);
+
+        let json = aliases_to_json(&aliases);
+        assert!(json.contains("\"solid\": {\n    \"HOME\": \"HOUSE\","));
+        assert_eq!(parse_aliases(&json).unwrap(), aliases);
+        assert_eq!(
+            deprecated_names(&aliases)[&("solid".to_string(), "HOUSE".to_string())],
+            ["HOME", "HOME_ALT"]
+        );
+    }
+
+    #[test]
+    fn rejects_other_layouts() {
+        assert!(parse_aliases("{\"solid\": [\"HOME\"]}").is_err());
+        assert!(parse_aliases("pub mod solid {}").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Generation of `ICONS.html`, a self-contained visual catalog of the icon set.
+//!
+//! The page inlines every icon as SVG and needs no network access, so it can
+//! be opened from disk or shipped next to the crate documentation. A small
+//! script filters icons by name, Rust path or alias and copies the Rust path
+//! to the clipboard.
+
+use crate::full::format_number;
+use crate::naming::rust_name_to_kebab_case;
+use crate::{IconData, ViewBox};
+use std::collections::BTreeMap;
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 0; background: #fff; color: #222; }
+header { position: sticky; top: 0; background: #fff; padding: 1rem 2rem; border-bottom: 1px solid #ddd; }
+header h1 { margin: 0 0 0.5rem; font-size: 1.4rem; }
+#search { width: 100%; max-width: 32rem; padding: 0.5rem; font-size: 1rem; }
+main { padding: 0 2rem 2rem; }
+h2 { margin-top: 2rem; text-transform: capitalize; }
+.grid { display: grid; grid-template-columns: repeat(auto-fill, minmax(11rem, 1fr)); gap: 0.75rem; }
+.icon { border: 1px solid #e3e3e3; border-radius: 6px; padding: 0.75rem; display: flex; flex-direction: column; align-items: center; gap: 0.3rem; text-align: center; }
+.icon svg { height: 2.5rem; max-width: 100%; }
+.icon code { font-size: 0.7rem; word-break: break-all; }
+.meta { font-size: 0.7rem; color: #777; }
+.icon button { font-size: 0.7rem; cursor: pointer; }
+.hidden { display: none; }
+@media (prefers-color-scheme: dark) {
+  body, header { background: #1e1e1e; color: #ddd; }
+  header { border-color: #333; }
+  .icon { border-color: #333; }
+}
+"#;
+
+const SCRIPT: &str = r#"
+const search = document.getElementById('search');
+search.addEventListener('input', () => {
+  const terms = search.value.toLowerCase().split(/\s+/).filter(Boolean);
+  for (const section of document.querySelectorAll('section')) {
+    let visible = 0;
+    for (const icon of section.querySelectorAll('.icon')) {
+      const match = terms.every(term => icon.dataset.search.includes(term));
+      icon.classList.toggle('hidden', !match);
+      if (match) visible++;
+    }
+    section.classList.toggle('hidden', visible === 0);
+  }
+});
+document.addEventListener('click', event => {
+  const button = event.target.closest('button[data-copy]');
+  if (!button) return;
+  navigator.clipboard.writeText(button.dataset.copy).then(() => {
+    button.textContent = 'Copied!';
+    setTimeout(() => { button.textContent = 'Copy'; }, 1200);
+  });
+});
+"#;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Generates the HTML catalog of all icons. `aliases` maps (category, Rust
+/// name) to deprecated names, as returned by [`crate::aliases::deprecated_names`].
+pub fn generate_icons_html(
+    cropped_icons: &[IconData],
+    aliases: &BTreeMap<(String, String), Vec<String>>,
+    license: &str,
+) -> String {
+    let mut output = String::new();
+
+    output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    output.push_str("<title>FontAwesome 7.0 Icons</title>\n");
+    output.push_str(&format!("<style>{}</style>\n", STYLE));
+    output.push_str("</head>\n<body>\n");
+    output.push_str(&format!("<!--{}-->\n", license));
+    output.push_str("<header>\n");
+    output.push_str(&format!(
+        "<h1>FontAwesome 7.0 Icons ({} icons)</h1>\n",
+        cropped_icons.len()
+    ));
+    output.push_str("<input id=\"search\" type=\"search\" placeholder=\"Search by name, path or alias\" autofocus>\n");
+    output.push_str("</header>\n<main>\n");
+
+    // Group icons by category
+    let mut by_category: BTreeMap<&str, Vec<&IconData>> = BTreeMap::new();
+    for icon in cropped_icons {
+        by_category.entry(&icon.category).or_default().push(icon);
+    }
+
+    for (category, icons) in &mut by_category {
+        icons.sort_by_key(|i| &i.name);
+        output.push_str(&format!("<section id=\"{}\">\n", category));
+        output.push_str(&format!("<h2>{} ({} icons)</h2>\n<div class=\"grid\">\n", category, icons.len()));
+
+        for icon in icons.iter() {
+            let name = rust_name_to_kebab_case(&icon.name);
+            let path = format!("icons::{}::{}", category, icon.name);
+            let bounds = ViewBox::parse(&icon.view_box).unwrap_or_default();
+            let icon_aliases = aliases
+                .get(&(category.to_string(), icon.name.clone()))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            let search = std::iter::once(name.clone())
+                .chain(std::iter::once(path.clone()))
+                .chain(icon_aliases.iter().cloned())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase();
+
+            output.push_str(&format!("<div class=\"icon\" data-search=\"{}\">\n", escape_html(&search)));
+            output.push_str(&format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{}\"><path fill=\"currentColor\" d=\"{}\"/></svg>\n",
+                escape_html(&icon.view_box),
+                escape_html(&icon.path_data)
+            ));
+            output.push_str(&format!("<strong>{}</strong>\n", escape_html(&name)));
+            output.push_str(&format!("<code>{}</code>\n", escape_html(&path)));
+            output.push_str(&format!(
+                "<span class=\"meta\">{}×{}</span>\n",
+                format_number(bounds.width as f64),
+                format_number(bounds.height as f64)
+            ));
+            if !icon_aliases.is_empty() {
+                output.push_str(&format!(
+                    "<span class=\"meta\">aliases: {}</span>\n",
+                    escape_html(&icon_aliases.join(", "))
+                ));
+            }
+            output.push_str(&format!("<button data-copy=\"{}\">Copy</button>\n", escape_html(&path)));
+            output.push_str("</div>\n");
+        }
+        output.push_str("</div>\n</section>\n");
+    }
+
+    output.push_str("</main>\n");
+    output.push_str(&format!("<script>{}</script>\n", SCRIPT));
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(category: &str, name: &str, path_data: &str) -> IconData {
+        IconData {
+            name: name.to_string(),
+            category: category.to_string(),
+            view_box: "0 0 448 512".to_string(),
+            path_data: path_data.to_string(),
+            comment: String::new(),
+            file_path: String::new(),
+        }
+    }
+
+    #[test]
+    fn escapes_names_aliases_and_path_data() {
+        let icons = [icon("solid", "A<B", "M0 0\"/><script>alert(1)</script>")];
+        let aliases = BTreeMap::from([(("solid".to_string(), "A<B".to_string()), vec!["OLD&NAME".to_string()])]);
+
+        let html = generate_icons_html(&icons, &aliases, "license");
+        assert!(html.contains("<div class=\"icon\" data-search=\"a&lt;b icons::solid::a&lt;b old&amp;name\">"));
+        assert!(html.contains("<strong>a&lt;b</strong>\n<code>icons::solid::A&lt;B</code>\n<span class=\"meta\">448×512</span>"));
+        assert!(html.contains("<span class=\"meta\">aliases: OLD&amp;NAME</span>"));
+        assert!(html.contains("<button data-copy=\"icons::solid::A&lt;B\">Copy</button>"));
+        assert!(html.contains("d=\"M0 0&quot;/&gt;&lt;script&gt;alert(1)&lt;/script&gt;\""));
+        assert!(!html.contains("A<B") && !html.contains("<script>alert"));
+    }
+
+    #[test]
+    fn embeds_the_search_and_copy_script() {
+        let icons = [icon("solid", "HOUSE", "M0 0h1z"), icon("regular", "HEART", "M0 0h2z")];
+        let html = generate_icons_html(&icons, &BTreeMap::new(), "license");
+
+        assert!(html.contains("<input id=\"search\" type=\"search\""));
+        assert!(html.contains(&format!("<script>{}</script>", SCRIPT)));
+        assert!(SCRIPT.contains("icon.dataset.search.includes(term)"));
+        assert!(SCRIPT.contains("navigator.clipboard.writeText(button.dataset.copy)"));
+        assert!(html.contains("<!--license-->"));
+        // No network access: everything is inline
+        assert!(!html.contains("src=") && !html.contains("href="));
+        // Categories in order, icons sorted within them
+        assert!(html.find("<section id=\"regular\">").unwrap() < html.find("<section id=\"solid\">").unwrap());
+        assert!(html.contains("<h2>solid (1 icons)</h2>"));
+    }
+}
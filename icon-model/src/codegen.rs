@@ -0,0 +1,678 @@
+//! Generation of the `fontawesome.rs` module and the `ICONS.md` listing
+
+use crate::custom::CustomIcon;
+use crate::full::{format_number, FullPlacement, FULL_VIEW_BOX};
+use crate::metadata::IconMetadata;
+use crate::naming::{category_feature, rust_name_to_kebab_case, symbol_id};
+use crate::optical::OpticalBounds;
+use crate::{IconData, ViewBox};
+use std::collections::BTreeMap;
+
+/// Fill of the rustdoc previews, readable on both light and dark themes
+const PREVIEW_FILL: &str = "#808080";
+
+/// The generated `fontawesome.rs` and one file per category module
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedModule {
+    /// Contents of `fontawesome.rs`: shared types and the feature-gated module declarations
+    pub root: String,
+    /// Contents of `fontawesome/<category>.rs`, keyed by category
+    pub categories: BTreeMap<String, String>,
+    /// Contents of `fontawesome/aliases.rs`, when any icon has deprecated names
+    pub aliases: Option<String>,
+}
+
+/// Generates the Rust module holding every icon constant, split into one
+/// file per category so each can be compiled only when its feature is enabled.
+/// `aliases` are the deprecated constants of renamed icons and `metadata` the
+/// search metadata the manifest records, keyed by kebab-case name.
+pub fn generate_rust_module(
+    cropped_icons: &[IconData],
+    placements: &BTreeMap<(String, String), FullPlacement>,
+    aliases: &BTreeMap<(String, String), Vec<String>>,
+    metadata: &BTreeMap<String, IconMetadata>,
+    license: &str,
+) -> GeneratedModule {
+    let mut output = String::new();
+    
+    // Add header
+    output.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+    
+    // Add the shared license constant
+    output.push_str("/// FontAwesome license comment shared by all icons\n");
+    output.push_str(&format!("pub const FONTAWESOME_LICENSE: &str = r#\"{}\"#;\n\n", license));
+    
+    // ViewBox struct with the parsed viewBox numbers
+    output.push_str("/// The numbers of an SVG viewBox\n");
+    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
+    output.push_str("pub struct ViewBox {\n");
+    output.push_str("    pub min_x: f32,\n");
+    output.push_str("    pub min_y: f32,\n");
+    output.push_str("    pub width: f32,\n");
+    output.push_str("    pub height: f32,\n");
+    output.push_str("}\n\n");
+    output.push_str("impl ViewBox {\n");
+    output.push_str("    /// Width divided by height\n");
+    output.push_str("    pub fn aspect_ratio(&self) -> f32 {\n");
+    output.push_str("        self.width / self.height\n");
+    output.push_str("    }\n\n");
+    output.push_str("    /// Rendered width for a given height, keeping the aspect ratio\n");
+    output.push_str("    pub fn width_for_height(&self, height: f32) -> f32 {\n");
+    output.push_str("        height * self.aspect_ratio()\n");
+    output.push_str("    }\n\n");
+    output.push_str("    /// Rendered height for a given width, keeping the aspect ratio\n");
+    output.push_str("    pub fn height_for_width(&self, width: f32) -> f32 {\n");
+    output.push_str("        width / self.aspect_ratio()\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+    
+    // CroppedIcon struct for cropped icons
+    output.push_str("/// Represents a cropped FontAwesome icon with its SVG path data\n");
+    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
+    output.push_str("pub struct CroppedIcon {\n");
+    output.push_str("    /// The SVG viewBox attribute value\n");
+    output.push_str("    pub view_box: &'static str,\n");
+    output.push_str("    /// The viewBox parsed into numbers\n");
+    output.push_str("    pub bounds: ViewBox,\n");
+    output.push_str("    /// Centroid of the filled area as (x, y) in viewBox units\n");
+    output.push_str("    pub optical_center: (f32, f32),\n");
+    output.push_str("    /// The SVG path data (d attribute)\n");
+    output.push_str("    pub d: &'static str,\n");
+    output.push_str("}\n\n");
+
+    // FullIcon struct describing where the cropped path sits in the full SVG
+    output.push_str("/// Placement of a cropped icon within the standard full viewBox\n");
+    output.push_str("///\n");
+    output.push_str("/// The full variant is rendered from the cropped path data as\n");
+    output.push_str("/// `full = cropped * scale + offset`, so no second path is stored.\n");
+    output.push_str("#[cfg(feature = \"full-svg\")]\n");
+    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
+    output.push_str("pub struct FullIcon {\n");
+    output.push_str("    /// Horizontal translation applied after scaling\n");
+    output.push_str("    pub offset_x: f32,\n");
+    output.push_str("    /// Vertical translation applied after scaling\n");
+    output.push_str("    pub offset_y: f32,\n");
+    output.push_str("    /// Uniform scale applied to the cropped path\n");
+    output.push_str("    pub scale: f32,\n");
+    output.push_str("}\n\n");
+    
+    output.push_str("/// Standard viewBox for full SVG icons\n");
+    output.push_str("#[cfg(feature = \"full-svg\")]\n");
+    output.push_str(&format!("pub const FULL_VIEW_BOX: &str = \"{}\";\n\n", FULL_VIEW_BOX));
+    
+    // Icon struct that contains both
+    output.push_str("/// Represents a FontAwesome icon that can have both cropped and full variants\n");
+    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
+    output.push_str("pub struct Icon {\n");
+    output.push_str("    /// Category the icon belongs to, e.g. `solid`\n");
+    output.push_str("    pub category: &'static str,\n");
+    output.push_str("    /// Kebab-case icon name, e.g. `house`\n");
+    output.push_str("    pub name: &'static str,\n");
+    output.push_str("    /// The cropped version of the icon\n");
+    output.push_str("    pub cropped: CroppedIcon,\n");
+    output.push_str("    /// The full version of the icon (when feature enabled), or `None`\n");
+    output.push_str("    /// when FontAwesome ships no full SVG for it\n");
+    output.push_str("    #[cfg(feature = \"full-svg\")]\n");
+    output.push_str("    pub full: Option<FullIcon>,\n");
+    output.push_str("}\n\n");
+    
+    // Deprecated aliases for renamed icons, recorded by `icon-processor diff`
+    if !aliases.is_empty() {
+        output.push_str("/// Deprecated aliases for icons renamed between FontAwesome releases\n");
+        output.push_str("pub mod aliases;\n\n");
+    }
+    
+    // Group icons by category
+    let mut cropped_by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
+    for icon in cropped_icons {
+        cropped_by_category.entry(icon.category.clone()).or_default().push(icon);
+    }
+    
+    // Generate a feature-gated module file for each category
+    let mut categories = BTreeMap::new();
+    for (category, icons) in &cropped_by_category {
+        let feature = category_feature(category);
+        output.push_str(&format!("/// {} icons\n", category));
+        output.push_str(&format!("#[cfg(feature = \"{}\")]\n", feature));
+        output.push_str(&format!("pub mod {};\n\n", category));
+        
+        let module = generate_category_module(
+            category,
+            icons,
+            placements,
+            aliases,
+            metadata,
+            &format!("`{}` feature", feature),
+        );
+        categories.insert(category.clone(), module);
+    }
+    
+    // Pro styles are generated locally into fontawesome/pro/ and never committed
+    output.push_str("/// Font Awesome Pro styles, generated locally by `icon-processor --pro`\n");
+    output.push_str("#[cfg(feature = \"pro\")]\n");
+    output.push_str("mod pro;\n");
+    output.push_str("#[cfg(feature = \"pro\")]\n");
+    output.push_str("pub use pro::*;\n\n");
+    
+    output.push_str("/// License comment to attach to a rendered icon\n");
+    output.push_str("#[cfg_attr(not(feature = \"pro\"), allow(unused_variables))]\n");
+    output.push_str("pub fn license(icon: &Icon) -> &'static str {\n");
+    output.push_str("    #[cfg(feature = \"pro\")]\n");
+    output.push_str("    if pro::CATEGORIES.contains(&icon.category) {\n");
+    output.push_str("        return pro::FONTAWESOME_PRO_LICENSE;\n");
+    output.push_str("    }\n");
+    output.push_str("    FONTAWESOME_LICENSE\n");
+    output.push_str("}\n\n");
+    
+    // The format comes from `naming::symbol_id`, so the tooling and the components agree on sprite ids
+    output.push_str("/// Id of the sprite `<symbol>` an icon is rendered into, e.g. `fa-solid-house`\n");
+    output.push_str("pub fn symbol_id(icon: &Icon) -> String {\n");
+    output.push_str(&format!(
+        "    format!(\"{}\", icon.category, icon.name)\n",
+        symbol_id("{}", "{}")
+    ));
+    output.push_str("}\n\n");
+    
+    // Add icons module that re-exports all categories
+    output.push_str("/// Module containing all FontAwesome icons organized by category\n");
+    output.push_str("pub mod icons {\n");
+    for category in cropped_by_category.keys() {
+        output.push_str(&format!("    #[cfg(feature = \"{}\")]\n", category_feature(category)));
+        output.push_str(&format!("    pub use super::{};\n", category));
+    }
+    output.push_str("    #[cfg(feature = \"pro\")]\n");
+    output.push_str("    pub use super::pro::*;\n");
+    output.push_str("}\n\n");
+    
+    GeneratedModule {
+        root: output,
+        categories,
+        aliases: (!aliases.is_empty()).then(|| generate_aliases_module(aliases)),
+    }
+}
+
+/// Generates `fontawesome/pro/mod.rs` and one file per Pro style. The whole
+/// directory sits behind the `pro` feature, so its styles get no features of their own.
+pub fn generate_pro_module(
+    cropped_icons: &[IconData],
+    placements: &BTreeMap<(String, String), FullPlacement>,
+    metadata: &BTreeMap<String, IconMetadata>,
+    license: &str,
+) -> GeneratedModule {
+    let mut output = String::new();
+    
+    output.push_str("// This file is auto-generated by `icon-processor --pro`. Do not edit manually or commit.\n\n");
+    output.push_str("//! Font Awesome Pro styles, enabled by the `pro` feature\n\n");
+    output.push_str("use super::{Icon, CroppedIcon, ViewBox};\n");
+    output.push_str("#[cfg(feature = \"full-svg\")]\n");
+    output.push_str("use super::FullIcon;\n\n");
+    
+    output.push_str("/// Font Awesome Pro license comment shared by the Pro icons\n");
+    output.push_str(&format!("pub const FONTAWESOME_PRO_LICENSE: &str = r#\"{}\"#;\n\n", license));
+    
+    let mut cropped_by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
+    for icon in cropped_icons {
+        cropped_by_category.entry(icon.category.clone()).or_default().push(icon);
+    }
+    
+    output.push_str("/// Pro styles in this module\n");
+    output.push_str("pub(super) const CATEGORIES: &[&str] = &[\n");
+    for category in cropped_by_category.keys() {
+        output.push_str(&format!("    \"{}\",\n", category));
+    }
+    output.push_str("];\n\n");
+    
+    let mut categories = BTreeMap::new();
+    for (category, icons) in &cropped_by_category {
+        output.push_str(&format!("/// {} icons\n", category));
+        output.push_str(&format!("pub mod {};\n\n", category));
+        
+        let module = generate_category_module(category, icons, placements, &BTreeMap::new(), metadata, "`pro` feature");
+        categories.insert(category.clone(), module);
+    }
+    
+    GeneratedModule {
+        root: output,
+        categories,
+        aliases: None,
+    }
+}
+
+/// Generates `fontawesome/aliases.rs`, which the category modules glob
+/// re-export so renamed icons keep resolving under their old names.
+/// `aliases` maps (category, current Rust name) to deprecated names.
+fn generate_aliases_module(aliases: &BTreeMap<(String, String), Vec<String>>) -> String {
+    let mut output = String::new();
+
+    output.push_str("// This file is auto-generated from aliases.json. Do not edit manually.\n\n");
+    output.push_str("//! Deprecated aliases for icons renamed between FontAwesome releases\n\n");
+
+    let mut by_category: BTreeMap<&str, Vec<(&str, &str)>> = BTreeMap::new();
+    for ((category, new_name), old_names) in aliases {
+        for old_name in old_names {
+            by_category.entry(category).or_default().push((old_name, new_name));
+        }
+    }
+
+    for (category, mut renames) in by_category {
+        renames.sort();
+        output.push_str(&format!("/// Renamed {} icons\n", category));
+        output.push_str(&format!("#[cfg(feature = \"{}\")]\n", category_feature(category)));
+        output.push_str(&format!("pub mod {} {{\n", category));
+        output.push_str("    use super::super::Icon;\n\n");
+        for (old_name, new_name) in renames {
+            output.push_str(&format!("    #[deprecated(note = \"renamed to {}\")]\n", new_name));
+            output.push_str(&format!(
+                "    pub const {}: &Icon = super::super::{}::{};\n\n",
+                old_name, category, new_name
+            ));
+        }
+        output.push_str("}\n\n");
+    }
+
+    output
+}
+
+/// `ViewBox { .. }` struct literal for a viewBox string; `struct_path` is how the generated code names the type
+pub fn view_box_literal(view_box: &str, struct_path: &str) -> String {
+    let bounds = ViewBox::parse(view_box).unwrap_or_default();
+    format!(
+        "{} {{ min_x: {:?}, min_y: {:?}, width: {:?}, height: {:?} }}",
+        struct_path, bounds.min_x, bounds.min_y, bounds.width, bounds.height
+    )
+}
+
+/// `(x, y)` tuple literal of an icon's optical center, falling back to the middle of the viewBox
+pub fn optical_center_literal(view_box: &str, path_data: &str) -> String {
+    let (x, y) = match OpticalBounds::from_path_data(path_data) {
+        Ok(optical) => (optical.center_x, optical.center_y),
+        Err(_) => {
+            let bounds = ViewBox::parse(view_box).unwrap_or_default();
+            (bounds.min_x + bounds.width / 2.0, bounds.min_y + bounds.height / 2.0)
+        }
+    };
+    format!("({:?}, {:?})", x, y)
+}
+
+/// Generates the file of a single category module
+fn generate_category_module(
+    category: &str,
+    icons: &[&IconData],
+    placements: &BTreeMap<(String, String), FullPlacement>,
+    aliases: &BTreeMap<(String, String), Vec<String>>,
+    metadata: &BTreeMap<String, IconMetadata>,
+    enabled_by: &str,
+) -> String {
+    let mut module = String::new();
+    module.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+    module.push_str(&format!("//! {} icons, enabled by the {}\n\n", category, enabled_by));
+    module.push_str("use super::{Icon, CroppedIcon, ViewBox};\n");
+    module.push_str("#[cfg(feature = \"full-svg\")]\n");
+    module.push_str("use super::FullIcon;\n\n");
+    
+    if aliases.keys().any(|(alias_category, _)| alias_category == category) {
+        module.push_str(&format!("pub use super::aliases::{}::*;\n\n", category));
+    }
+    
+    // Sort icons by name for consistent output
+    let mut sorted_icons = icons.to_vec();
+    sorted_icons.sort_by_key(|i| &i.name);
+    
+    for icon in sorted_icons {
+        let icon_name = &icon.name;
+        
+        // Find the placement derived from the corresponding full icon, if available
+        let placement = placements.get(&(category.to_string(), icon.name.clone()));
+        
+        let deprecated = aliases
+            .get(&(category.to_string(), icon.name.clone()))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let other_names = metadata
+            .get(&rust_name_to_kebab_case(icon_name))
+            .map(|entry| entry.aliases.as_slice())
+            .unwrap_or_default();
+        module.push_str(&icon_doc(
+            "",
+            category,
+            icon_name,
+            &icon.view_box,
+            &icon.path_data,
+            other_names,
+            deprecated,
+        ));
+        module.push_str(&format!("pub const {}: &Icon = &Icon {{\n", icon_name));
+        module.push_str(&format!("    category: \"{}\",\n", category));
+        module.push_str(&format!("    name: \"{}\",\n", rust_name_to_kebab_case(icon_name)));
+        module.push_str("    cropped: CroppedIcon {\n");
+        module.push_str(&format!("        view_box: \"{}\",\n", icon.view_box));
+        module.push_str(&format!("        bounds: {},\n", view_box_literal(&icon.view_box, "ViewBox")));
+        module.push_str(&format!(
+            "        optical_center: {},\n",
+            optical_center_literal(&icon.view_box, &icon.path_data)
+        ));
+        module.push_str(&format!("        d: r#\"{}\"#,\n", icon.path_data));
+        module.push_str("    },\n");
+        
+        // Icons without a full counterpart only get the cropped variant
+        module.push_str("    #[cfg(feature = \"full-svg\")]\n");
+        match placement {
+            Some(placement) => {
+                module.push_str("    full: Some(FullIcon {\n");
+                module.push_str(&format!("        offset_x: {:?},\n", placement.offset_x));
+                module.push_str(&format!("        offset_y: {:?},\n", placement.offset_y));
+                module.push_str(&format!("        scale: {:?},\n", placement.scale));
+                module.push_str("    }),\n");
+            }
+            None => module.push_str("    full: None,\n"),
+        }
+        module.push_str("};\n\n");
+    }
+    
+    module
+}
+
+/// Generates a module of custom icons in the same shape as `fontawesome.rs`,
+/// for `include!` in the user's crate. `crate_path` is how that crate refers
+/// to yew-shortcuts, usually `::yew_shortcuts`.
+///
+/// The icons' categories, which only show up in their sprite ids, are
+/// qualified by [`custom_category`] so they never match a FontAwesome style.
+pub fn generate_custom_module(
+    icons: &[CustomIcon],
+    license: &str,
+    crate_path: &str,
+    module: &str,
+) -> String {
+    let mut output = String::new();
+    
+    // `include!` can't take inner attributes or doc comments, so only plain comments here
+    output.push_str("// This file is auto-generated by yew-shortcuts-build. Do not edit manually.\n\n");
+    
+    if !license.is_empty() {
+        output.push_str("/// License comment shared by the icons\n");
+        output.push_str(&format!("pub const LICENSE: &str = r#\"{}\"#;\n\n", license));
+    }
+    
+    // Group icons by category; files at the top of the directory have none
+    let mut by_category: BTreeMap<&str, Vec<&CustomIcon>> = BTreeMap::new();
+    for custom in icons {
+        by_category.entry(&custom.icon.category).or_default().push(custom);
+    }
+    
+    for (category, icons) in &by_category {
+        let indent = if category.is_empty() { "" } else { "    " };
+        if !category.is_empty() {
+            output.push_str(&format!("/// {} icons\n", category));
+            output.push_str(&format!("pub mod {} {{\n", category));
+        }
+        output.push_str(&format!("{}use {}::fontawesome::{{CroppedIcon, Icon, ViewBox}};\n\n", indent, crate_path));
+        
+        for custom in icons {
+            let icon = &custom.icon;
+            let icon_category = custom_category(module, category);
+            output.push_str(&icon_doc(
+                indent,
+                &icon_category,
+                &icon.name,
+                &custom.cropped_view_box,
+                &icon.path_data,
+                &[],
+                &[],
+            ));
+            output.push_str(&format!(
+                "{}pub const {}: &Icon = &{}::__private::icon(\n",
+                indent, icon.name, crate_path
+            ));
+            output.push_str(&format!("{}    \"{}\",\n", indent, icon_category));
+            output.push_str(&format!("{}    \"{}\",\n", indent, rust_name_to_kebab_case(&icon.name)));
+            output.push_str(&format!("{}    CroppedIcon {{\n", indent));
+            output.push_str(&format!("{}        view_box: \"{}\",\n", indent, custom.cropped_view_box));
+            output.push_str(&format!(
+                "{}        bounds: {},\n",
+                indent,
+                view_box_literal(&custom.cropped_view_box, "ViewBox")
+            ));
+            output.push_str(&format!(
+                "{}        optical_center: {},\n",
+                indent,
+                optical_center_literal(&custom.cropped_view_box, &icon.path_data)
+            ));
+            output.push_str(&format!("{}        d: r#\"{}\"#,\n", indent, icon.path_data));
+            output.push_str(&format!("{}    }},\n", indent));
+            output.push_str(&format!("{}    {:?},\n", indent, custom.full.offset_x));
+            output.push_str(&format!("{}    {:?},\n", indent, custom.full.offset_y));
+            output.push_str(&format!("{}    {:?},\n", indent, custom.full.scale));
+            output.push_str(&format!("{});\n\n", indent));
+        }
+        
+        if !category.is_empty() {
+            output.push_str("}\n\n");
+        }
+    }
+    
+    // Re-export the categories like `fontawesome::icons`
+    let categories: Vec<&&str> = by_category.keys().filter(|c| !c.is_empty()).collect();
+    if !categories.is_empty() {
+        output.push_str("/// Module containing all icons organized by category\n");
+        output.push_str("pub mod icons {\n");
+        for category in categories {
+            output.push_str(&format!("    pub use super::{};\n", category));
+        }
+        output.push_str("}\n");
+    }
+    
+    output
+}
+
+/// Category of a custom icon from the generated `module`: `custom-<module>`
+/// for files at the top of the directory, `custom-<module>-<subdirectory>` otherwise
+pub fn custom_category(module: &str, category: &str) -> String {
+    if category.is_empty() {
+        format!("custom-{}", module)
+    } else {
+        format!("custom-{}-{}", module, category)
+    }
+}
+
+/// Generates the markdown listing of all icons
+pub fn generate_icons_markdown(cropped_icons: &[IconData]) -> String {
+    let mut output = String::new();
+    
+    // Add header
+    output.push_str("# FontAwesome 7.0 Icons\n\n");
+    output.push_str(&format!("Total icons: {}\n\n", cropped_icons.len()));
+    
+    // Group icons by category
+    let mut by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
+    for icon in cropped_icons {
+        by_category.entry(icon.category.clone()).or_default().push(icon);
+    }
+    
+    // Generate markdown for each category
+    for (category, icons) in &by_category {
+        output.push_str(&format!("## {} ({} icons)\n\n", category, icons.len()));
+        
+        // Sort icons by name for consistent output
+        let mut sorted_icons = icons.clone();
+        sorted_icons.sort_by_key(|i| &i.name);
+        
+        for icon in sorted_icons {
+            let bounds = ViewBox::parse(&icon.view_box).unwrap_or_default();
+            output.push_str(&format!(
+                "- `icons::{}::{}` {}x{}, aspect {}",
+                category,
+                icon.name,
+                format_number(bounds.width as f64),
+                format_number(bounds.height as f64),
+                format_number(if bounds.height > 0.0 { bounds.aspect_ratio() as f64 } else { 0.0 })
+            ));
+            // FontAwesome viewBoxes start at the origin; third-party ones may not
+            if bounds.min_x != 0.0 || bounds.min_y != 0.0 {
+                output.push_str(&format!(
+                    " at ({}, {})",
+                    format_number(bounds.min_x as f64),
+                    format_number(bounds.min_y as f64)
+                ));
+            }
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+    
+    output
+}
+
+/// Doc comment of a generated icon constant: the kebab-case name and
+/// dimensions, a preview image, the other names FontAwesome lists for it and
+/// any deprecated alias constants. Every generated icon is documented here,
+/// so this is the only place previews are rendered.
+fn icon_doc(
+    indent: &str,
+    category: &str,
+    rust_name: &str,
+    view_box: &str,
+    path_data: &str,
+    other_names: &[String],
+    deprecated: &[String],
+) -> String {
+    let name = rust_name_to_kebab_case(rust_name);
+    let bounds = ViewBox::parse(view_box).unwrap_or_default();
+    let mut doc = format!(
+        "{}/// `{}` from `{}`, {}x{}\n",
+        indent,
+        name,
+        category,
+        format_number(bounds.width as f64),
+        format_number(bounds.height as f64)
+    );
+    doc.push_str(&format!("{}///\n", indent));
+    doc.push_str(&format!(
+        "{}/// <img src=\"{}\" height=\"32\" alt=\"{}\">\n",
+        indent,
+        svg_data_uri(view_box, path_data, PREVIEW_FILL),
+        name
+    ));
+    let code_list = |names: &[String]| names.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", ");
+    if !other_names.is_empty() {
+        doc.push_str(&format!("{}///\n", indent));
+        doc.push_str(&format!("{}/// Also known as: {}\n", indent, code_list(other_names)));
+    }
+    if !deprecated.is_empty() {
+        doc.push_str(&format!("{}///\n", indent));
+        doc.push_str(&format!("{}/// Deprecated aliases: {}\n", indent, code_list(deprecated)));
+    }
+    doc
+}
+
+/// `data:` URI of a standalone SVG drawing the path, for `<img>` previews
+fn svg_data_uri(view_box: &str, path_data: &str, fill: &str) -> String {
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='{}'><path fill='{}' d='{}'/></svg>",
+        view_box, fill, path_data
+    );
+    let mut uri = String::from("data:image/svg+xml,");
+    for byte in svg.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' | b'=' | b',' | b'\'' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    fn icon(name: &str, view_box: &str, path_data: &str) -> IconData {
+        IconData {
+            name: name.to_string(),
+            category: "solid".to_string(),
+            view_box: view_box.to_string(),
+            path_data: path_data.to_string(),
+            comment: String::new(),
+            file_path: format!("solid/{}.svg", rust_name_to_kebab_case(name)),
+        }
+    }
+
+    /// Compiles the generated module as a crate of its own with the given features
+    fn check_compiles(module: &GeneratedModule, features: &[&str]) {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("lib.rs"), &module.root).unwrap();
+        for (category, source) in &module.categories {
+            fs::write(dir.path().join(format!("{}.rs", category)), source).unwrap();
+        }
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let mut command = Command::new(rustc);
+        command
+            .args(["--edition", "2021", "--crate-type", "lib", "--crate-name", "generated", "--emit", "metadata"])
+            .arg("--out-dir")
+            .arg(dir.path())
+            .arg(Path::new(&dir.path().join("lib.rs")));
+        for feature in features {
+            command.arg("--cfg").arg(format!("feature=\"{}\"", feature));
+        }
+        let output = command.output().unwrap();
+        assert!(
+            output.status.success(),
+            "generated module does not compile with {:?}:\n{}",
+            features,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn icons_without_a_full_variant_compile() {
+        let icons = [
+            icon("CIRCLE", "0 0 512 512", "M256 0a256 256 0 1 1 0 512a256 256 0 1 1 0-512z"),
+            icon("GRIP_LINES", "0 0 448 192", "M0 0h448v64H0zM0 128h448v64H0z"),
+        ];
+        // Only CIRCLE has a full SVG
+        let placements = BTreeMap::from([(
+            ("solid".to_string(), "CIRCLE".to_string()),
+            FullPlacement::rounded(64.0, 64.0, 1.0),
+        )]);
+
+        let module = generate_rust_module(&icons, &placements, &BTreeMap::new(), &BTreeMap::new(), "license");
+        let solid = &module.categories["solid"];
+        assert!(solid.contains("    full: Some(FullIcon {\n        offset_x: 64.0,"));
+        assert!(solid.contains("    full: None,\n"));
+
+        check_compiles(&module, &["fa-solid"]);
+        check_compiles(&module, &["fa-solid", "full-svg"]);
+    }
+
+    #[test]
+    fn documents_metadata_names_and_deprecated_aliases() {
+        let icons = [icon("HOUSE", "0 0 576 512", "M0 0h576v512H0z"), icon("GEAR", "0 0 512 512", "M0 0h512v512H0z")];
+        let aliases = BTreeMap::from([(("solid".to_string(), "HOUSE".to_string()), vec!["HOME_ALT".to_string()])]);
+        let metadata = BTreeMap::from([(
+            "house".to_string(),
+            IconMetadata { aliases: vec!["home".to_string()], search_terms: vec!["building".to_string()] },
+        )]);
+
+        let module = generate_rust_module(&icons, &BTreeMap::new(), &aliases, &metadata, "license");
+        let solid = &module.categories["solid"];
+        let house_doc = &solid[solid.find("/// `house`").unwrap()..solid.find("pub const HOUSE").unwrap()];
+        assert!(house_doc.starts_with("/// `house` from `solid`, 576x512\n///\n/// <img src=\"data:image/svg+xml,"));
+        assert!(house_doc.ends_with("///\n/// Also known as: `home`\n///\n/// Deprecated aliases: `HOME_ALT`\n"));
+
+        let gear_doc = &solid[solid.find("/// `gear`").unwrap()..solid.find("pub const GEAR").unwrap()];
+        assert!(!gear_doc.contains("Also known as") && !gear_doc.contains("Deprecated"));
+
+        // The alias constants are re-exported from their category module
+        assert!(solid.contains("pub use super::aliases::solid::*;"));
+        let aliases_module = module.aliases.unwrap();
+        assert!(aliases_module.contains("#[cfg(feature = \"fa-solid\")]\npub mod solid {"));
+        assert!(aliases_module.contains(
+            "    #[deprecated(note = \"renamed to HOUSE\")]\n    pub const HOME_ALT: &Icon = super::super::solid::HOUSE;"
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Project-local SVG icons, compiled by `include_svg_icon!` and `yew-shortcuts-build`
+//!
+//! Unlike the FontAwesome sources these files come from arbitrary editors, so
+//! they are checked for constructs the `Icon` model can't represent, and their
+//! viewBox is recomputed to fit the outline tightly.
+
+use crate::full::{centered_placement, format_number, FullPlacement};
+use crate::path;
+use crate::{process_svg_file, IconData, SvgError, SvgErrorKind, ViewBox};
+use roxmltree::Document;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Elements that may appear next to the single `<path>` without changing what is drawn
+const IGNORED_ELEMENTS: &[&str] = &["svg", "path", "g", "title", "desc", "metadata"];
+
+/// A custom icon ready to be emitted as an `Icon` constant
+#[derive(Debug, Clone)]
+pub struct CustomIcon {
+    /// The icon as parsed from the file, with its original viewBox
+    pub icon: IconData,
+    /// viewBox tightly enclosing the path, like the cropped FontAwesome icons
+    pub cropped_view_box: String,
+    /// Placement of the outline on the standard full canvas
+    pub full: FullPlacement,
+}
+
+/// Reads a project-local SVG and computes its cropped viewBox and full placement
+pub fn load_custom_icon(path: &Path) -> Result<CustomIcon, SvgError> {
+    let unsupported = |message: String| SvgError {
+        kind: SvgErrorKind::Unsupported,
+        file: path.display().to_string(),
+        message,
+    };
+
+    let content = fs::read_to_string(path).map_err(|e| SvgError {
+        kind: SvgErrorKind::Parse,
+        file: path.display().to_string(),
+        message: e.to_string(),
+    })?;
+    if let Ok(doc) = Document::parse(&content) {
+        check_supported(&doc).map_err(unsupported)?;
+    }
+
+    let icon = process_svg_file(path)?;
+    // The viewBox is recomputed from the path, but a malformed one usually means a broken export
+    if ViewBox::parse(&icon.view_box).is_none() {
+        return Err(SvgError {
+            kind: SvgErrorKind::Parse,
+            file: path.display().to_string(),
+            message: format!(
+                "invalid viewBox \"{}\"; expected four numbers with a positive size",
+                icon.view_box
+            ),
+        });
+    }
+    let bbox = path::path_bounding_box(&icon.path_data).map_err(|e| unsupported(e.to_string()))?;
+    if bbox.width() <= 0.0 || bbox.height() <= 0.0 {
+        return Err(unsupported("the path has an empty bounding box".to_string()));
+    }
+
+    // Round outwards so anti-aliased edges are never clipped
+    let min_x = (bbox.min_x * 1000.0).floor() / 1000.0;
+    let min_y = (bbox.min_y * 1000.0).floor() / 1000.0;
+    let max_x = (bbox.max_x * 1000.0).ceil() / 1000.0;
+    let max_y = (bbox.max_y * 1000.0).ceil() / 1000.0;
+    let cropped_view_box = format!(
+        "{} {} {} {}",
+        format_number(min_x),
+        format_number(min_y),
+        format_number(max_x - min_x),
+        format_number(max_y - min_y)
+    );
+
+    Ok(CustomIcon {
+        full: centered_placement(&bbox),
+        icon,
+        cropped_view_box,
+    })
+}
+
+/// Custom icons read from a directory, together with the problems encountered
+#[derive(Debug, Clone, Default)]
+pub struct CustomIconSet {
+    /// Icons sorted by category and name. Files directly inside the directory
+    /// have an empty category.
+    pub icons: Vec<CustomIcon>,
+    /// The license comment of the first icon, empty when there is none
+    pub license: String,
+    pub errors: Vec<SvgError>,
+}
+
+/// Reads every SVG below `dir`; subdirectories become categories
+pub fn load_custom_icon_dir(dir: &Path) -> CustomIconSet {
+    let mut set = CustomIconSet::default();
+    let mut license_comment = None;
+
+    for entry in WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "svg"))
+    {
+        match load_custom_icon(entry.path()) {
+            Ok(mut custom) => {
+                if entry.path().parent() == Some(dir) {
+                    custom.icon.category.clear();
+                } else {
+                    // Directory names like `social-media` become `social_media` modules
+                    custom.icon.category = custom.icon.category.replace('-', "_");
+                }
+                if let Some(ref expected_comment) = license_comment {
+                    if custom.icon.comment != *expected_comment {
+                        set.errors.push(SvgError {
+                            kind: SvgErrorKind::LicenseMismatch,
+                            file: custom.icon.file_path.clone(),
+                            message: format!(
+                                "Different comment '{}' vs expected '{}'",
+                                custom.icon.comment, expected_comment
+                            ),
+                        });
+                    }
+                } else {
+                    license_comment = Some(custom.icon.comment.clone());
+                }
+                set.icons.push(custom);
+            }
+            Err(e) => set.errors.push(e),
+        }
+    }
+
+    set.icons.sort_by(|a, b| {
+        (&a.icon.category, &a.icon.name).cmp(&(&b.icon.category, &b.icon.name))
+    });
+    set.license = license_comment.unwrap_or_default();
+    set
+}
+
+/// Rejects SVG features that would be lost when only the path data is kept
+fn check_supported(doc: &Document) -> Result<(), String> {
+    let mut paths = 0;
+    for node in doc.descendants().filter(|n| n.is_element()) {
+        let tag = node.tag_name().name();
+        if !IGNORED_ELEMENTS.contains(&tag) && !is_inside_metadata(node) {
+            return Err(format!(
+                "<{}> elements are not supported; convert shapes to a single <path>",
+                tag
+            ));
+        }
+        if tag == "path" {
+            paths += 1;
+        }
+        if node.has_attribute("transform") {
+            return Err(format!(
+                "the transform on <{}> is not supported; apply it to the path data",
+                tag
+            ));
+        }
+        if node.attribute("fill-rule") == Some("evenodd") {
+            return Err("fill-rule=\"evenodd\" is not supported; only nonzero paths render correctly".to_string());
+        }
+    }
+
+    match paths {
+        0 => Err("no <path> element found".to_string()),
+        1 => Ok(()),
+        n => Err(format!(
+            "found {} <path> elements; combine them into a single path",
+            n
+        )),
+    }
+}
+
+fn is_inside_metadata(node: roxmltree::Node) -> bool {
+    node.ancestors()
+        .any(|n| matches!(n.tag_name().name(), "metadata" | "title" | "desc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(svg: &str) -> Result<CustomIcon, SvgError> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logo.svg");
+        fs::write(&path, svg).unwrap();
+        load_custom_icon(&path)
+    }
+
+    #[test]
+    fn crops_to_the_path() {
+        let custom = load(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M2 4h20v16H2z"/></svg>"#).unwrap();
+        assert_eq!(custom.icon.name, "LOGO");
+        assert_eq!(custom.cropped_view_box, "2 4 20 16");
+        // The height spans 512 units of the full canvas, which the width just fills
+        assert_eq!(custom.full.scale, 32.0);
+    }
+
+    #[test]
+    fn rejects_unsupported_files() {
+        let cases = [
+            (r#"<svg viewBox="0 0 24"><path d="M2 2h20v20H2z"/></svg>"#, "invalid viewBox \"0 0 24\""),
+            (r#"<svg viewBox="0 0 24 24"><title>empty</title></svg>"#, "no <path> element found"),
+            (r#"<svg viewBox="0 0 24 24"><circle r="4"/></svg>"#, "<circle> elements are not supported"),
+            (r#"<svg viewBox="0 0 24 24"><path transform="scale(2)" d="M0 0h1v1z"/></svg>"#, "the transform on <path>"),
+            (r#"<svg viewBox="0 0 24 24"><path d="M0 0h1"/><path d="M0 0v1"/></svg>"#, "found 2 <path> elements"),
+        ];
+        for (svg, expected) in cases {
+            let error = load(svg).unwrap_err();
+            assert!(error.message.contains(expected), "{}: {}", svg, error.message);
+        }
+    }
+}
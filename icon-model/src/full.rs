@@ -0,0 +1,205 @@
+//! Placement of the cropped outline within the full-size icon.
+//!
+//! The full 640×640 FontAwesome SVGs contain the same outline as the cropped
+//! ones, only scaled and translated onto the larger canvas. Instead of storing
+//! a second copy of the path data the generated module stores that placement.
+
+use crate::path;
+use crate::IconData;
+
+/// The viewBox every full FontAwesome SVG uses
+pub const FULL_VIEW_BOX: &str = "0 0 640 640";
+
+/// Decimal places the scale of a placement is rounded to
+const SCALE_DECIMALS: i32 = 4;
+
+/// Decimal places the offsets of a placement are rounded to
+const OFFSET_DECIMALS: i32 = 3;
+
+/// Placement of the cropped outline on the full canvas: `full = cropped * scale + offset`
+///
+/// The fields hold exactly the `f32` values written into the generated module,
+/// so whatever checks a placement checks what the component renders.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FullPlacement {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub scale: f32,
+}
+
+impl FullPlacement {
+    /// Rounds a computed placement to the precision the generated module stores.
+    /// The offsets must have been computed with an already rounded scale, see [`round_scale`].
+    pub fn rounded(offset_x: f64, offset_y: f64, scale: f64) -> Self {
+        Self {
+            offset_x: round_to(offset_x, OFFSET_DECIMALS) as f32,
+            offset_y: round_to(offset_y, OFFSET_DECIMALS) as f32,
+            scale: round_scale(scale) as f32,
+        }
+    }
+
+    /// SVG transform attribute applying this placement to the cropped path,
+    /// formatted like the `FontAwesomeSvg` component formats it
+    pub fn transform(&self) -> String {
+        if self.scale == 1.0 {
+            format!("translate({} {})", self.offset_x, self.offset_y)
+        } else {
+            format!(
+                "translate({} {}) scale({})",
+                self.offset_x, self.offset_y, self.scale
+            )
+        }
+    }
+}
+
+/// Rounds a scale the way [`FullPlacement::rounded`] does, returning the value
+/// as stored so offsets can be computed against it
+pub fn round_scale(scale: f64) -> f64 {
+    round_to(scale, SCALE_DECIMALS) as f32 as f64
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+/// Formats a number the way it would be written in SVG, without trailing zeros
+pub fn format_number(value: f64) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded == rounded.trunc() {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+/// Computes where the cropped outline sits within the full icon
+pub fn derive_placement(cropped: &IconData, full: &IconData) -> Result<FullPlacement, String> {
+    let cropped_box = path::path_bounding_box(&cropped.path_data)
+        .map_err(|e| format!("{}: {}", cropped.file_path, e))?;
+    let full_box = path::path_bounding_box(&full.path_data)
+        .map_err(|e| format!("{}: {}", full.file_path, e))?;
+
+    if cropped_box.width() <= 0.0 || cropped_box.height() <= 0.0 {
+        return Err(format!("{}: path has an empty bounding box", cropped.file_path));
+    }
+
+    let scale_x = full_box.width() / cropped_box.width();
+    let scale_y = full_box.height() / cropped_box.height();
+    if (scale_x - scale_y).abs() > 1e-3 * scale_x.max(scale_y) {
+        return Err(format!(
+            "{}: full icon is not a uniform scale of the cropped icon ({} vs {})",
+            full.file_path, scale_x, scale_y
+        ));
+    }
+
+    // Offsets are fitted to the scale as stored, so rounding the scale doesn't shift the outline
+    let scale = round_scale(scale_x);
+    Ok(FullPlacement::rounded(
+        full_box.min_x - cropped_box.min_x * scale,
+        full_box.min_y - cropped_box.min_y * scale,
+        scale,
+    ))
+}
+
+/// Size FontAwesome draws icon content at within the full canvas
+const FULL_CONTENT_SIZE: f64 = 512.0;
+
+/// Size of the full canvas, matching [`FULL_VIEW_BOX`]
+const FULL_CANVAS_SIZE: f64 = 640.0;
+
+/// Places an outline on the full canvas the way FontAwesome does: 512 units
+/// high, or as wide as the 640-unit canvas if that is narrower, and centered.
+/// `IconFit::Full` in yew-shortcuts pads cropped viewBoxes by the same rule.
+pub fn centered_placement(bbox: &path::BoundingBox) -> FullPlacement {
+    let side = (bbox.height() * FULL_CANVAS_SIZE / FULL_CONTENT_SIZE).max(bbox.width());
+    let scale = round_scale(FULL_CANVAS_SIZE / side);
+    FullPlacement::rounded(
+        (FULL_CANVAS_SIZE - bbox.width() * scale) / 2.0 - bbox.min_x * scale,
+        (FULL_CANVAS_SIZE - bbox.height() * scale) / 2.0 - bbox.min_y * scale,
+        scale,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(view_box: &str, path_data: &str) -> IconData {
+        IconData {
+            name: "TEST".to_string(),
+            category: "solid".to_string(),
+            view_box: view_box.to_string(),
+            path_data: path_data.to_string(),
+            comment: String::new(),
+            file_path: "test.svg".to_string(),
+        }
+    }
+
+    #[test]
+    fn derives_scale_and_offsets() {
+        let cropped = icon("0 0 448 512", "M0 0H448V512H0Z");
+        let full = icon(FULL_VIEW_BOX, "M96 64H544V576H96Z");
+        let placement = derive_placement(&cropped, &full).unwrap();
+        assert_eq!(placement, FullPlacement { offset_x: 96.0, offset_y: 64.0, scale: 1.0 });
+        assert_eq!(placement.transform(), "translate(96 64)");
+
+        // Cropped outlines starting away from the origin
+        let cropped = icon("10 20 100 50", "M10 20h100v50h-100z");
+        let full = icon(FULL_VIEW_BOX, "M64 192h512v256h-512z");
+        let placement = derive_placement(&cropped, &full).unwrap();
+        assert_eq!(placement, FullPlacement { offset_x: 12.8, offset_y: 89.6, scale: 5.12 });
+        assert_eq!(placement.transform(), "translate(12.8 89.6) scale(5.12)");
+    }
+
+    #[test]
+    fn rounds_like_the_generated_module() {
+        // 512 / 3 = 170.666..., stored with four decimals
+        let cropped = icon("0 0 3 3", "M0 0h3v3h-3z");
+        let full = icon(FULL_VIEW_BOX, "M64 64h512v512h-512z");
+        let placement = derive_placement(&cropped, &full).unwrap();
+        assert_eq!(placement.scale, 170.6667);
+        // The offset fits the rounded scale rather than the exact one
+        assert_eq!(placement.offset_x, 64.0);
+
+        let rounded = FullPlacement::rounded(1.23456, -0.0004, 0.123456);
+        assert_eq!(rounded, FullPlacement { offset_x: 1.235, offset_y: -0.0, scale: 0.1235 });
+        assert_eq!(round_scale(0.123456), 0.1235f32 as f64);
+    }
+
+    #[test]
+    fn rejects_non_uniform_scales() {
+        let cropped = icon("0 0 10 10", "M0 0h10v10h-10z");
+        let full = icon(FULL_VIEW_BOX, "M0 0h100v200h-100z");
+        assert!(derive_placement(&cropped, &full).is_err());
+
+        let empty = icon("0 0 10 10", "M0 0h10");
+        assert!(derive_placement(&empty, &full).is_err());
+    }
+
+    #[test]
+    fn centers_like_fontawesome() {
+        // Tall outlines are 512 units high, centered horizontally
+        let tall = centered_placement(&path::path_bounding_box("M0 0h8v16h-8z").unwrap());
+        assert_eq!(tall, FullPlacement { offset_x: 192.0, offset_y: 64.0, scale: 32.0 });
+
+        // Slightly wide outlines are still 512 units high
+        let house = centered_placement(&path::path_bounding_box("M0 0h576v512h-576z").unwrap());
+        assert_eq!(house, FullPlacement { offset_x: 32.0, offset_y: 64.0, scale: 1.0 });
+
+        // Wider outlines span the canvas, centered vertically
+        let wide = centered_placement(&path::path_bounding_box("M10 10h32v16h-32z").unwrap());
+        assert_eq!(wide, FullPlacement { offset_x: -200.0, offset_y: -40.0, scale: 20.0 });
+
+        let square = centered_placement(&path::path_bounding_box("M-5 -5h10v10h-10z").unwrap());
+        assert_eq!(square, FullPlacement { offset_x: 320.0, offset_y: 320.0, scale: 51.2 });
+    }
+
+    #[test]
+    fn formats_numbers_without_trailing_zeros() {
+        assert_eq!(format_number(640.0), "640");
+        assert_eq!(format_number(-0.5), "-0.5");
+        assert_eq!(format_number(1.23449), "1.234");
+        assert_eq!(format_number(2.0004), "2");
+    }
+}
@@ -0,0 +1,25 @@
+//! Icon model shared by the yew-shortcuts tooling
+//!
+//! `icon-processor`, the demo build script and the MCP icon server all need to
+//! agree on how SVG files are parsed, how icons are named and what the
+//! generated `fontawesome.rs` and its manifest look like. This crate owns all
+//! of that, so the code that writes the manifest and the code that reads it
+//! back can't drift apart.
+
+pub mod aliases;
+pub mod catalog;
+pub mod codegen;
+pub mod custom;
+pub mod full;
+pub mod manifest;
+pub mod metadata;
+pub mod naming;
+pub mod optical;
+pub mod path;
+#[cfg(feature = "usage")]
+pub mod usage;
+mod svg;
+mod view_box;
+
+pub use svg::{process_icons_directory, process_svg_file, IconData, IconSet, SvgError, SvgErrorKind};
+pub use view_box::ViewBox;
@@ -0,0 +1,438 @@
+//! Versioned, serializable description of a generated icon set
+//!
+//! `icon-processor` writes the manifest next to `fontawesome.rs` as JSON and,
+//! with the `msgpack` feature, as MessagePack. The layout is documented in
+//! `manifest.schema.json`; readers should go through [`Manifest::read`] or the
+//! `from_*` constructors, which reject manifests newer than they understand.
+
+use crate::full::{FullPlacement, FULL_VIEW_BOX};
+use crate::metadata::IconMetadata;
+use crate::naming::{import_path, rust_name_to_kebab_case};
+use crate::optical::OpticalBounds;
+use crate::{IconData, ViewBox};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Manifest format version written by this crate. Bumped whenever a field is
+/// removed or changes meaning; new optional fields don't bump it.
+pub const MANIFEST_VERSION: u32 = 2;
+
+/// Every icon in a generated `fontawesome.rs`, independent of the Rust source layout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Format version, see [`MANIFEST_VERSION`]
+    pub version: u32,
+    /// The FontAwesome license comment shared by all icons
+    pub license: String,
+    /// Icons sorted by category and Rust name
+    pub icons: Vec<ManifestIcon>,
+}
+
+/// A single icon of the manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestIcon {
+    /// Category module, e.g. `solid`
+    pub category: String,
+    /// Kebab-case FontAwesome name, e.g. `arrow-right`
+    pub name: String,
+    /// Generated constant name, e.g. `ARROW_RIGHT`
+    pub rust_name: String,
+    /// Path relative to `yew_shortcuts::fontawesome`, e.g. `icons::solid::ARROW_RIGHT`
+    pub import_path: String,
+    /// viewBox of the cropped icon
+    pub view_box: String,
+    /// The numbers of `view_box`
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// `width / height`
+    pub aspect_ratio: f32,
+    /// Path data of the cropped icon
+    pub path_data: String,
+    /// Hex FNV-1a hash of the whitespace-normalized path data
+    pub content_hash: String,
+    /// Ink bounds and optical center, when the path data could be measured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optical: Option<ManifestOptical>,
+    /// Perceptual hash of the rendered shape as 16 hex digits; icons that look alike differ in few bits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phash: Option<String>,
+    /// Other names FontAwesome lists for the icon, from its metadata
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// FontAwesome's search keywords for the icon, from its metadata
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_terms: Vec<String>,
+    /// Placement on the full canvas, when the icon has a full variant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full: Option<ManifestFull>,
+}
+
+/// Tight box around the drawn outline and the centroid of its filled area, in viewBox units
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestOptical {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub center_x: f32,
+    pub center_y: f32,
+}
+
+impl From<&OpticalBounds> for ManifestOptical {
+    fn from(optical: &OpticalBounds) -> Self {
+        Self {
+            min_x: optical.bounds.min_x,
+            min_y: optical.bounds.min_y,
+            width: optical.bounds.width,
+            height: optical.bounds.height,
+            center_x: optical.center_x,
+            center_y: optical.center_y,
+        }
+    }
+}
+
+/// The full variant of an icon: the cropped path drawn at `cropped * scale + offset`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestFull {
+    /// viewBox of the full icon
+    pub view_box: String,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub scale: f32,
+}
+
+impl From<&FullPlacement> for ManifestFull {
+    fn from(placement: &FullPlacement) -> Self {
+        // Same precision as the constants in the generated module
+        Self {
+            view_box: FULL_VIEW_BOX.to_string(),
+            offset_x: placement.offset_x,
+            offset_y: placement.offset_y,
+            scale: placement.scale,
+        }
+    }
+}
+
+impl ManifestIcon {
+    /// Describes an icon, failing if its viewBox can't be parsed into a positive size
+    pub fn new(category: &str, rust_name: &str, view_box: &str, path_data: &str) -> Result<Self, ManifestError> {
+        let bounds = ViewBox::parse(view_box).ok_or_else(|| ManifestError::InvalidViewBox {
+            import_path: import_path(category, rust_name),
+            view_box: view_box.to_string(),
+        })?;
+        Ok(Self {
+            category: category.to_string(),
+            name: rust_name_to_kebab_case(rust_name),
+            rust_name: rust_name.to_string(),
+            import_path: import_path(category, rust_name),
+            view_box: view_box.to_string(),
+            min_x: bounds.min_x,
+            min_y: bounds.min_y,
+            width: bounds.width,
+            height: bounds.height,
+            aspect_ratio: bounds.aspect_ratio(),
+            path_data: path_data.to_string(),
+            content_hash: format!("{:016x}", geometry_hash(path_data)),
+            optical: OpticalBounds::from_path_data(path_data)
+                .ok()
+                .map(|optical| ManifestOptical::from(&optical)),
+            phash: None,
+            aliases: Vec::new(),
+            search_terms: Vec::new(),
+            full: None,
+        })
+    }
+
+    /// The perceptual hash as a number, for Hamming distance comparisons
+    pub fn perceptual_hash(&self) -> Option<u64> {
+        u64::from_str_radix(self.phash.as_deref()?, 16).ok()
+    }
+}
+
+impl TryFrom<&IconData> for ManifestIcon {
+    type Error = ManifestError;
+
+    fn try_from(icon: &IconData) -> Result<Self, ManifestError> {
+        Self::new(&icon.category, &icon.name, &icon.view_box, &icon.path_data)
+    }
+}
+
+/// Errors from building or reading a manifest
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    #[cfg(feature = "msgpack")]
+    MsgPack(rmp_serde::decode::Error),
+    /// The manifest was written by a newer format version
+    UnsupportedVersion(u32),
+    /// The file extension is neither `.json` nor `.msgpack`
+    UnknownFormat(String),
+    /// An icon's viewBox is not four numbers with a positive size
+    InvalidViewBox { import_path: String, view_box: String },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(e) => write!(f, "failed to read manifest: {}", e),
+            ManifestError::Json(e) => write!(f, "invalid JSON manifest: {}", e),
+            #[cfg(feature = "msgpack")]
+            ManifestError::MsgPack(e) => write!(f, "invalid MessagePack manifest: {}", e),
+            ManifestError::UnsupportedVersion(version) => write!(
+                f,
+                "manifest version {} is newer than the supported version {}",
+                version, MANIFEST_VERSION
+            ),
+            ManifestError::UnknownFormat(path) => {
+                write!(f, "{}: expected a .json or .msgpack manifest", path)
+            }
+            ManifestError::InvalidViewBox { import_path, view_box } => {
+                write!(f, "{}: invalid viewBox \"{}\"", import_path, view_box)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+/// Just the version field, read before the rest so newer layouts fail cleanly
+#[derive(Deserialize)]
+struct VersionProbe {
+    version: u32,
+}
+
+fn check_version(version: u32) -> Result<(), ManifestError> {
+    if version > MANIFEST_VERSION {
+        return Err(ManifestError::UnsupportedVersion(version));
+    }
+    Ok(())
+}
+
+impl Manifest {
+    pub fn new(license: &str, mut icons: Vec<ManifestIcon>) -> Self {
+        icons.sort_by(|a, b| (&a.category, &a.rust_name).cmp(&(&b.category, &b.rust_name)));
+        Self {
+            version: MANIFEST_VERSION,
+            license: license.to_string(),
+            icons,
+        }
+    }
+
+    /// Builds a manifest from icons read from SVG files and their full placements
+    pub fn from_icons(
+        icons: &[IconData],
+        placements: &BTreeMap<(String, String), FullPlacement>,
+        license: &str,
+    ) -> Result<Self, ManifestError> {
+        let icons = icons
+            .iter()
+            .map(|icon| {
+                let mut entry = ManifestIcon::try_from(icon)?;
+                entry.full = placements
+                    .get(&(icon.category.clone(), icon.name.clone()))
+                    .map(ManifestFull::from);
+                Ok(entry)
+            })
+            .collect::<Result<_, ManifestError>>()?;
+        Ok(Self::new(license, icons))
+    }
+
+    /// Reads a manifest, choosing the format from the file extension
+    pub fn read(path: &Path) -> Result<Self, ManifestError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&fs::read_to_string(path).map_err(ManifestError::Io)?),
+            #[cfg(feature = "msgpack")]
+            Some("msgpack") => Self::from_msgpack(&fs::read(path).map_err(ManifestError::Io)?),
+            _ => Err(ManifestError::UnknownFormat(path.display().to_string())),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ManifestError> {
+        let probe: VersionProbe = serde_json::from_str(json).map_err(ManifestError::Json)?;
+        check_version(probe.version)?;
+        serde_json::from_str(json).map_err(ManifestError::Json)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("manifest is always serializable")
+    }
+
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, ManifestError> {
+        let probe: VersionProbe = rmp_serde::from_slice(bytes).map_err(ManifestError::MsgPack)?;
+        check_version(probe.version)?;
+        rmp_serde::from_slice(bytes).map_err(ManifestError::MsgPack)
+    }
+
+    /// MessagePack encoding with field names, so readers can skip unknown fields
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        rmp_serde::to_vec_named(self).expect("manifest is always serializable")
+    }
+
+    /// Fills in aliases and search terms from FontAwesome metadata keyed by kebab-case name
+    pub fn apply_metadata(&mut self, metadata: &BTreeMap<String, IconMetadata>) {
+        for icon in &mut self.icons {
+            if let Some(entry) = metadata.get(&icon.name) {
+                icon.aliases = entry.aliases.clone();
+                icon.search_terms = entry.search_terms.clone();
+            }
+        }
+    }
+
+    /// Records perceptual hashes computed from each icon's shape
+    pub fn apply_perceptual_hashes(&mut self, hash: impl Fn(&ManifestIcon) -> Option<u64>) {
+        for icon in &mut self.icons {
+            icon.phash = hash(icon).map(|hash| format!("{:016x}", hash));
+        }
+    }
+
+    /// Category names in alphabetical order
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self.icons.iter().map(|i| i.category.as_str()).collect();
+        categories.dedup();
+        categories
+    }
+
+    /// Icons of a single category
+    pub fn category(&self, category: &str) -> impl Iterator<Item = &ManifestIcon> {
+        let category = category.to_string();
+        self.icons.iter().filter(move |icon| icon.category == category)
+    }
+
+    /// Looks up an icon by category and kebab-case name
+    pub fn find(&self, category: &str, name: &str) -> Option<&ManifestIcon> {
+        self.icons
+            .iter()
+            .find(|icon| icon.category == category && icon.name == name)
+    }
+}
+
+/// FNV-1a hash of the path data with insignificant whitespace collapsed,
+/// stable across runs and Rust versions
+pub fn geometry_hash(path_data: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let normalized = path_data.split_whitespace().collect::<Vec<_>>().join(" ");
+    for byte in normalized.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(category: &str, name: &str, view_box: &str, path_data: &str) -> IconData {
+        IconData {
+            name: name.to_string(),
+            category: category.to_string(),
+            view_box: view_box.to_string(),
+            path_data: path_data.to_string(),
+            comment: String::new(),
+            file_path: format!("{}/{}.svg", category, rust_name_to_kebab_case(name)),
+        }
+    }
+
+    /// A manifest using every optional field
+    fn sample() -> Manifest {
+        let icons = [
+            icon("solid", "HOUSE", "0 0 576 512", "M0 0h576v512H0z"),
+            icon("regular", "ICON_0", "0 0 320 512", "M0 0h320v512H0z"),
+            icon("brands", "GITHUB", "0 0 496 512", "M248 8a248 248 0 1 0 0 496a248 248 0 1 0 0-496z"),
+        ];
+        let placements = BTreeMap::from([(
+            ("solid".to_string(), "HOUSE".to_string()),
+            FullPlacement::rounded(32.0, 64.0, 1.0),
+        )]);
+        let metadata = BTreeMap::from([(
+            "house".to_string(),
+            IconMetadata {
+                aliases: vec!["home".to_string()],
+                search_terms: vec!["building".to_string()],
+            },
+        )]);
+
+        let mut manifest = Manifest::from_icons(&icons, &placements, "license").unwrap();
+        manifest.apply_metadata(&metadata);
+        manifest.apply_perceptual_hashes(|icon| (icon.name == "house").then_some(0x0123_4567_89ab_cdef));
+        manifest
+    }
+
+    #[test]
+    fn describes_icons() {
+        let manifest = sample();
+        assert_eq!(manifest.categories(), ["brands", "regular", "solid"]);
+
+        let house = manifest.find("solid", "house").unwrap();
+        assert_eq!(house.import_path, "icons::solid::HOUSE");
+        assert_eq!((house.width, house.height), (576.0, 512.0));
+        assert_eq!(house.aliases, ["home"]);
+        assert_eq!(house.perceptual_hash(), Some(0x0123_4567_89ab_cdef));
+        assert_eq!(house.full.as_ref().map(|full| (full.offset_x, full.offset_y)), Some((32.0, 64.0)));
+
+        let zero = manifest.find("regular", "0").unwrap();
+        assert_eq!(zero.rust_name, "ICON_0");
+        assert!(zero.full.is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_view_boxes() {
+        for view_box in ["", "0 0 512", "0 0 0 512", "0 0 a 512"] {
+            let icons = [icon("solid", "BROKEN", view_box, "M0 0h1v1z")];
+            let error = Manifest::from_icons(&icons, &BTreeMap::new(), "").unwrap_err();
+            assert!(
+                matches!(&error, ManifestError::InvalidViewBox { import_path, .. } if import_path == "icons::solid::BROKEN"),
+                "{:?}: {}",
+                view_box,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn json_round_trips() {
+        let manifest = sample();
+        assert_eq!(Manifest::from_json(&manifest.to_json()).unwrap(), manifest);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_round_trips() {
+        let manifest = sample();
+        assert_eq!(Manifest::from_msgpack(&manifest.to_msgpack()).unwrap(), manifest);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let mut manifest = sample();
+        manifest.version = MANIFEST_VERSION + 1;
+        assert!(matches!(
+            Manifest::from_json(&manifest.to_json()),
+            Err(ManifestError::UnsupportedVersion(version)) if version == MANIFEST_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn matches_the_schema() {
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../manifest.schema.json")).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+
+        let manifest: serde_json::Value = serde_json::from_str(&sample().to_json()).unwrap();
+        let errors: Vec<String> = validator.iter_errors(&manifest).map(|e| format!("{} at {}", e, e.instance_path)).collect();
+        assert!(errors.is_empty(), "{:#?}", errors);
+
+        // The schema is strict enough to catch what `ManifestIcon::new` rejects
+        let mut broken = manifest.clone();
+        broken["icons"][0]["width"] = 0.into();
+        assert!(!validator.is_valid(&broken));
+    }
+}
@@ -0,0 +1,81 @@
+//! FontAwesome's icon metadata (`metadata/icons.json` in a download)
+//!
+//! Only the parts used for search are read: the alternative names FontAwesome
+//! lists under `aliases.names` and the keywords under `search.terms`. Other
+//! fields are ignored, so newer metadata layouts keep working.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Search metadata of one icon
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IconMetadata {
+    /// Other names of the icon, e.g. `home` for `house`
+    pub aliases: Vec<String>,
+    /// Keywords describing the icon, e.g. `building` and `residence` for `house`
+    pub search_terms: Vec<String>,
+}
+
+/// Errors from reading a metadata file
+#[derive(Debug)]
+pub enum MetadataError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// The top level is not an object keyed by icon name
+    UnexpectedLayout,
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetadataError::Io(e) => write!(f, "failed to read icon metadata: {}", e),
+            MetadataError::Json(e) => write!(f, "invalid icon metadata: {}", e),
+            MetadataError::UnexpectedLayout => write!(f, "icon metadata is not an object keyed by icon name"),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {}
+
+/// Reads `icons.json`, keyed by kebab-case icon name
+pub fn read_metadata(path: &Path) -> Result<BTreeMap<String, IconMetadata>, MetadataError> {
+    let json = fs::read_to_string(path).map_err(MetadataError::Io)?;
+    parse_metadata(&json)
+}
+
+/// Parses the contents of `icons.json`
+pub fn parse_metadata(json: &str) -> Result<BTreeMap<String, IconMetadata>, MetadataError> {
+    let value: Value = serde_json::from_str(json).map_err(MetadataError::Json)?;
+    let icons = value.as_object().ok_or(MetadataError::UnexpectedLayout)?;
+
+    Ok(icons
+        .iter()
+        .map(|(name, icon)| {
+            let metadata = IconMetadata {
+                aliases: strings(&icon["aliases"]["names"]),
+                search_terms: strings(&icon["search"]["terms"]),
+            };
+            (name.clone(), metadata)
+        })
+        .collect())
+}
+
+/// String items of a JSON array; some releases list numeric terms as numbers
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
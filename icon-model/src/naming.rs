@@ -0,0 +1,242 @@
+//! Conversions between icon file names, Rust identifiers and import paths
+
+use crate::IconData;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Converts a kebab-case icon file name into the generated constant name
+pub fn snake_case_to_upper(s: &str) -> String {
+    let mut result = s.to_uppercase().replace('-', "_");
+    // Ensure identifiers don't start with a number
+    if result.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        result = format!("ICON_{}", result);
+    }
+    result
+}
+
+/// Converts a generated constant name back into the kebab-case icon name
+pub fn rust_name_to_kebab_case(rust_name: &str) -> String {
+    // Undo the prefix added to names starting with a digit
+    let rust_name = match rust_name.strip_prefix("ICON_") {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => rust_name,
+    };
+
+    rust_name
+        .chars()
+        .map(|c| {
+            if c == '_' {
+                '-'
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+/// Path of an icon constant relative to `yew_shortcuts::fontawesome`
+pub fn import_path(category: &str, rust_name: &str) -> String {
+    format!("icons::{}::{}", category, rust_name)
+}
+
+/// Id of the sprite `<symbol>` an icon is rendered into, e.g. `fa-solid-house`
+pub fn symbol_id(category: &str, kebab_name: &str) -> String {
+    format!("fa-{}-{}", category, kebab_name)
+}
+
+/// Categories of the free download, each behind its own `fa-*` feature;
+/// Pro styles are generated into `fontawesome/pro` behind the `pro` feature
+pub const FREE_CATEGORIES: &[&str] = &["solid", "regular", "brands"];
+
+/// Cargo feature of `yew-shortcuts` that enables a category module
+pub fn category_feature(category: &str) -> String {
+    format!("fa-{}", category.replace('_', "-"))
+}
+
+/// Strict and reserved Rust keywords, which can't be used as plain identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Whether `ident` is a Rust keyword
+pub fn is_keyword(ident: &str) -> bool {
+    RUST_KEYWORDS.contains(&ident)
+}
+
+/// Whether `ident` is a plain ASCII Rust identifier
+pub fn is_valid_identifier(ident: &str) -> bool {
+    let mut chars = ident.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// What is wrong with a generated identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierProblemKind {
+    /// Several files map to the same constant
+    Duplicate,
+    /// A constant or module name is a Rust keyword
+    Keyword,
+    /// A constant or module name is not a plain Rust identifier
+    Invalid,
+}
+
+/// A generated identifier that would not compile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifierProblem {
+    pub kind: IdentifierProblemKind,
+    /// The file the identifier comes from; `None` when several files share it
+    pub file: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for IdentifierProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}: {}", file, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Checks the constant and category module names generated for a set of
+/// icons for collisions, keywords and invalid characters. Icons with an empty
+/// category sit at the top of the module and get no category module.
+pub fn check_identifiers<'a>(icons: impl IntoIterator<Item = &'a IconData>) -> Vec<IdentifierProblem> {
+    let mut problems = Vec::new();
+    let mut files_by_ident: BTreeMap<(&str, &str), Vec<&str>> = BTreeMap::new();
+    let mut categories: BTreeMap<&str, &str> = BTreeMap::new();
+    for icon in icons {
+        files_by_ident
+            .entry((&icon.category, &icon.name))
+            .or_default()
+            .push(&icon.file_path);
+        categories.entry(&icon.category).or_insert(&icon.file_path);
+    }
+
+    let problem = |kind, file: Option<&str>, message: String| IdentifierProblem {
+        kind,
+        file: file.map(str::to_string),
+        message,
+    };
+
+    for ((category, name), files) in &files_by_ident {
+        if files.len() > 1 {
+            let path = if category.is_empty() {
+                name.to_string()
+            } else {
+                import_path(category, name)
+            };
+            problems.push(problem(
+                IdentifierProblemKind::Duplicate,
+                None,
+                format!("{} is generated from {} files: {}", path, files.len(), files.join(", ")),
+            ));
+        }
+        if is_keyword(name) {
+            problems.push(problem(
+                IdentifierProblemKind::Keyword,
+                Some(files[0]),
+                format!("constant name '{}' is a Rust keyword", name),
+            ));
+        } else if !is_valid_identifier(name) {
+            problems.push(problem(
+                IdentifierProblemKind::Invalid,
+                Some(files[0]),
+                format!("constant name '{}' is not a valid Rust identifier", name),
+            ));
+        }
+    }
+
+    for (category, file) in categories {
+        if category.is_empty() {
+            continue;
+        }
+        if is_keyword(category) {
+            problems.push(problem(
+                IdentifierProblemKind::Keyword,
+                Some(file),
+                format!("category module name '{}' is a Rust keyword", category),
+            ));
+        } else if !is_valid_identifier(category) {
+            problems.push(problem(
+                IdentifierProblemKind::Invalid,
+                Some(file),
+                format!("category '{}' is not a valid Rust module name", category),
+            ));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(category: &str, file: &str) -> IconData {
+        let stem = file.rsplit('/').next().unwrap().trim_end_matches(".svg");
+        IconData {
+            name: snake_case_to_upper(stem),
+            category: category.to_string(),
+            view_box: "0 0 16 16".to_string(),
+            path_data: "M0 0h16v16H0z".to_string(),
+            comment: String::new(),
+            file_path: file.to_string(),
+        }
+    }
+
+    #[test]
+    fn converts_between_file_and_constant_names() {
+        for (file, constant) in [("house", "HOUSE"), ("arrow-right", "ARROW_RIGHT"), ("0", "ICON_0"), ("42-group", "ICON_42_GROUP")] {
+            assert_eq!(snake_case_to_upper(file), constant);
+            assert_eq!(rust_name_to_kebab_case(constant), file);
+        }
+        assert_eq!(import_path("solid", "HOUSE"), "icons::solid::HOUSE");
+        assert_eq!(category_feature("sharp_solid"), "fa-sharp-solid");
+        assert_eq!(symbol_id("solid", "house"), "fa-solid-house");
+    }
+
+    #[test]
+    fn accepts_distinct_valid_identifiers() {
+        let icons = [icon("solid", "solid/house.svg"), icon("regular", "regular/house.svg"), icon("", "logo.svg")];
+        assert_eq!(check_identifiers(&icons), []);
+    }
+
+    #[test]
+    fn reports_colliding_and_invalid_identifiers() {
+        let icons = [
+            icon("solid", "solid/arrow-up.svg"),
+            icon("solid", "solid/arrow_up.svg"),
+            icon("", "a-b.svg"),
+            icon("", "a_b.svg"),
+            icon("", "café.svg"),
+            icon("type", "type/house.svg"),
+            icon("my.icons", "my.icons/house.svg"),
+        ];
+
+        let problems: Vec<(IdentifierProblemKind, String)> = check_identifiers(&icons)
+            .into_iter()
+            .map(|problem| (problem.kind, problem.to_string()))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                (IdentifierProblemKind::Duplicate, "A_B is generated from 2 files: a-b.svg, a_b.svg".to_string()),
+                (IdentifierProblemKind::Invalid, "café.svg: constant name 'CAFÉ' is not a valid Rust identifier".to_string()),
+                (
+                    IdentifierProblemKind::Duplicate,
+                    "icons::solid::ARROW_UP is generated from 2 files: solid/arrow-up.svg, solid/arrow_up.svg".to_string()
+                ),
+                (IdentifierProblemKind::Invalid, "my.icons/house.svg: category 'my.icons' is not a valid Rust module name".to_string()),
+                (IdentifierProblemKind::Keyword, "type/house.svg: category module name 'type' is a Rust keyword".to_string()),
+            ]
+        );
+    }
+}
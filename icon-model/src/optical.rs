@@ -0,0 +1,43 @@
+//! Optical geometry of an icon's outline.
+//!
+//! A cropped viewBox includes FontAwesome's side bearings, and the middle of a
+//! bounding box is not where an asymmetric glyph looks centered. The optical
+//! bounds are the tight box around the ink and the centroid of its filled
+//! area, which is what `IconFit` centers on when it pads a viewBox.
+
+use crate::path::{self, PathError};
+use crate::ViewBox;
+
+/// Ink bounding box and area centroid of an outline, in viewBox units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpticalBounds {
+    pub bounds: ViewBox,
+    pub center_x: f32,
+    pub center_y: f32,
+}
+
+impl OpticalBounds {
+    /// Measures path data; the center falls back to the middle of the ink
+    /// when the outline encloses no area
+    pub fn from_path_data(d: &str) -> Result<Self, PathError> {
+        let segments = path::parse(d)?;
+        let bbox = path::bounding_box(&segments).ok_or_else(|| PathError {
+            message: "path has no drawable segments".to_string(),
+        })?;
+        let center = path::area_centroid(&segments).unwrap_or(path::Point::new(
+            bbox.min_x + bbox.width() / 2.0,
+            bbox.min_y + bbox.height() / 2.0,
+        ));
+
+        Ok(Self {
+            bounds: ViewBox {
+                min_x: bbox.min_x as f32,
+                min_y: bbox.min_y as f32,
+                width: bbox.width() as f32,
+                height: bbox.height() as f32,
+            },
+            center_x: center.x as f32,
+            center_y: center.y as f32,
+        })
+    }
+}
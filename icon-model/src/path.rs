@@ -0,0 +1,764 @@
+//! Minimal SVG path data parser used for geometry calculations.
+//!
+//! Path data is normalized into absolute move/line/quadratic/cubic segments:
+//! `H`/`V` become lines, `S`/`T` have their reflected control points resolved,
+//! and elliptical arcs are approximated with cubic Béziers.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// An absolute path segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadTo(Point, Point),
+    CubicTo(Point, Point, Point),
+    Close,
+}
+
+/// Axis-aligned bounding box of a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl BoundingBox {
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    fn include(&mut self, p: Point) {
+        self.min_x = self.min_x.min(p.x);
+        self.min_y = self.min_y.min(p.y);
+        self.max_x = self.max_x.max(p.x);
+        self.max_y = self.max_y.max(p.y);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathError {
+    pub message: String,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid path data: {}", self.message)
+    }
+}
+
+impl std::error::Error for PathError {}
+
+fn error(message: impl Into<String>) -> PathError {
+    PathError {
+        message: message.into(),
+    }
+}
+
+struct Tokenizer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(d: &'a str) -> Self {
+        Self {
+            bytes: d.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.pos < self.bytes.len()
+            && (self.bytes[self.pos].is_ascii_whitespace() || self.bytes[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn peek_command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes
+            .get(self.pos)
+            .copied()
+            .filter(|b| b.is_ascii_alphabetic() && *b != b'e' && *b != b'E')
+    }
+
+    fn at_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.bytes.get(self.pos), Some(b) if b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f64, PathError> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+        let mut seen_dot = false;
+        let mut seen_digit = false;
+        while let Some(&b) = self.bytes.get(self.pos) {
+            if b.is_ascii_digit() {
+                seen_digit = true;
+            } else if b == b'.' && !seen_dot {
+                seen_dot = true;
+            } else {
+                break;
+            }
+            self.pos += 1;
+        }
+        if seen_digit && matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+            let mark = self.pos;
+            self.pos += 1;
+            if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+            if matches!(self.bytes.get(self.pos), Some(b) if b.is_ascii_digit()) {
+                while matches!(self.bytes.get(self.pos), Some(b) if b.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+            } else {
+                self.pos = mark;
+            }
+        }
+        if !seen_digit {
+            return Err(error(format!("expected a number at offset {}", start)));
+        }
+        // The slice only contains ASCII digits, signs, dots and exponents
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| error(format!("malformed number at offset {}", start)))
+    }
+
+    fn flag(&mut self) -> Result<bool, PathError> {
+        self.skip_separators();
+        match self.bytes.get(self.pos) {
+            Some(b'0') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(error(format!("expected an arc flag at offset {}", self.pos))),
+        }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_separators();
+        self.pos >= self.bytes.len()
+    }
+}
+
+/// Parses SVG path data into absolute segments
+pub fn parse(d: &str) -> Result<Vec<Segment>, PathError> {
+    let mut tokens = Tokenizer::new(d);
+    let mut segments = Vec::new();
+
+    let mut current = Point::new(0.0, 0.0);
+    let mut subpath_start = current;
+    // Control point of the previous curve, used for S/T reflection
+    let mut last_cubic_ctrl: Option<Point> = None;
+    let mut last_quad_ctrl: Option<Point> = None;
+    let mut command: Option<u8> = None;
+
+    while !tokens.is_done() {
+        if let Some(c) = tokens.peek_command() {
+            tokens.pos += 1;
+            command = Some(c);
+        } else if !tokens.at_number() {
+            return Err(error(format!(
+                "unexpected character at offset {}",
+                tokens.pos
+            )));
+        }
+
+        let cmd = command.ok_or_else(|| error("path data must start with a command"))?;
+        let relative = cmd.is_ascii_lowercase();
+        let base = if relative { current } else { Point::new(0.0, 0.0) };
+        let point = |tokens: &mut Tokenizer| -> Result<Point, PathError> {
+            let x = tokens.number()?;
+            let y = tokens.number()?;
+            Ok(Point::new(base.x + x, base.y + y))
+        };
+
+        let mut cubic_ctrl = None;
+        let mut quad_ctrl = None;
+
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                current = point(&mut tokens)?;
+                subpath_start = current;
+                segments.push(Segment::MoveTo(current));
+                // Subsequent coordinate pairs are implicit line-to commands
+                command = Some(if relative { b'l' } else { b'L' });
+            }
+            b'L' => {
+                current = point(&mut tokens)?;
+                segments.push(Segment::LineTo(current));
+            }
+            b'H' => {
+                let x = tokens.number()?;
+                current = Point::new(if relative { current.x + x } else { x }, current.y);
+                segments.push(Segment::LineTo(current));
+            }
+            b'V' => {
+                let y = tokens.number()?;
+                current = Point::new(current.x, if relative { current.y + y } else { y });
+                segments.push(Segment::LineTo(current));
+            }
+            b'C' => {
+                let c1 = point(&mut tokens)?;
+                let c2 = point(&mut tokens)?;
+                current = point(&mut tokens)?;
+                segments.push(Segment::CubicTo(c1, c2, current));
+                cubic_ctrl = Some(c2);
+            }
+            b'S' => {
+                let c1 = reflect(last_cubic_ctrl, current);
+                let c2 = point(&mut tokens)?;
+                current = point(&mut tokens)?;
+                segments.push(Segment::CubicTo(c1, c2, current));
+                cubic_ctrl = Some(c2);
+            }
+            b'Q' => {
+                let c = point(&mut tokens)?;
+                current = point(&mut tokens)?;
+                segments.push(Segment::QuadTo(c, current));
+                quad_ctrl = Some(c);
+            }
+            b'T' => {
+                let c = reflect(last_quad_ctrl, current);
+                current = point(&mut tokens)?;
+                segments.push(Segment::QuadTo(c, current));
+                quad_ctrl = Some(c);
+            }
+            b'A' => {
+                let rx = tokens.number()?;
+                let ry = tokens.number()?;
+                let rotation = tokens.number()?;
+                let large_arc = tokens.flag()?;
+                let sweep = tokens.flag()?;
+                let to = point(&mut tokens)?;
+                arc_to_cubics(current, rx, ry, rotation, large_arc, sweep, to, &mut segments);
+                current = to;
+            }
+            b'Z' => {
+                segments.push(Segment::Close);
+                current = subpath_start;
+                // A close path takes no arguments, so stop repeating it
+                command = None;
+            }
+            other => {
+                return Err(error(format!("unsupported command '{}'", other as char)));
+            }
+        }
+
+        last_cubic_ctrl = cubic_ctrl;
+        last_quad_ctrl = quad_ctrl;
+    }
+
+    Ok(segments)
+}
+
+fn reflect(ctrl: Option<Point>, current: Point) -> Point {
+    match ctrl {
+        Some(c) => Point::new(2.0 * current.x - c.x, 2.0 * current.y - c.y),
+        None => current,
+    }
+}
+
+/// Converts an endpoint-parameterized elliptical arc into cubic segments
+#[allow(clippy::too_many_arguments)]
+fn arc_to_cubics(
+    from: Point,
+    rx: f64,
+    ry: f64,
+    rotation_deg: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+    segments: &mut Vec<Segment>,
+) {
+    let mut rx = rx.abs();
+    let mut ry = ry.abs();
+    if from == to {
+        return;
+    }
+    if rx == 0.0 || ry == 0.0 {
+        segments.push(Segment::LineTo(to));
+        return;
+    }
+
+    let phi = rotation_deg.to_radians();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    // Step 1: compute (x1', y1')
+    let dx = (from.x - to.x) / 2.0;
+    let dy = (from.y - to.y) / 2.0;
+    let x1p = cos_phi * dx + sin_phi * dy;
+    let y1p = -sin_phi * dx + cos_phi * dy;
+
+    // Scale radii up if they are too small to span the endpoints
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        rx *= s;
+        ry *= s;
+    }
+
+    // Step 2: compute (cx', cy')
+    let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+    let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let cxp = coef * rx * y1p / ry;
+    let cyp = -coef * ry * x1p / rx;
+
+    // Step 3: compute (cx, cy)
+    let cx = cos_phi * cxp - sin_phi * cyp + (from.x + to.x) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (from.y + to.y) / 2.0;
+
+    // Step 4: compute start angle and sweep
+    let angle = |ux: f64, uy: f64, vx: f64, vy: f64| -> f64 {
+        let dot = ux * vx + uy * vy;
+        let len = (ux * ux + uy * uy).sqrt() * (vx * vx + vy * vy).sqrt();
+        let mut a = (dot / len).clamp(-1.0, 1.0).acos();
+        if ux * vy - uy * vx < 0.0 {
+            a = -a;
+        }
+        a
+    };
+    let theta1 = angle(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+    let mut delta = angle(
+        (x1p - cxp) / rx,
+        (y1p - cyp) / ry,
+        (-x1p - cxp) / rx,
+        (-y1p - cyp) / ry,
+    );
+    if !sweep && delta > 0.0 {
+        delta -= std::f64::consts::TAU;
+    } else if sweep && delta < 0.0 {
+        delta += std::f64::consts::TAU;
+    }
+
+    // Split into pieces of at most 90 degrees
+    let pieces = (delta.abs() / std::f64::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = delta / pieces as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let map = |x: f64, y: f64| -> Point {
+        Point::new(
+            cx + rx * x * cos_phi - ry * y * sin_phi,
+            cy + rx * x * sin_phi + ry * y * cos_phi,
+        )
+    };
+
+    let mut theta = theta1;
+    for i in 0..pieces {
+        let (sin1, cos1) = theta.sin_cos();
+        let (sin2, cos2) = (theta + step).sin_cos();
+        let c1 = map(cos1 - k * sin1, sin1 + k * cos1);
+        let c2 = map(cos2 + k * sin2, sin2 - k * cos2);
+        // Land exactly on the requested endpoint to avoid drift
+        let end = if i + 1 == pieces { to } else { map(cos2, sin2) };
+        segments.push(Segment::CubicTo(c1, c2, end));
+        theta += step;
+    }
+}
+
+/// Computes the tight bounding box of parsed segments, including curve extrema
+pub fn bounding_box(segments: &[Segment]) -> Option<BoundingBox> {
+    let mut bbox: Option<BoundingBox> = None;
+    let mut include = |p: Point| match bbox.as_mut() {
+        Some(b) => b.include(p),
+        None => {
+            bbox = Some(BoundingBox {
+                min_x: p.x,
+                min_y: p.y,
+                max_x: p.x,
+                max_y: p.y,
+            })
+        }
+    };
+
+    let mut current = Point::new(0.0, 0.0);
+    for segment in segments {
+        match *segment {
+            Segment::MoveTo(p) => {
+                // A lone move-to doesn't paint anything, but FontAwesome paths
+                // always follow it with drawing commands
+                current = p;
+            }
+            Segment::LineTo(p) => {
+                include(current);
+                include(p);
+                current = p;
+            }
+            Segment::QuadTo(c, p) => {
+                include(current);
+                include(p);
+                for t in quad_extrema(current.x, c.x, p.x)
+                    .into_iter()
+                    .chain(quad_extrema(current.y, c.y, p.y))
+                    .flatten()
+                {
+                    include(eval_quad(current, c, p, t));
+                }
+                current = p;
+            }
+            Segment::CubicTo(c1, c2, p) => {
+                include(current);
+                include(p);
+                for t in cubic_extrema(current.x, c1.x, c2.x, p.x)
+                    .into_iter()
+                    .chain(cubic_extrema(current.y, c1.y, c2.y, p.y))
+                    .flatten()
+                {
+                    include(eval_cubic(current, c1, c2, p, t));
+                }
+                current = p;
+            }
+            Segment::Close => {}
+        }
+    }
+
+    bbox
+}
+
+/// Parses path data and returns its tight bounding box
+pub fn path_bounding_box(d: &str) -> Result<BoundingBox, PathError> {
+    let segments = parse(d)?;
+    bounding_box(&segments).ok_or_else(|| error("path has no drawable segments"))
+}
+
+/// Points each curve is flattened into when integrating the filled area
+const FLATTEN_STEPS: usize = 16;
+
+/// Centroid of the filled area, treating every subpath as closed.
+///
+/// Subpaths are weighted by their signed area, so holes wound against their
+/// outline subtract from it like they do under the nonzero fill rule.
+/// Returns `None` when the path encloses no area.
+pub fn area_centroid(segments: &[Segment]) -> Option<Point> {
+    let mut area = 0.0;
+    let mut moment_x = 0.0;
+    let mut moment_y = 0.0;
+
+    let mut start = Point::new(0.0, 0.0);
+    let mut current = start;
+    let mut edge = |from: Point, to: Point| {
+        let cross = from.x * to.y - to.x * from.y;
+        area += cross;
+        moment_x += (from.x + to.x) * cross;
+        moment_y += (from.y + to.y) * cross;
+    };
+
+    for segment in segments {
+        match *segment {
+            Segment::MoveTo(p) => {
+                edge(current, start);
+                start = p;
+                current = p;
+            }
+            Segment::LineTo(p) => {
+                edge(current, p);
+                current = p;
+            }
+            Segment::QuadTo(c, p) => {
+                let mut previous = current;
+                for step in 1..=FLATTEN_STEPS {
+                    let point = eval_quad(current, c, p, step as f64 / FLATTEN_STEPS as f64);
+                    edge(previous, point);
+                    previous = point;
+                }
+                current = p;
+            }
+            Segment::CubicTo(c1, c2, p) => {
+                let mut previous = current;
+                for step in 1..=FLATTEN_STEPS {
+                    let point = eval_cubic(current, c1, c2, p, step as f64 / FLATTEN_STEPS as f64);
+                    edge(previous, point);
+                    previous = point;
+                }
+                current = p;
+            }
+            Segment::Close => {
+                edge(current, start);
+                current = start;
+            }
+        }
+    }
+    edge(current, start);
+
+    if area.abs() < f64::EPSILON {
+        return None;
+    }
+    Some(Point::new(moment_x / (3.0 * area), moment_y / (3.0 * area)))
+}
+
+fn quad_extrema(p0: f64, p1: f64, p2: f64) -> [Option<f64>; 1] {
+    let den = p0 - 2.0 * p1 + p2;
+    if den.abs() < f64::EPSILON {
+        return [None];
+    }
+    let t = (p0 - p1) / den;
+    [(t > 0.0 && t < 1.0).then_some(t)]
+}
+
+fn cubic_extrema(p0: f64, p1: f64, p2: f64, p3: f64) -> [Option<f64>; 2] {
+    // Derivative coefficients: a t^2 + b t + c
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+    let in_range = |t: f64| (t > 0.0 && t < 1.0).then_some(t);
+
+    if a.abs() < 1e-12 {
+        if b.abs() < 1e-12 {
+            return [None, None];
+        }
+        return [in_range(-c / b), None];
+    }
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return [None, None];
+    }
+    let sq = disc.sqrt();
+    [in_range((-b + sq) / (2.0 * a)), in_range((-b - sq) / (2.0 * a))]
+}
+
+fn eval_quad(p0: Point, p1: Point, p2: Point, t: f64) -> Point {
+    let mt = 1.0 - t;
+    Point::new(
+        mt * mt * p0.x + 2.0 * mt * t * p1.x + t * t * p2.x,
+        mt * mt * p0.y + 2.0 * mt * t * p1.y + t * t * p2.y,
+    )
+}
+
+fn eval_cubic(p0: Point, p1: Point, p2: Point, p3: Point, t: f64) -> Point {
+    let mt = 1.0 - t;
+    let a = mt * mt * mt;
+    let b = 3.0 * mt * mt * t;
+    let c = 3.0 * mt * t * t;
+    let d = t * t * t;
+    Point::new(
+        a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+        a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: f64, y: f64) -> Point {
+        Point::new(x, y)
+    }
+
+    fn assert_close(a: Point, b: Point) {
+        assert!(
+            (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    fn end(segment: &Segment) -> Point {
+        match *segment {
+            Segment::MoveTo(p) | Segment::LineTo(p) | Segment::QuadTo(_, p) | Segment::CubicTo(_, _, p) => p,
+            Segment::Close => panic!("close has no end point"),
+        }
+    }
+
+    #[test]
+    fn parses_each_command_family() {
+        use Segment::*;
+        let cases: &[(&str, &[Segment])] = &[
+            ("M1 2L3 4", &[MoveTo(p(1.0, 2.0)), LineTo(p(3.0, 4.0))]),
+            ("m1 2l3 4", &[MoveTo(p(1.0, 2.0)), LineTo(p(4.0, 6.0))]),
+            ("M1 2H5V7", &[MoveTo(p(1.0, 2.0)), LineTo(p(5.0, 2.0)), LineTo(p(5.0, 7.0))]),
+            ("M1 2h5v7", &[MoveTo(p(1.0, 2.0)), LineTo(p(6.0, 2.0)), LineTo(p(6.0, 9.0))]),
+            ("M0 0C1 2 3 4 5 6", &[MoveTo(p(0.0, 0.0)), CubicTo(p(1.0, 2.0), p(3.0, 4.0), p(5.0, 6.0))]),
+            ("M1 1c1 2 3 4 5 6", &[MoveTo(p(1.0, 1.0)), CubicTo(p(2.0, 3.0), p(4.0, 5.0), p(6.0, 7.0))]),
+            ("M0 0Q1 2 3 4", &[MoveTo(p(0.0, 0.0)), QuadTo(p(1.0, 2.0), p(3.0, 4.0))]),
+            ("M1 1q1 2 3 4", &[MoveTo(p(1.0, 1.0)), QuadTo(p(2.0, 3.0), p(4.0, 5.0))]),
+            // S reflects the previous cubic's second control point about the current point
+            (
+                "M0 0C0 1 2 1 2 0S4 -1 4 0",
+                &[
+                    MoveTo(p(0.0, 0.0)),
+                    CubicTo(p(0.0, 1.0), p(2.0, 1.0), p(2.0, 0.0)),
+                    CubicTo(p(2.0, -1.0), p(4.0, -1.0), p(4.0, 0.0)),
+                ],
+            ),
+            (
+                "M0 0c0 1 2 1 2 0s2 -1 2 0",
+                &[
+                    MoveTo(p(0.0, 0.0)),
+                    CubicTo(p(0.0, 1.0), p(2.0, 1.0), p(2.0, 0.0)),
+                    CubicTo(p(2.0, -1.0), p(4.0, -1.0), p(4.0, 0.0)),
+                ],
+            ),
+            // Without a preceding cubic the first control point is the current point
+            ("M1 1S2 2 3 3", &[MoveTo(p(1.0, 1.0)), CubicTo(p(1.0, 1.0), p(2.0, 2.0), p(3.0, 3.0))]),
+            // T reflects the previous quadratic's control point, also across several T commands
+            (
+                "M0 0Q1 1 2 0T4 0T6 0",
+                &[
+                    MoveTo(p(0.0, 0.0)),
+                    QuadTo(p(1.0, 1.0), p(2.0, 0.0)),
+                    QuadTo(p(3.0, -1.0), p(4.0, 0.0)),
+                    QuadTo(p(5.0, 1.0), p(6.0, 0.0)),
+                ],
+            ),
+            ("M0 0t2 0", &[MoveTo(p(0.0, 0.0)), QuadTo(p(0.0, 0.0), p(2.0, 0.0))]),
+            // A cubic doesn't feed T, nor a quadratic S
+            ("M0 0C1 1 1 1 2 0T4 0", &[MoveTo(p(0.0, 0.0)), CubicTo(p(1.0, 1.0), p(1.0, 1.0), p(2.0, 0.0)), QuadTo(p(2.0, 0.0), p(4.0, 0.0))]),
+            // Z returns to the subpath start, which later relative commands build on
+            ("M1 1l2 0zl0 2", &[MoveTo(p(1.0, 1.0)), LineTo(p(3.0, 1.0)), Close, LineTo(p(1.0, 3.0))]),
+            ("M1 1l2 0zm1 1", &[MoveTo(p(1.0, 1.0)), LineTo(p(3.0, 1.0)), Close, MoveTo(p(2.0, 2.0))]),
+        ];
+        for (d, expected) in cases {
+            assert_eq!(parse(d).unwrap(), *expected, "{}", d);
+        }
+    }
+
+    #[test]
+    fn repeats_implicit_commands() {
+        use Segment::*;
+        let cases: &[(&str, &[Segment])] = &[
+            // Extra pairs after a move are line-tos, relative after `m`
+            ("M0 0 1 1 2 0", &[MoveTo(p(0.0, 0.0)), LineTo(p(1.0, 1.0)), LineTo(p(2.0, 0.0))]),
+            ("m1 1 1 1 1 0", &[MoveTo(p(1.0, 1.0)), LineTo(p(2.0, 2.0)), LineTo(p(3.0, 2.0))]),
+            ("M0 0h1 2v3 4", &[MoveTo(p(0.0, 0.0)), LineTo(p(1.0, 0.0)), LineTo(p(3.0, 0.0)), LineTo(p(3.0, 3.0)), LineTo(p(3.0, 7.0))]),
+            ("M0 0l1 0,0 1", &[MoveTo(p(0.0, 0.0)), LineTo(p(1.0, 0.0)), LineTo(p(1.0, 1.0))]),
+            // Numbers run together when the sign or a second dot starts the next one
+            ("M0 0l1-1.5.5.5", &[MoveTo(p(0.0, 0.0)), LineTo(p(1.0, -1.5)), LineTo(p(1.5, -1.0))]),
+            ("M1e1 2E-1L-.5-.5", &[MoveTo(p(10.0, 0.2)), LineTo(p(-0.5, -0.5))]),
+        ];
+        for (d, expected) in cases {
+            assert_eq!(parse(d).unwrap(), *expected, "{}", d);
+        }
+    }
+
+    #[test]
+    fn parses_arcs() {
+        // Flags may be packed against each other and the following number
+        for d in ["M0 0a1 1 0 00 1 1", "M0 0a1 1 0 0 0 1 1", "M0 0a1,1,0,0,0,1,1", "M0 0A1 1 0 001 1"] {
+            let segments = parse(d).unwrap();
+            assert!(segments[1..].iter().all(|s| matches!(s, Segment::CubicTo(..))), "{}", d);
+            assert_close(end(segments.last().unwrap()), p(1.0, 1.0));
+        }
+
+        // A half circle is split into two quarter arcs on the unit circle
+        let segments = parse("M-1 0A1 1 0 0 1 1 0").unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_close(end(&segments[1]), p(0.0, -1.0));
+        let bbox = bounding_box(&segments).unwrap();
+        assert!((bbox.min_y + 1.0).abs() < 1e-9 && bbox.max_y.abs() < 1e-9);
+
+        // The sweep flag picks the side, the large-arc flag the longer way round
+        let lower = bounding_box(&parse("M-1 0A1 1 0 0 0 1 0").unwrap()).unwrap();
+        assert!((lower.max_y - 1.0).abs() < 1e-9);
+        let large = bounding_box(&parse("M0 -1A1 1 0 1 0 1 0").unwrap()).unwrap();
+        assert!((large.min_x + 1.0).abs() < 1e-9 && (large.max_y - 1.0).abs() < 1e-9);
+
+        // Radii too small to span the endpoints are scaled up
+        let scaled = bounding_box(&parse("M-1 0A0.5 0.5 0 0 1 1 0").unwrap()).unwrap();
+        assert!((scaled.min_y + 1.0).abs() < 1e-9);
+
+        // Zero radii draw a line, equal endpoints nothing
+        assert_eq!(parse("M0 0A0 1 0 0 1 2 2").unwrap()[1], Segment::LineTo(p(2.0, 2.0)));
+        assert_eq!(parse("M1 1A1 1 0 0 1 1 1").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        for d in ["1 2", "M0 0X1 1", "M0 0L1", "M0 0a1 1 0 2 0 1 1", "M0 0L1 #"] {
+            assert!(parse(d).is_err(), "{}", d);
+        }
+        assert!(path_bounding_box("").is_err());
+        assert!(parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn bounds_include_curve_extrema() {
+        let cubic = path_bounding_box("M0 0C0 10 10 10 10 0").unwrap();
+        assert_eq!((cubic.min_x, cubic.min_y, cubic.max_x, cubic.max_y), (0.0, 0.0, 10.0, 7.5));
+
+        let quad = path_bounding_box("M0 0Q5 10 10 0").unwrap();
+        assert_eq!((quad.min_y, quad.max_y), (0.0, 5.0));
+
+        // A lone move-to doesn't extend the box
+        let moved = path_bounding_box("M100 100M0 0L1 1").unwrap();
+        assert_eq!((moved.max_x, moved.max_y), (1.0, 1.0));
+    }
+
+    #[test]
+    fn bounds_of_fontawesome_icons() {
+        // Font Awesome Free 6 `minus` (solid), viewBox 0 0 448 512
+        let minus = path_bounding_box(
+            "M432 256c0 17.7-14.3 32-32 32L48 288c-17.7 0-32-14.3-32-32s14.3-32 32-32l352 0c17.7 0 32 14.3 32 32z",
+        )
+        .unwrap();
+        let rounded = |v: f64| (v * 1000.0).round() / 1000.0;
+        assert_eq!(
+            (rounded(minus.min_x), rounded(minus.min_y), rounded(minus.max_x), rounded(minus.max_y)),
+            (16.0, 224.0, 432.0, 288.0)
+        );
+
+        // Font Awesome Free 6 `circle` (solid), viewBox 0 0 512 512
+        let circle = path_bounding_box("M256 512A256 256 0 1 0 256 0a256 256 0 1 0 0 512z").unwrap();
+        assert_eq!(
+            (rounded(circle.min_x), rounded(circle.min_y), rounded(circle.max_x), rounded(circle.max_y)),
+            (0.0, 0.0, 512.0, 512.0)
+        );
+    }
+
+    #[test]
+    fn centroid_of_filled_area() {
+        let square = parse("M0 0H2V2H0Z").unwrap();
+        assert_close(area_centroid(&square).unwrap(), p(1.0, 1.0));
+
+        // Unclosed subpaths count as closed, in either winding
+        let triangle = parse("M0 0L3 0L0 3").unwrap();
+        assert_close(area_centroid(&triangle).unwrap(), p(1.0, 1.0));
+        let reversed = parse("M0 0L0 3L3 0").unwrap();
+        assert_close(area_centroid(&reversed).unwrap(), p(1.0, 1.0));
+
+        // A hole wound against the outline pulls the centroid away from it
+        let holed = parse("M0 0H4V2H0Z M0 0V2H1V0Z").unwrap();
+        assert_close(area_centroid(&holed).unwrap(), p(2.5, 1.0));
+
+        // Curves are flattened; a circle's centroid is its center
+        let circle = parse("M256 512A256 256 0 1 0 256 0a256 256 0 1 0 0 512z").unwrap();
+        let center = area_centroid(&circle).unwrap();
+        assert!((center.x - 256.0).abs() < 1e-6 && (center.y - 256.0).abs() < 1e-6);
+
+        assert_eq!(area_centroid(&parse("M0 0L1 1").unwrap()), None);
+        assert_eq!(area_centroid(&[]), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Parsing of individual SVG icon files and icon directories
+
+use crate::naming::snake_case_to_upper;
+use roxmltree::Document;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// An icon as read from an SVG file
+#[derive(Debug, Clone)]
+pub struct IconData {
+    /// Generated constant name, e.g. `HOUSE`
+    pub name: String,
+    /// Category taken from the parent directory, e.g. `solid` or `sharp_solid`
+    pub category: String,
+    pub view_box: String,
+    pub path_data: String,
+    /// The license comment embedded in the SVG
+    pub comment: String,
+    pub file_path: String,
+}
+
+/// What went wrong while reading an icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgErrorKind {
+    /// The file could not be read or parsed, or lacks a path
+    Parse,
+    /// The `<svg>` element has no viewBox attribute
+    MissingViewBox,
+    /// The license comment differs from the first icon of the directory
+    LicenseMismatch,
+    /// The SVG uses features a single-path icon can't represent
+    Unsupported,
+}
+
+#[derive(Debug, Clone)]
+pub struct SvgError {
+    pub kind: SvgErrorKind,
+    pub file: String,
+    pub message: String,
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.file, self.message)
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+/// Icons read from a directory, together with the problems encountered
+#[derive(Debug, Clone, Default)]
+pub struct IconSet {
+    pub icons: Vec<IconData>,
+    /// The license comment shared by the icons
+    pub license: String,
+    pub errors: Vec<SvgError>,
+}
+
+/// Reads a single SVG icon; its category is the name of the parent directory
+pub fn process_svg_file(path: &Path) -> Result<IconData, SvgError> {
+    let file = path.display().to_string();
+    let error = |kind: SvgErrorKind, message: String| SvgError {
+        kind,
+        file: file.clone(),
+        message,
+    };
+    let parse_failure = |message: String| error(SvgErrorKind::Parse, message);
+
+    let content = fs::read_to_string(path).map_err(|e| parse_failure(e.to_string()))?;
+
+    // Extract the comment (it's between <!-- and -->)
+    let comment = if let Some(start) = content.find("<!--") {
+        if let Some(end) = content.find("-->") {
+            content[start + 4..end].trim().to_string()
+        } else {
+            String::new()
+        }
+    } else {
+        String::new()
+    };
+
+    let doc = Document::parse(&content).map_err(|e| parse_failure(e.to_string()))?;
+
+    let root = doc.root_element();
+    let svg = root
+        .descendants()
+        .find(|n| n.tag_name().name() == "svg")
+        .ok_or_else(|| parse_failure("No SVG element found".to_string()))?;
+
+    let view_box = svg
+        .attribute("viewBox")
+        .ok_or_else(|| error(SvgErrorKind::MissingViewBox, "No viewBox attribute".to_string()))?
+        .to_string();
+
+    // Duotone icons hold a secondary and a primary layer; both become one single-color path
+    let path_data = svg
+        .descendants()
+        .filter(|n| n.tag_name().name() == "path")
+        .map(|n| {
+            n.attribute("d")
+                .ok_or_else(|| parse_failure("No d attribute in path".to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .join("");
+    if path_data.is_empty() {
+        return Err(parse_failure("No path element found".to_string()));
+    }
+
+    let file_name = path
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| parse_failure("Invalid file name".to_string()))?;
+
+    let category = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| parse_failure("Cannot determine category".to_string()))?;
+
+    let name = snake_case_to_upper(file_name);
+
+    Ok(IconData {
+        name,
+        // Pro styles like `sharp-solid` become modules like `sharp_solid`
+        category: category.replace('-', "_"),
+        view_box,
+        path_data,
+        comment,
+        file_path: file,
+    })
+}
+
+/// Reads every SVG below `dir`, sorted by category and name
+pub fn process_icons_directory(dir: &Path) -> IconSet {
+    let mut set = IconSet::default();
+    let mut license_comment = None;
+
+    for entry in WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "svg"))
+    {
+        match process_svg_file(entry.path()) {
+            Ok(icon_data) => {
+                // Check that all icons have the same license comment
+                if let Some(ref expected_comment) = license_comment {
+                    if icon_data.comment != *expected_comment {
+                        set.errors.push(SvgError {
+                            kind: SvgErrorKind::LicenseMismatch,
+                            file: icon_data.file_path.clone(),
+                            message: format!(
+                                "Different comment '{}' vs expected '{}'",
+                                icon_data.comment, expected_comment
+                            ),
+                        });
+                    }
+                } else {
+                    license_comment = Some(icon_data.comment.clone());
+                }
+
+                set.icons.push(icon_data);
+            }
+            Err(e) => set.errors.push(e),
+        }
+    }
+
+    set.icons
+        .sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+    set.license = license_comment.unwrap_or_default();
+    set
+}
@@ -0,0 +1,362 @@
+//! Finding the icons a Rust project uses
+//!
+//! Sources are parsed with syn, so references in comments and strings are
+//! ignored while imports such as `use icons::solid as fas;` are followed.
+//! `icon-processor export --scan` and the MCP server's usage audit share this
+//! scanner.
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::visit::{self, Visit};
+use syn::UseTree;
+
+/// Line and 1-based column of a token in a source file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An `icons::<category>::<NAME>` reference, however it was imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconReference {
+    pub category: String,
+    pub rust_name: String,
+    pub location: Location,
+}
+
+/// Icon references and `<FontAwesomeSvg>` elements of one file
+#[derive(Debug, Clone, Default)]
+pub struct FileUsage {
+    pub references: Vec<IconReference>,
+    pub components: Vec<Location>,
+}
+
+/// Collects icon references and `<FontAwesomeSvg>` elements from one file
+struct UsageVisitor<'a> {
+    categories: &'a BTreeSet<String>,
+    /// Local names of imported category modules, e.g. `fas` after `use icons::solid as fas;`
+    category_aliases: HashMap<String, String>,
+    usage: FileUsage,
+}
+
+impl<'a> UsageVisitor<'a> {
+    fn new(categories: &'a BTreeSet<String>) -> Self {
+        Self {
+            categories,
+            category_aliases: HashMap::new(),
+            usage: FileUsage::default(),
+        }
+    }
+
+    fn location(span: Span) -> Location {
+        let start = span.start();
+        Location {
+            line: start.line,
+            column: start.column + 1,
+        }
+    }
+
+    fn reference(&mut self, category: &str, name: &Ident) {
+        let rust_name = name.to_string();
+        if !is_constant_name(&rust_name) {
+            return;
+        }
+        self.usage.references.push(IconReference {
+            category: category.to_string(),
+            rust_name,
+            location: Self::location(name.span()),
+        });
+    }
+
+    /// Records `icons::<category>::<NAME>`, or `<alias>::<NAME>` for an imported category module
+    fn path(&mut self, segments: &[Ident]) {
+        match segments {
+            [.., icons, category, name] if icons == "icons" => {
+                self.reference(&category.to_string(), name);
+            }
+            [module, name] => {
+                if let Some(category) = self.category_aliases.get(&module.to_string()).cloned() {
+                    self.reference(&category, name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn use_tree(&mut self, tree: &UseTree, prefix: &mut Vec<String>) {
+        match tree {
+            UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.use_tree(&path.tree, prefix);
+                prefix.pop();
+            }
+            UseTree::Name(name) => self.use_name(prefix, &name.ident, &name.ident),
+            UseTree::Rename(rename) => self.use_name(prefix, &rename.ident, &rename.rename),
+            UseTree::Glob(_) => {
+                // `use icons::*` brings every category module into scope
+                if prefix.last().is_some_and(|last| last == "icons") {
+                    for category in self.categories {
+                        self.category_aliases.insert(category.clone(), category.clone());
+                    }
+                }
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.use_tree(tree, prefix);
+                }
+            }
+        }
+    }
+
+    fn use_name(&mut self, prefix: &[String], ident: &Ident, local: &Ident) {
+        match prefix {
+            [.., icons, category] if icons == "icons" => {
+                if ident == "self" {
+                    let local = if local == "self" { category.clone() } else { local.to_string() };
+                    self.category_aliases.insert(local, category.clone());
+                } else {
+                    self.reference(category, ident);
+                }
+            }
+            [.., icons] if icons == "icons" => {
+                self.category_aliases.insert(local.to_string(), ident.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    /// Macro bodies such as `html!` are not Rust syntax, so their tokens are scanned for paths and elements
+    fn tokens(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                TokenTree::Group(group) => self.tokens(group.stream()),
+                TokenTree::Punct(punct) if punct.as_char() == '<' => {
+                    if let Some(TokenTree::Ident(ident)) = tokens.get(i + 1) {
+                        if ident == "FontAwesomeSvg" {
+                            self.usage.components.push(Self::location(ident.span()));
+                        }
+                    }
+                }
+                TokenTree::Ident(ident) => {
+                    let mut segments = vec![ident.clone()];
+                    let mut next = i + 1;
+                    while let (Some(TokenTree::Punct(first)), Some(TokenTree::Punct(second)), Some(TokenTree::Ident(ident))) =
+                        (tokens.get(next), tokens.get(next + 1), tokens.get(next + 2))
+                    {
+                        if first.as_char() != ':' || second.as_char() != ':' {
+                            break;
+                        }
+                        segments.push(ident.clone());
+                        next += 3;
+                    }
+                    self.path(&segments);
+                    i = next;
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for UsageVisitor<'_> {
+    fn visit_item_use(&mut self, item: &'ast syn::ItemUse) {
+        self.use_tree(&item.tree, &mut Vec::new());
+    }
+
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        let segments: Vec<Ident> = path.segments.iter().map(|segment| segment.ident.clone()).collect();
+        self.path(&segments);
+        visit::visit_path(self, path);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.tokens(mac.tokens.clone());
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// Icon constants are SCREAMING_SNAKE_CASE, which tells them apart from functions and modules
+fn is_constant_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Icon references and `<FontAwesomeSvg>` elements of a parsed file.
+/// `categories` are the modules a `use icons::*` brings into scope.
+pub fn scan_file(file: &syn::File, categories: &BTreeSet<String>) -> FileUsage {
+    let mut visitor = UsageVisitor::new(categories);
+    visitor.visit_file(file);
+    visitor.usage
+}
+
+/// Parses Rust source and scans it with [`scan_file`]
+pub fn scan_source(source: &str, categories: &BTreeSet<String>) -> syn::Result<FileUsage> {
+    Ok(scan_file(&syn::parse_file(source)?, categories))
+}
+
+/// Every `.rs` file below a project directory in path order, skipping build
+/// output and hidden directories. Symbolic links are not followed, so a link
+/// cannot lead the scan out of the project or around a cycle.
+pub fn collect_rust_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_files(dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // Unlike `Path::is_dir`, the entry's own file type does not follow symbolic links
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+        if file_type.is_dir() {
+            let skip = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name == "target" || name.starts_with('.'));
+            if !skip {
+                collect_files(&path, files)?;
+            }
+        } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn categories() -> BTreeSet<String> {
+        ["solid", "regular", "brands"].into_iter().map(String::from).collect()
+    }
+
+    /// `category::NAME` of every reference in the source
+    fn references(source: &str) -> Vec<String> {
+        scan_source(source, &categories())
+            .unwrap()
+            .references
+            .into_iter()
+            .map(|reference| format!("{}::{}", reference.category, reference.rust_name))
+            .collect()
+    }
+
+    #[test]
+    fn finds_paths_and_grouped_imports() {
+        let source = r#"
+            use yew_shortcuts::fontawesome::icons::solid::{HOUSE, GEAR};
+            const ICON: &Icon = icons::brands::GITHUB;
+        "#;
+        assert_eq!(references(source), ["solid::HOUSE", "solid::GEAR", "brands::GITHUB"]);
+    }
+
+    #[test]
+    fn ignores_comments_strings_and_other_modules() {
+        let source = r#"
+            // icons::solid::HOUSE
+            const NAME: &str = "icons::solid::GEAR";
+            use app_icons::solid::TRASH;
+            fn f() { icons::solid::helper(); }
+        "#;
+        assert!(references(source).is_empty());
+    }
+
+    #[test]
+    fn follows_renamed_category_imports() {
+        let source = r#"
+            use yew_shortcuts::fontawesome::icons::solid as fas;
+            use yew_shortcuts::fontawesome::icons::{regular::{self as far}, brands};
+            const A: &Icon = fas::HOUSE;
+            const B: &Icon = far::HEART;
+            const C: &Icon = brands::GITHUB;
+            const D: &Icon = solid::GEAR;
+        "#;
+        assert_eq!(references(source), ["solid::HOUSE", "regular::HEART", "brands::GITHUB"]);
+    }
+
+    #[test]
+    fn glob_import_brings_every_category_into_scope() {
+        let source = r#"
+            use yew_shortcuts::fontawesome::icons::*;
+            const A: &Icon = solid::HOUSE;
+            const B: &Icon = brands::GITHUB;
+            const C: &Icon = duotone::HOUSE;
+        "#;
+        assert_eq!(references(source), ["solid::HOUSE", "brands::GITHUB"]);
+    }
+
+    #[test]
+    fn finds_paths_and_components_inside_html() {
+        let source = r#"
+            use yew_shortcuts::fontawesome::icons::solid as fas;
+            fn view() -> Html {
+                html! {
+                    <div class="toolbar">
+                        <FontAwesomeSvg icon={icons::solid::HOUSE} />
+                        <FontAwesomeSvg icon={fas::GEAR} full={true} />
+                        { "icons::solid::TRASH" }
+                    </div>
+                }
+            }
+        "#;
+        let usage = scan_source(source, &categories()).unwrap();
+        let found: Vec<String> = usage
+            .references
+            .iter()
+            .map(|reference| format!("{}::{}", reference.category, reference.rust_name))
+            .collect();
+        assert_eq!(found, ["solid::HOUSE", "solid::GEAR"]);
+        assert_eq!(usage.components.len(), 2);
+        assert_eq!(usage.components[0].line, 6);
+    }
+
+    #[test]
+    fn locations_are_one_based() {
+        let usage = scan_source("fn f() {\n    let _ = icons::solid::HOUSE;\n}\n", &categories()).unwrap();
+        assert_eq!(usage.references[0].location, Location { line: 2, column: 27 });
+    }
+
+    #[test]
+    fn collects_rust_files_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["src/main.rs", "src/app/mod.rs", "target/debug/build.rs", ".git/hook.rs", "README.md"] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let files: Vec<PathBuf> = collect_rust_files(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|path| path.strip_prefix(dir.path()).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(files, [PathBuf::from("src/app/mod.rs"), PathBuf::from("src/main.rs")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_follow_symbolic_links() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        fs::write(outside.path().join("secret.rs"), "").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "").unwrap();
+        // A link out of the project, a cycle back to its root and a linked file
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("src/outside")).unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("src/cycle")).unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret.rs"), dir.path().join("src/secret.rs")).unwrap();
+
+        let files = collect_rust_files(dir.path()).unwrap();
+        assert_eq!(files, [dir.path().join("src/main.rs")]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Parsed SVG viewBoxes
+//!
+//! Third-party SVGs often use fractional or offset viewBoxes, so every
+//! component is kept as a float instead of assuming `0 0 <int> <int>`.
+
+use crate::full::format_number;
+use std::fmt;
+
+/// The four numbers of an SVG viewBox attribute
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ViewBox {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ViewBox {
+    /// Parses `"min-x min-y width height"`, separated by whitespace and/or
+    /// commas. Returns `None` unless there are exactly four finite numbers
+    /// and the size is positive.
+    pub fn parse(view_box: &str) -> Option<Self> {
+        let numbers: Vec<f32> = view_box
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse().ok().filter(|number: &f32| number.is_finite()))
+            .collect::<Option<_>>()?;
+
+        match numbers[..] {
+            [min_x, min_y, width, height] if width > 0.0 && height > 0.0 => Some(Self {
+                min_x,
+                min_y,
+                width,
+                height,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Width divided by height
+    pub fn aspect_ratio(&self) -> f32 {
+        self.width / self.height
+    }
+
+    /// Rendered width for a given height, keeping the aspect ratio
+    pub fn width_for_height(&self, height: f32) -> f32 {
+        height * self.aspect_ratio()
+    }
+
+    /// Rendered height for a given width, keeping the aspect ratio
+    pub fn height_for_width(&self, width: f32) -> f32 {
+        width / self.aspect_ratio()
+    }
+}
+
+/// Formats as an SVG viewBox attribute, without trailing zeros
+impl fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            format_number(self.min_x as f64),
+            format_number(self.min_y as f64),
+            format_number(self.width as f64),
+            format_number(self.height as f64)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_whitespace_and_comma_separated_numbers() {
+        let expected = ViewBox { min_x: -1.5, min_y: 0.0, width: 448.0, height: 512.25 };
+        assert_eq!(ViewBox::parse("-1.5 0 448 512.25"), Some(expected));
+        assert_eq!(ViewBox::parse(" -1.5,0, 448\t512.25 "), Some(expected));
+        assert_eq!(expected.to_string(), "-1.5 0 448 512.25");
+    }
+
+    #[test]
+    fn rejects_malformed_view_boxes() {
+        for view_box in ["", "0 0 512", "0 0 512 512 1", "0 0 a 512", "0 0 0 512", "0 0 512 -1"] {
+            assert_eq!(ViewBox::parse(view_box), None, "{:?}", view_box);
+        }
+    }
+
+    #[test]
+    fn rejects_non_finite_numbers() {
+        for view_box in ["0 0 inf 512", "0 0 512 NaN", "-inf 0 512 512", "0 nan 512 512", "0 0 1e39 512"] {
+            assert_eq!(ViewBox::parse(view_box), None, "{:?}", view_box);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! Comparison of two icon set versions.
+//!
+//! `icon-processor diff <old> <new>` accepts either SVG directories (laid out
+//! like `svgs-7/<category>/<name>.svg`) or previously generated
+//! `fontawesome.rs` modules (split per category or in a single file), and reports which icons were added, removed,
+//! renamed or had their geometry changed. Renames can be recorded in the
+//! `aliases.json` table that generation turns into deprecated constants.
+
+use crate::legacy_module::read_module;
+use crate::validation::Report;
+use crate::process_icons_directory;
+use icon_model::aliases::{aliases_to_json, parse_aliases, AliasTable};
+use icon_model::manifest::geometry_hash;
+use icon_model::naming::FREE_CATEGORIES;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// An icon as seen by the diff: its identity and a hash of its geometry
+#[derive(Debug, Clone)]
+struct DiffIcon {
+    category: String,
+    name: String,
+    geometry_hash: u64,
+}
+
+/// An icon that disappeared and reappeared under a new name with identical geometry
+#[derive(Debug, Clone)]
+struct Rename {
+    category: String,
+    old_name: String,
+    new_name: String,
+}
+
+#[derive(Debug, Default)]
+struct IconSetDiff {
+    added: Vec<DiffIcon>,
+    removed: Vec<DiffIcon>,
+    renamed: Vec<Rename>,
+    changed: Vec<DiffIcon>,
+    unchanged: usize,
+}
+
+/// Loads icons from an SVG directory or a generated Rust module
+fn load_icon_set(path: &Path) -> Result<Vec<DiffIcon>, Box<dyn std::error::Error>> {
+    if path.is_dir() {
+        let mut report = Report::default();
+        let (icons, _) = process_icons_directory(path, &mut report);
+        for issue in &report.issues {
+            eprintln!("Warning: {}", issue);
+        }
+        Ok(icons
+            .iter()
+            .map(|icon| diff_icon(&icon.category, &icon.name, &icon.path_data))
+            .collect())
+    } else if path.extension().is_some_and(|ext| ext == "rs") {
+        Ok(read_module(path)?
+            .icons
+            .iter()
+            .map(|icon| diff_icon(&icon.category, &icon.rust_name, &icon.path_data))
+            .collect())
+    } else {
+        Err(format!(
+            "{} is neither an icon directory nor a generated .rs module",
+            path.display()
+        )
+        .into())
+    }
+}
+
+fn diff_icon(category: &str, name: &str, path_data: &str) -> DiffIcon {
+    DiffIcon {
+        category: category.to_string(),
+        name: name.to_string(),
+        geometry_hash: geometry_hash(path_data),
+    }
+}
+
+fn diff_icon_sets(old: &[DiffIcon], new: &[DiffIcon]) -> IconSetDiff {
+    let old_by_key: BTreeMap<(&str, &str), &DiffIcon> = old
+        .iter()
+        .map(|icon| ((icon.category.as_str(), icon.name.as_str()), icon))
+        .collect();
+    let new_by_key: BTreeMap<(&str, &str), &DiffIcon> = new
+        .iter()
+        .map(|icon| ((icon.category.as_str(), icon.name.as_str()), icon))
+        .collect();
+
+    let mut diff = IconSetDiff::default();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    for (key, old_icon) in &old_by_key {
+        match new_by_key.get(key) {
+            Some(new_icon) if new_icon.geometry_hash == old_icon.geometry_hash => {
+                diff.unchanged += 1;
+            }
+            Some(new_icon) => diff.changed.push((*new_icon).clone()),
+            None => removed.push(*old_icon),
+        }
+    }
+    for (key, new_icon) in &new_by_key {
+        if !old_by_key.contains_key(key) {
+            added.push(*new_icon);
+        }
+    }
+
+    // Match removed icons to added icons with identical geometry in the same category
+    let mut claimed = BTreeSet::new();
+    for old_icon in removed {
+        let candidate = added.iter().position(|new_icon| {
+            !claimed.contains(&(new_icon.category.as_str(), new_icon.name.as_str()))
+                && new_icon.category == old_icon.category
+                && new_icon.geometry_hash == old_icon.geometry_hash
+        });
+
+        match candidate {
+            Some(idx) => {
+                let new_icon = added[idx];
+                claimed.insert((new_icon.category.as_str(), new_icon.name.as_str()));
+                diff.renamed.push(Rename {
+                    category: old_icon.category.clone(),
+                    old_name: old_icon.name.clone(),
+                    new_name: new_icon.name.clone(),
+                });
+            }
+            None => diff.removed.push(old_icon.clone()),
+        }
+    }
+
+    diff.added = added
+        .into_iter()
+        .filter(|icon| !claimed.contains(&(icon.category.as_str(), icon.name.as_str())))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+fn generate_changelog(diff: &IconSetDiff, old_label: &str, new_label: &str) -> String {
+    let mut output = String::new();
+
+    output.push_str("# Icon Changelog\n\n");
+    output.push_str(&format!("Comparing `{}` to `{}`.\n\n", old_label, new_label));
+    output.push_str(&format!(
+        "{} added, {} removed, {} renamed, {} changed geometry, {} unchanged\n\n",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.renamed.len(),
+        diff.changed.len(),
+        diff.unchanged
+    ));
+
+    if !diff.renamed.is_empty() {
+        output.push_str("## Renamed\n\n");
+        output.push_str("Deprecated aliases keep the old names compiling.\n\n");
+        for rename in &diff.renamed {
+            output.push_str(&format!(
+                "- `icons::{}::{}` → `icons::{}::{}`\n",
+                rename.category, rename.old_name, rename.category, rename.new_name
+            ));
+        }
+        output.push('\n');
+    }
+
+    let sections = [
+        ("Removed", &diff.removed),
+        ("Added", &diff.added),
+        ("Changed geometry", &diff.changed),
+    ];
+    for (title, icons) in sections {
+        if icons.is_empty() {
+            continue;
+        }
+        output.push_str(&format!("## {}\n\n", title));
+        for icon in icons {
+            output.push_str(&format!("- `icons::{}::{}`\n", icon.category, icon.name));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Adds this diff's renames to the aliases of earlier upgrades and points
+/// every alias at the icon's final name, so `A → B` followed by `B → C`
+/// yields `A → C` and `B → C`.
+///
+/// Aliases whose name is a real icon again, or whose target no longer exists
+/// in `new`, would not compile and are returned separately. Pro styles are
+/// generated into `fontawesome/pro` without aliases, so their renames are
+/// left out.
+fn merge_aliases(
+    existing: &AliasTable,
+    diff: &IconSetDiff,
+    new: &[DiffIcon],
+) -> (AliasTable, Vec<(String, String)>) {
+    let mut table = existing.clone();
+    for rename in diff.renamed.iter().filter(|rename| is_free(&rename.category)) {
+        table.insert(
+            (rename.category.clone(), rename.old_name.clone()),
+            rename.new_name.clone(),
+        );
+    }
+
+    let current: BTreeSet<(&str, &str)> = new
+        .iter()
+        .map(|icon| (icon.category.as_str(), icon.name.as_str()))
+        .collect();
+
+    let mut merged = AliasTable::new();
+    let mut dropped = Vec::new();
+    for ((category, old_name), target) in &table {
+        // Follow the chain to its end; `seen` stops on cycles
+        let mut target = target;
+        let mut seen = BTreeSet::from([old_name]);
+        while let Some(next) = table.get(&(category.clone(), target.clone())) {
+            if !seen.insert(target) {
+                break;
+            }
+            target = next;
+        }
+
+        let key = (category.clone(), old_name.clone());
+        if !is_free(category) || current.contains(&(category.as_str(), old_name.as_str()))
+            || !current.contains(&(category.as_str(), target.as_str()))
+        {
+            dropped.push(key);
+        } else {
+            merged.insert(key, target.clone());
+        }
+    }
+
+    (merged, dropped)
+}
+
+fn is_free(category: &str) -> bool {
+    FREE_CATEGORIES.contains(&category)
+}
+
+fn print_usage() {
+    eprintln!("Usage: icon-processor diff <old> <new> [--changelog <file>] [--aliases <file>]");
+    eprintln!();
+    eprintln!("  <old>, <new>         SVG icon directories or generated fontawesome.rs modules");
+    eprintln!("  --changelog <file>   Write the markdown changelog to a file instead of stdout");
+    eprintln!("  --aliases <file>     Add renamed icons to an aliases.json table, which generation turns into deprecated constants");
+}
+
+/// Entry point for `icon-processor diff`
+pub fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut paths = Vec::new();
+    let mut changelog_path = None;
+    let mut aliases_path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--changelog" => changelog_path = Some(args.next().ok_or("--changelog needs a path")?),
+            "--aliases" => aliases_path = Some(args.next().ok_or("--aliases needs a path")?),
+            "-h" | "--help" => {
+                print_usage();
+                return Ok(());
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let [old_path, new_path] = paths.as_slice() else {
+        print_usage();
+        return Err("diff takes exactly two icon sets".into());
+    };
+
+    let old_icons = load_icon_set(Path::new(old_path))?;
+    let new_icons = load_icon_set(Path::new(new_path))?;
+    let diff = diff_icon_sets(&old_icons, &new_icons);
+
+    eprintln!(
+        "{} added, {} removed, {} renamed, {} changed geometry",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.renamed.len(),
+        diff.changed.len()
+    );
+
+    let changelog = generate_changelog(&diff, old_path, new_path);
+    match changelog_path {
+        Some(path) => {
+            fs::write(path, changelog)?;
+            eprintln!("Wrote changelog to {}", path);
+        }
+        None => print!("{}", changelog),
+    }
+
+    if let Some(path) = aliases_path {
+        // Keep the aliases of earlier upgrades
+        let existing = match fs::read_to_string(path) {
+            Ok(json) => parse_aliases(&json).map_err(|e| format!("could not parse {}: {}", path, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => AliasTable::new(),
+            Err(e) => return Err(format!("could not read {}: {}", path, e).into()),
+        };
+        let (aliases, dropped) = merge_aliases(&existing, &diff, &new_icons);
+        let pro_renames = diff.renamed.iter().filter(|rename| !is_free(&rename.category)).count();
+        if pro_renames > 0 {
+            eprintln!("Skipping {} renames in Pro styles, which have no aliases module", pro_renames);
+        }
+        for (category, name) in dropped {
+            eprintln!("Warning: dropping alias icons::{}::{}, which no longer resolves", category, name);
+        }
+        fs::write(path, aliases_to_json(&aliases))?;
+        eprintln!("Wrote {} aliases to {}", aliases.len(), path);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(category: &str, name: &str, path_data: &str) -> DiffIcon {
+        diff_icon(category, name, path_data)
+    }
+
+    fn alias(category: &str, old_name: &str, new_name: &str) -> ((String, String), String) {
+        ((category.to_string(), old_name.to_string()), new_name.to_string())
+    }
+
+    fn names(icons: &[DiffIcon]) -> Vec<&str> {
+        icons.iter().map(|icon| icon.name.as_str()).collect()
+    }
+
+    #[test]
+    fn detects_renames_by_geometry() {
+        let old = [
+            icon("solid", "HOME", "M0 0h10v10z"),
+            icon("solid", "GEAR", "M0 0h20v20z"),
+            icon("solid", "TRASH", "M0 0h30v30z"),
+            icon("regular", "HOME", "M0 0h10v10z"),
+        ];
+        let new = [
+            icon("solid", "HOUSE", "M0 0h10v10z"),
+            icon("solid", "GEAR", "M0 0h21v21z"),
+            icon("solid", "PLUS", "M0 0h40v40z"),
+            // Same geometry as the old solid HOME, but in another category
+            icon("brands", "HOME_BRAND", "M0 0h10v10z"),
+        ];
+
+        let diff = diff_icon_sets(&old, &new);
+        let renames: Vec<(&str, &str, &str)> = diff
+            .renamed
+            .iter()
+            .map(|rename| (rename.category.as_str(), rename.old_name.as_str(), rename.new_name.as_str()))
+            .collect();
+        assert_eq!(renames, [("solid", "HOME", "HOUSE")]);
+        assert_eq!(names(&diff.changed), ["GEAR"]);
+        assert_eq!(names(&diff.removed), ["HOME", "TRASH"]);
+        assert_eq!(names(&diff.added), ["HOME_BRAND", "PLUS"]);
+        assert_eq!(diff.unchanged, 0);
+    }
+
+    #[test]
+    fn claims_each_new_icon_once() {
+        let old = [icon("solid", "A", "M0 0h1"), icon("solid", "B", "M0 0h1")];
+        let new = [icon("solid", "C", "M0 0h1")];
+
+        let diff = diff_icon_sets(&old, &new);
+        assert_eq!(diff.renamed.len(), 1);
+        assert_eq!(diff.renamed[0].old_name, "A");
+        assert_eq!(names(&diff.removed), ["B"]);
+        assert!(diff.added.is_empty());
+    }
+
+    #[test]
+    fn resolves_chained_renames() {
+        // 7.0 → 7.1 renamed HOME to HOUSE, 7.1 → 7.2 renames HOUSE to HOUSE_CHIMNEY
+        let existing = AliasTable::from([alias("solid", "HOME", "HOUSE")]);
+        let old = [icon("solid", "HOUSE", "M0 0h10v10z"), icon("solid", "GEAR", "M0 0h20v20z")];
+        let new = [icon("solid", "HOUSE_CHIMNEY", "M0 0h10v10z"), icon("solid", "GEAR", "M0 0h20v20z")];
+
+        let diff = diff_icon_sets(&old, &new);
+        let (aliases, dropped) = merge_aliases(&existing, &diff, &new);
+        assert_eq!(
+            aliases,
+            AliasTable::from([
+                alias("solid", "HOME", "HOUSE_CHIMNEY"),
+                alias("solid", "HOUSE", "HOUSE_CHIMNEY"),
+            ])
+        );
+        assert!(dropped.is_empty());
+    }
+
+    #[test]
+    fn keeps_aliases_from_earlier_upgrades() {
+        let existing = AliasTable::from([alias("regular", "FILE_ALT", "FILE_LINES")]);
+        let old = [icon("solid", "HOME", "M0 0h10v10z"), icon("regular", "FILE_LINES", "M0 0h5")];
+        let new = [icon("solid", "HOUSE", "M0 0h10v10z"), icon("regular", "FILE_LINES", "M0 0h5")];
+
+        let diff = diff_icon_sets(&old, &new);
+        let (aliases, _) = merge_aliases(&existing, &diff, &new);
+        assert_eq!(
+            aliases,
+            AliasTable::from([
+                alias("regular", "FILE_ALT", "FILE_LINES"),
+                alias("solid", "HOME", "HOUSE"),
+            ])
+        );
+    }
+
+    #[test]
+    fn drops_aliases_that_no_longer_resolve() {
+        let existing = AliasTable::from([
+            // The old name is a real icon again
+            alias("solid", "HOME", "HOUSE"),
+            // The target was removed
+            alias("solid", "COG", "GEAR"),
+            // A cycle never reaches a current icon
+            alias("solid", "A", "B"),
+            alias("solid", "B", "A"),
+        ]);
+        let new = [icon("solid", "HOME", "M0 0h10v10z"), icon("solid", "HOUSE", "M0 0h10v10z")];
+
+        let (aliases, dropped) = merge_aliases(&existing, &IconSetDiff::default(), &new);
+        assert!(aliases.is_empty());
+        assert_eq!(dropped.len(), 4);
+    }
+
+    #[test]
+    fn leaves_pro_renames_out() {
+        let existing = AliasTable::from([alias("sharp_solid", "COG", "GEAR")]);
+        let old = [icon("sharp_solid", "HOME", "M0 0h10v10z"), icon("solid", "HOME", "M0 0h10v10z")];
+        let new = [
+            icon("sharp_solid", "HOUSE", "M0 0h10v10z"),
+            icon("sharp_solid", "GEAR", "M0 0h20v20z"),
+            icon("solid", "HOUSE", "M0 0h10v10z"),
+        ];
+
+        let diff = diff_icon_sets(&old, &new);
+        assert_eq!(diff.renamed.len(), 2);
+        let (aliases, dropped) = merge_aliases(&existing, &diff, &new);
+        assert_eq!(aliases, AliasTable::from([alias("solid", "HOME", "HOUSE")]));
+        assert_eq!(dropped, [("sharp_solid".to_string(), "COG".to_string())]);
+    }
+}
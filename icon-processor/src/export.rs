@@ -0,0 +1,461 @@
+//! Static exports of an icon subset for pages that are not built with Yew.
+//!
+//! `icon-processor export` reads the generated manifest and writes, for the
+//! selected icons, a `sprite.svg` of `<symbol>`s, one standalone SVG per icon,
+//! a CSS class map and a JSON map. With `--font` it also builds a WOFF2 icon
+//! font through FontForge.
+
+use icon_model::manifest::{Manifest, ManifestIcon};
+use icon_model::naming;
+use icon_model::usage;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// First private-use codepoint handed out to font glyphs
+const FIRST_CODEPOINT: u32 = 0xE000;
+
+/// Font family name used in the CSS and the WOFF2 file
+const FONT_FAMILY: &str = "yew-shortcuts-icons";
+
+/// Class every exported icon carries next to its own, named so it can't clash
+/// with the `.fa` class of Font Awesome's own stylesheet
+const ICON_CLASS: &str = "yew-shortcuts-icon";
+
+/// CSS variable holding an icon's mask image
+const MASK_VARIABLE: &str = "--yew-shortcuts-icon-mask";
+
+/// Entry of `icons.json`
+#[derive(Debug, Serialize)]
+struct ExportEntry<'a> {
+    category: &'a str,
+    name: &'a str,
+    rust_name: &'a str,
+    view_box: &'a str,
+    width: f32,
+    height: f32,
+    aspect_ratio: f32,
+    /// Per-icon SVG, relative to the output directory
+    file: String,
+    /// Fragment of the symbol in `sprite.svg`
+    symbol: String,
+    /// CSS class selecting the icon
+    class: String,
+    /// Glyph codepoint in the icon font, when one was built
+    #[serde(skip_serializing_if = "Option::is_none")]
+    codepoint: Option<String>,
+}
+
+/// Id shared by the sprite symbol and the CSS class, matching the ids
+/// `IconSpriteProvider` uses in the Yew crate
+fn symbol_id(icon: &ManifestIcon) -> String {
+    naming::symbol_id(&icon.category, &icon.name)
+}
+
+/// Relative path of an icon's standalone SVG
+fn icon_file(icon: &ManifestIcon) -> String {
+    format!("icons/{}/{}.svg", icon.category, icon.name)
+}
+
+/// Resolves `solid/house`, `solid::HOUSE` or `icons::solid::HOUSE` to a manifest icon
+pub(crate) fn resolve<'a>(manifest: &'a Manifest, spec: &str) -> Option<&'a ManifestIcon> {
+    let spec = spec.trim().trim_start_matches("icons::");
+    let (category, name) = spec.split_once('/').or_else(|| spec.split_once("::"))?;
+    manifest
+        .icons
+        .iter()
+        .find(|icon| icon.category == category && (icon.name == name || icon.rust_name == name))
+}
+
+/// Collects the `(category, rust_name)` of every icon a crate's sources
+/// reference. Files that don't parse are skipped with a warning.
+pub(crate) fn scan_crate(
+    dir: &Path,
+    categories: &BTreeSet<String>,
+    found: &mut BTreeSet<(String, String)>,
+) -> std::io::Result<()> {
+    for path in usage::collect_rust_files(dir)? {
+        match usage::scan_source(&fs::read_to_string(&path)?, categories) {
+            Ok(file_usage) => found.extend(
+                file_usage
+                    .references
+                    .into_iter()
+                    .map(|reference| (reference.category, reference.rust_name)),
+            ),
+            Err(e) => eprintln!("Skipping {}: {}", path.display(), e),
+        }
+    }
+    Ok(())
+}
+
+/// Standalone SVG document for one icon
+fn icon_svg(icon: &ManifestIcon, license: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{}\"><!--{}--><path d=\"{}\"/></svg>\n",
+        icon.view_box, license, icon.path_data
+    )
+}
+
+fn generate_sprite(icons: &[&ManifestIcon], license: &str) -> String {
+    let mut output = String::new();
+
+    output.push_str("<svg xmlns=\"http://www.w3.org/2000/svg\" style=\"display:none\">\n");
+    output.push_str(&format!("<!--{}-->\n", license));
+    for icon in icons {
+        output.push_str(&format!(
+            "<symbol id=\"{}\" viewBox=\"{}\"><path fill=\"currentColor\" d=\"{}\"/></symbol>\n",
+            symbol_id(icon),
+            icon.view_box,
+            icon.path_data
+        ));
+    }
+    output.push_str("</svg>\n");
+
+    output
+}
+
+/// CSS class map. Without a font every class masks `currentColor` with the
+/// icon's SVG file; with a font the classes select glyphs instead.
+fn generate_css(
+    icons: &[&ManifestIcon],
+    codepoints: Option<&BTreeMap<String, u32>>,
+    license: &str,
+) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!("/* {} */\n\n", license));
+
+    match codepoints {
+        Some(codepoints) => {
+            output.push_str("@font-face {\n");
+            output.push_str(&format!("  font-family: \"{}\";\n", FONT_FAMILY));
+            output.push_str(&format!(
+                "  src: url(\"{}.woff2\") format(\"woff2\");\n",
+                FONT_FAMILY
+            ));
+            output.push_str("  font-display: block;\n");
+            output.push_str("}\n\n");
+            output.push_str(&format!(".{} {{\n", ICON_CLASS));
+            output.push_str(&format!("  font-family: \"{}\";\n", FONT_FAMILY));
+            output.push_str("  font-style: normal;\n");
+            output.push_str("  font-weight: normal;\n");
+            output.push_str("  line-height: 1;\n");
+            output.push_str("  -webkit-font-smoothing: antialiased;\n");
+            output.push_str("}\n\n");
+            for icon in icons {
+                let id = symbol_id(icon);
+                output.push_str(&format!(
+                    ".{}::before {{ content: \"\\{:x}\"; }}\n",
+                    id, codepoints[&id]
+                ));
+            }
+        }
+        None => {
+            output.push_str(&format!(".{} {{\n", ICON_CLASS));
+            output.push_str("  display: inline-block;\n");
+            output.push_str("  height: 1em;\n");
+            output.push_str("  background-color: currentColor;\n");
+            output.push_str(&format!("  -webkit-mask: var({}) no-repeat center / contain;\n", MASK_VARIABLE));
+            output.push_str(&format!("  mask: var({}) no-repeat center / contain;\n", MASK_VARIABLE));
+            output.push_str("}\n\n");
+            for icon in icons {
+                output.push_str(&format!(
+                    ".{} {{ {}: url(\"{}\"); width: {}em; }}\n",
+                    symbol_id(icon),
+                    MASK_VARIABLE,
+                    icon_file(icon),
+                    icon_model::full::format_number(icon.aspect_ratio as f64)
+                ));
+            }
+        }
+    }
+
+    output
+}
+
+/// FontForge script importing every per-icon SVG into a private-use glyph.
+/// Glyphs share one em box, so icons keep their proportions relative to each other.
+fn generate_font_script(
+    icons: &[&ManifestIcon],
+    codepoints: &BTreeMap<String, u32>,
+    out_dir: &Path,
+) -> String {
+    let mut output = String::new();
+
+    output.push_str("import fontforge\n\n");
+    output.push_str("font = fontforge.font()\n");
+    output.push_str(&format!(
+        "font.familyname = font.fontname = font.fullname = \"{}\"\n",
+        FONT_FAMILY
+    ));
+    output.push_str("font.em = 512\n");
+    output.push_str("font.ascent = 448\n");
+    output.push_str("font.descent = 64\n\n");
+    for icon in icons {
+        let id = symbol_id(icon);
+        output.push_str(&format!(
+            "glyph = font.createChar({}, \"{}\")\n",
+            codepoints[&id], id
+        ));
+        output.push_str(&format!(
+            "glyph.importOutlines({:?})\n",
+            out_dir.join(icon_file(icon)).display().to_string()
+        ));
+        output.push_str("glyph.left_side_bearing = glyph.right_side_bearing = 0\n\n");
+    }
+    output.push_str(&format!(
+        "font.generate({:?})\n",
+        out_dir
+            .join(format!("{}.woff2", FONT_FAMILY))
+            .display()
+            .to_string()
+    ));
+
+    output
+}
+
+fn build_font(
+    icons: &[&ManifestIcon],
+    codepoints: &BTreeMap<String, u32>,
+    out_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let script_path = out_dir.join("build-font.py");
+    fs::write(
+        &script_path,
+        generate_font_script(icons, codepoints, out_dir),
+    )?;
+
+    let status = Command::new("fontforge")
+        .args(["-lang=py", "-script"])
+        .arg(&script_path)
+        .status()
+        .map_err(|e| {
+            format!(
+                "could not run fontforge ({}); install it to build the WOFF2 font",
+                e
+            )
+        })?;
+    if !status.success() {
+        return Err(format!("fontforge failed with {}", status).into());
+    }
+
+    fs::remove_file(&script_path)?;
+    Ok(())
+}
+
+fn print_usage() {
+    eprintln!("Usage: icon-processor export [<icon>...] [--scan <crate>] [--out <dir>] [--manifest <file>] [--font]");
+    eprintln!();
+    eprintln!(
+        "  <icon>             Icon to export: solid/house, solid::HOUSE or icons::solid::HOUSE"
+    );
+    eprintln!("  --scan <crate>     Also export every icons::<category>::<NAME> used in a crate's sources");
+    eprintln!("  --out <dir>        Output directory (default: ../icon-export)");
+    eprintln!("  --manifest <file>  Icon manifest to read (default: ../yew-shortcuts/src/fontawesome.json)");
+    eprintln!("  --font             Also build a WOFF2 icon font with FontForge and map classes to glyphs");
+}
+
+/// Entry point for `icon-processor export`
+pub fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut specs = Vec::new();
+    let mut scan_dirs = Vec::new();
+    let mut out_dir = PathBuf::from("../icon-export");
+    let mut manifest_path = PathBuf::from("../yew-shortcuts/src/fontawesome.json");
+    let mut font = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scan" => scan_dirs.push(args.next().ok_or("--scan needs a crate directory")?),
+            "--out" => out_dir = args.next().ok_or("--out needs a directory")?.into(),
+            "--manifest" => manifest_path = args.next().ok_or("--manifest needs a path")?.into(),
+            "--font" => font = true,
+            "-h" | "--help" => {
+                print_usage();
+                return Ok(());
+            }
+            _ => specs.push(arg.clone()),
+        }
+    }
+
+    let manifest = Manifest::read(&manifest_path)?;
+
+    let categories: BTreeSet<String> = manifest.icons.iter().map(|icon| icon.category.clone()).collect();
+    let mut scanned = BTreeSet::new();
+    for dir in &scan_dirs {
+        scan_crate(Path::new(dir), &categories, &mut scanned)?;
+    }
+    if specs.is_empty() && scanned.is_empty() {
+        print_usage();
+        return Err("no icons to export".into());
+    }
+
+    let mut selected: BTreeMap<String, &ManifestIcon> = BTreeMap::new();
+    let mut unknown = Vec::new();
+    for spec in &specs {
+        match resolve(&manifest, spec) {
+            Some(icon) => {
+                selected.insert(symbol_id(icon), icon);
+            }
+            None => unknown.push(spec.as_str()),
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(format!("unknown icons: {}", unknown.join(", ")).into());
+    }
+    // Scanned references may point into project icon modules, which are not in the manifest
+    for (category, name) in &scanned {
+        match resolve(&manifest, &format!("{}::{}", category, name)) {
+            Some(icon) => {
+                selected.insert(symbol_id(icon), icon);
+            }
+            None => eprintln!(
+                "Skipping icons::{}::{}: not in the manifest",
+                category, name
+            ),
+        }
+    }
+    let icons: Vec<&ManifestIcon> = selected.values().copied().collect();
+
+    for icon in &icons {
+        let path = out_dir.join(icon_file(icon));
+        fs::create_dir_all(
+            path.parent()
+                .expect("icon files live in a category directory"),
+        )?;
+        fs::write(&path, icon_svg(icon, &manifest.license))?;
+    }
+    fs::write(
+        out_dir.join("sprite.svg"),
+        generate_sprite(&icons, &manifest.license),
+    )?;
+
+    let codepoints: Option<BTreeMap<String, u32>> = font.then(|| {
+        selected
+            .keys()
+            .zip(FIRST_CODEPOINT..)
+            .map(|(id, codepoint)| (id.clone(), codepoint))
+            .collect()
+    });
+    if let Some(codepoints) = &codepoints {
+        build_font(&icons, codepoints, &out_dir)?;
+    }
+
+    fs::write(
+        out_dir.join("icons.css"),
+        generate_css(&icons, codepoints.as_ref(), &manifest.license),
+    )?;
+
+    let entries: BTreeMap<String, ExportEntry> = icons
+        .iter()
+        .map(|icon| {
+            let id = symbol_id(icon);
+            let entry = ExportEntry {
+                category: &icon.category,
+                name: &icon.name,
+                rust_name: &icon.rust_name,
+                view_box: &icon.view_box,
+                width: icon.width,
+                height: icon.height,
+                aspect_ratio: icon.aspect_ratio,
+                file: icon_file(icon),
+                symbol: format!("sprite.svg#{}", id),
+                class: format!("{} {}", ICON_CLASS, id),
+                codepoint: codepoints
+                    .as_ref()
+                    .map(|codepoints| format!("U+{:04X}", codepoints[&id])),
+            };
+            (id, entry)
+        })
+        .collect();
+    fs::write(
+        out_dir.join("icons.json"),
+        serde_json::to_string_pretty(&entries)? + "\n",
+    )?;
+
+    eprintln!(
+        "Exported {} icons to {}{}",
+        icons.len(),
+        out_dir.display(),
+        if font { " (with WOFF2 font)" } else { "" }
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> Manifest {
+        Manifest::new(
+            "license",
+            vec![
+                ManifestIcon::new("solid", "HOUSE", "0 0 576 512", "M0 0h576v512H0z").unwrap(),
+                ManifestIcon::new("regular", "HEART", "0 0 512 512", "M0 0h512v512H0z").unwrap(),
+            ],
+        )
+    }
+
+    #[test]
+    fn resolves_every_spelling() {
+        let manifest = manifest();
+        for spec in ["solid/house", "solid::HOUSE", "icons::solid::HOUSE", " solid/HOUSE "] {
+            assert_eq!(resolve(&manifest, spec).map(|icon| icon.name.as_str()), Some("house"), "{}", spec);
+        }
+        assert!(resolve(&manifest, "regular/house").is_none());
+        assert!(resolve(&manifest, "house").is_none());
+    }
+
+    #[test]
+    fn ids_match_the_sprite_provider() {
+        let manifest = manifest();
+        let house = resolve(&manifest, "solid/house").unwrap();
+        assert_eq!(symbol_id(house), naming::symbol_id("solid", "house"));
+
+        let icons: Vec<&ManifestIcon> = manifest.icons.iter().collect();
+        let sprite = generate_sprite(&icons, "license");
+        assert!(sprite.contains(r#"<symbol id="fa-solid-house" viewBox="0 0 576 512">"#));
+        assert!(sprite.contains(r#"<symbol id="fa-regular-heart" viewBox="0 0 512 512">"#));
+    }
+
+    #[test]
+    fn css_classes_are_namespaced() {
+        let manifest = manifest();
+        let icons: Vec<&ManifestIcon> = manifest.icons.iter().collect();
+
+        let masked = generate_css(&icons, None, "license");
+        assert!(masked.contains(".yew-shortcuts-icon {\n"));
+        assert!(masked.contains(
+            ".fa-solid-house { --yew-shortcuts-icon-mask: url(\"icons/solid/house.svg\"); width: 1.125em; }\n"
+        ));
+
+        let codepoints = BTreeMap::from([("fa-solid-house".to_string(), 0xE000), ("fa-regular-heart".to_string(), 0xE001)]);
+        let font = generate_css(&icons, Some(&codepoints), "license");
+        assert!(font.contains(".yew-shortcuts-icon {\n  font-family: \"yew-shortcuts-icons\";\n"));
+        assert!(font.contains(".fa-regular-heart::before { content: \"\\e001\"; }\n"));
+
+        for css in [masked, font] {
+            assert!(!css.contains(".fa ") && !css.contains("--fa-"), "{}", css);
+        }
+    }
+
+    #[test]
+    fn scans_crates_through_imports() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(
+            dir.path().join("src/main.rs"),
+            "use icons::solid as fas;\n// icons::brands::GITHUB\nfn main() { let _ = fas::HOUSE; }\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("src/broken.rs"), "fn main( { icons::regular::HEART }").unwrap();
+
+        let categories = manifest().icons.iter().map(|icon| icon.category.clone()).collect();
+        let mut found = BTreeSet::new();
+        scan_crate(dir.path(), &categories, &mut found).unwrap();
+        assert_eq!(found, BTreeSet::from([("solid".to_string(), "HOUSE".to_string())]));
+    }
+}
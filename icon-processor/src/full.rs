@@ -0,0 +1,154 @@
+//! Verification of full icon placements.
+//!
+//! `icon_model::full` derives where the cropped outline sits on the full
+//! canvas; here we prove the result by rasterizing both and comparing them
+//! against the original full SVG.
+
+use icon_model::full::{FullPlacement, FULL_VIEW_BOX};
+use icon_model::IconData;
+
+/// Rasterization size used when comparing rendered icons; 0.4 pixels per unit of the 640-unit canvas
+const VERIFY_SIZE: u32 = 256;
+
+/// Largest per-pixel alpha difference tolerated between renders.
+///
+/// tiny-skia anti-aliases with 16 coverage levels, so alpha moves in steps of
+/// 16. Identical geometry flattened under a different transform can land one
+/// edge pixel a single step off, which is all this allows. Rounding the stored
+/// placement moves edges by under 0.001 units, far below a step, while an
+/// offset error of half a unit (0.2 pixels at this size) already produces
+/// differences of 64.
+const MAX_ALPHA_DELTA: u8 = 16;
+
+/// Renders the cropped path with the derived placement and compares it
+/// pixel by pixel against the original full SVG.
+///
+/// The placement holds the `f32` values written into the generated module and
+/// `transform` formats them like the component does, so this checks exactly
+/// what is shipped.
+pub fn verify_placement(
+    cropped: &IconData,
+    full: &IconData,
+    placement: &FullPlacement,
+) -> Result<(), String> {
+    if full.view_box != FULL_VIEW_BOX {
+        return Err(format!(
+            "{}: unexpected full viewBox '{}'",
+            full.file_path, full.view_box
+        ));
+    }
+
+    // Rendered from the parsed path so duotone layers count as one opaque shape, like they are generated
+    let original = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{}"><path d="{}"/></svg>"#,
+        full.view_box, full.path_data
+    );
+    let reconstructed = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{}"><path transform="{}" d="{}"/></svg>"#,
+        FULL_VIEW_BOX,
+        placement.transform(),
+        cropped.path_data
+    );
+
+    let expected = rasterize(&original).map_err(|e| format!("{}: {}", full.file_path, e))?;
+    let actual = rasterize(&reconstructed).map_err(|e| format!("{}: {}", cropped.file_path, e))?;
+
+    let mismatched = expected
+        .iter()
+        .zip(&actual)
+        .filter(|(a, b)| a.abs_diff(**b) > MAX_ALPHA_DELTA)
+        .count();
+
+    if mismatched > 0 {
+        return Err(format!(
+            "{}: {} of {} pixels differ from the original full SVG",
+            full.file_path,
+            mismatched,
+            expected.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Rasterizes an SVG document and returns its alpha channel
+fn rasterize(svg: &str) -> Result<Vec<u8>, String> {
+    let tree = resvg::usvg::Tree::from_str(svg, &resvg::usvg::Options::default())
+        .map_err(|e| e.to_string())?;
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(VERIFY_SIZE, VERIFY_SIZE)
+        .ok_or("Failed to allocate pixmap")?;
+
+    let size = tree.size();
+    let transform = resvg::tiny_skia::Transform::from_scale(
+        VERIFY_SIZE as f32 / size.width(),
+        VERIFY_SIZE as f32 / size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    Ok(pixmap.pixels().iter().map(|p| p.alpha()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icon_model::full::derive_placement;
+
+    fn icon(view_box: &str, path_data: &str) -> IconData {
+        IconData {
+            name: "TEST".to_string(),
+            category: "solid".to_string(),
+            view_box: view_box.to_string(),
+            path_data: path_data.to_string(),
+            comment: String::new(),
+            file_path: "test.svg".to_string(),
+        }
+    }
+
+    /// A cropped circle with radius 256 and the full icon drawing it centered at 320,320
+    fn circle() -> (IconData, IconData) {
+        let cropped = icon("0 0 512 512", "M256 0a256 256 0 1 1 0 512a256 256 0 1 1 0-512z");
+        let full = icon(FULL_VIEW_BOX, "M320 64a256 256 0 1 1 0 512a256 256 0 1 1 0-512z");
+        (cropped, full)
+    }
+
+    #[test]
+    fn accepts_the_derived_placement() {
+        let (cropped, full) = circle();
+        let placement = derive_placement(&cropped, &full).unwrap();
+        assert_eq!(placement, FullPlacement { offset_x: 64.0, offset_y: 64.0, scale: 1.0 });
+        verify_placement(&cropped, &full, &placement).unwrap();
+    }
+
+    #[test]
+    fn accepts_a_scaled_placement() {
+        let cropped = icon("0 0 16 16", "M0 0h16v16h-16z");
+        let full = icon(FULL_VIEW_BOX, "M64 64h512v512h-512z");
+        let placement = derive_placement(&cropped, &full).unwrap();
+        assert_eq!(placement.scale, 32.0);
+        verify_placement(&cropped, &full, &placement).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_placement_off_by_half_a_unit() {
+        let (cropped, full) = circle();
+        let mut placement = derive_placement(&cropped, &full).unwrap();
+        placement.offset_x += 0.5;
+        assert!(verify_placement(&cropped, &full, &placement).is_err());
+    }
+
+    #[test]
+    fn rejects_a_slightly_wrong_scale() {
+        let (cropped, full) = circle();
+        let mut placement = derive_placement(&cropped, &full).unwrap();
+        placement.scale = 1.002;
+        assert!(verify_placement(&cropped, &full, &placement).is_err());
+    }
+
+    #[test]
+    fn rejects_other_full_view_boxes() {
+        let (cropped, _) = circle();
+        let full = icon("0 0 512 512", "M256 0a256 256 0 1 1 0 512a256 256 0 1 1 0-512z");
+        let placement = FullPlacement { offset_x: 0.0, offset_y: 0.0, scale: 1.0 };
+        assert!(verify_placement(&cropped, &full, &placement).is_err());
+    }
+}
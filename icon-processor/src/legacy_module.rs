@@ -0,0 +1,114 @@
+//! Reading icons back from a `fontawesome.rs` generated by an older release
+//!
+//! Current generations are described by `fontawesome.json`; only `diff` reads
+//! the generated source, for old icon set versions that predate the manifest.
+//! It understands both the split layout (`fontawesome.rs` declaring
+//! `pub mod solid;` next to `fontawesome/solid.rs`) and the older single-file
+//! layout with inline category modules.
+
+use icon_model::full::FULL_VIEW_BOX;
+use icon_model::manifest::{Manifest, ManifestError, ManifestFull, ManifestIcon};
+use std::fs;
+use std::path::Path;
+
+/// Reads a generated module from disk, following its per-category files
+pub(crate) fn read_module(path: &Path) -> Result<Manifest, ManifestError> {
+    let content = fs::read_to_string(path).map_err(ManifestError::Io)?;
+    let mut manifest = parse_module(&content)?;
+
+    let category_dir = path.with_extension("");
+    for category in declared_modules(&content) {
+        let category_path = category_dir.join(format!("{}.rs", category));
+        if category_path.is_file() {
+            let category_content = fs::read_to_string(category_path).map_err(ManifestError::Io)?;
+            parse_icons(&category_content, Some(category), &mut manifest.icons)?;
+        }
+    }
+
+    let Manifest { license, icons, .. } = manifest;
+    Ok(Manifest::new(&license, icons))
+}
+
+/// Extracts the license and all inline icons, including full placements, from a generated module
+fn parse_module(content: &str) -> Result<Manifest, ManifestError> {
+    let license = content
+        .lines()
+        .find_map(|line| {
+            line.trim()
+                .strip_prefix("pub const FONTAWESOME_LICENSE: &str = r#\"")
+                .map(|rest| rest.trim_end_matches("\"#;").to_string())
+        })
+        .unwrap_or_default();
+
+    let mut icons = Vec::new();
+    parse_icons(content, None, &mut icons)?;
+    Ok(Manifest::new(&license, icons))
+}
+
+/// Category modules declared with `pub mod name;`, skipping the aliases file
+fn declared_modules(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub mod "))
+        .filter_map(|rest| rest.strip_suffix(';'))
+        .filter(|module| *module != "aliases")
+        .collect()
+}
+
+fn parse_icons(
+    content: &str,
+    category: Option<&str>,
+    icons: &mut Vec<ManifestIcon>,
+) -> Result<(), ManifestError> {
+    let mut current_module: Option<String> = category.map(str::to_string);
+    let mut current_icon: Option<(String, String)> = None;
+    let mut last_icon: Option<usize> = None;
+    let mut view_box = String::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if let Some(rest) = trimmed.strip_prefix("pub mod ") {
+            let module = rest.trim_end_matches('{').trim();
+            // The `icons` module only re-exports the categories; `mod x;` lives in another file
+            current_module = (rest.ends_with('{') && module != "icons").then(|| module.to_string());
+            last_icon = None;
+        } else if let Some(rest) = trimmed.strip_prefix("pub const ") {
+            if let (Some(category), Some((name, _))) = (&current_module, rest.split_once(": &Icon")) {
+                current_icon = Some((category.clone(), name.to_string()));
+            }
+        } else if current_icon.is_some() {
+            if let Some(rest) = trimmed.strip_prefix("view_box: \"") {
+                view_box = rest.trim_end_matches("\",").to_string();
+            } else if let Some(rest) = trimmed.strip_prefix("d: r#\"") {
+                // The first `d` belongs to the cropped variant
+                let path_data = rest.trim_end_matches("\"#,");
+                if let Some((category, name)) = current_icon.take() {
+                    icons.push(ManifestIcon::new(&category, &name, &view_box, path_data)?);
+                    last_icon = Some(icons.len() - 1);
+                }
+            }
+        } else if let Some(icon) = last_icon.map(|idx| &mut icons[idx]) {
+            // The full placement follows the cropped path data of the same icon
+            let field = |prefix: &str| {
+                trimmed
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.trim_end_matches(',').parse::<f32>().ok())
+            };
+            let full = || ManifestFull {
+                view_box: FULL_VIEW_BOX.to_string(),
+                offset_x: 0.0,
+                offset_y: 0.0,
+                scale: 1.0,
+            };
+            if let Some(value) = field("offset_x: ") {
+                icon.full.get_or_insert_with(full).offset_x = value;
+            } else if let Some(value) = field("offset_y: ") {
+                icon.full.get_or_insert_with(full).offset_y = value;
+            } else if let Some(value) = field("scale: ") {
+                icon.full.get_or_insert_with(full).scale = value;
+            }
+        }
+    }
+    Ok(())
+}
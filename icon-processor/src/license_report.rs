@@ -0,0 +1,495 @@
+//! License and attribution reports for the icons a crate actually ships.
+//!
+//! `icon-processor license-report` finds the icons a crate uses, either by
+//! scanning its sources for `icons::<category>::<NAME>` references or by
+//! looking for their path data among the strings retained in a built WASM
+//! file, and groups them by icon pack with the pack's license text and
+//! required attribution. The report is written as Markdown, HTML or an SPDX
+//! 2.3 tag-value document.
+
+use crate::export::{resolve, scan_crate};
+use icon_model::manifest::{Manifest, ManifestIcon};
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::BuildHasher;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Base URI of the SPDX documents this tool writes
+const SPDX_NAMESPACE_BASE: &str = concat!(env!("CARGO_PKG_REPOSITORY"), "/spdxdocs");
+
+/// Bytes of path data used to find candidate matches in a WASM file
+const WASM_KEY_LEN: usize = 12;
+
+/// Output format of the report
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    Html,
+    Spdx,
+}
+
+/// One icon pack (a manifest) and the icons used from it
+struct Pack<'a> {
+    /// e.g. `Font Awesome Free v7.0.0`
+    name: String,
+    /// The license comment embedded in every SVG of the pack
+    license: &'a str,
+    spdx_license: &'static str,
+    /// What the license requires of the crate shipping the icons
+    attribution: &'static str,
+    icons: Vec<&'a ManifestIcon>,
+}
+
+impl<'a> Pack<'a> {
+    fn new(manifest: &'a Manifest, icons: Vec<&'a ManifestIcon>) -> Self {
+        let license = manifest.license.trim_start_matches('!');
+        let name = license.split(" by ").next().unwrap_or(license).trim();
+        let free = name.contains("Free");
+        Self {
+            name: if name.is_empty() { "Unknown icon pack".to_string() } else { name.to_string() },
+            license,
+            spdx_license: if free { "CC-BY-4.0" } else { "LicenseRef-Font-Awesome-Pro" },
+            attribution: if free {
+                "CC BY 4.0 requires attribution. Keep the license comment in the SVGs (the data-fa-license attribute or a page-level IconLicenseProvider) or credit Font Awesome where you credit other assets."
+            } else {
+                "Covered by your Font Awesome Pro commercial license; no public attribution is required, but the icons may only be shipped by license holders."
+            },
+            icons,
+        }
+    }
+
+    /// The license URL quoted in the comment, e.g. `https://fontawesome.com/license/free`
+    fn license_url(&self) -> Option<&str> {
+        let (_, rest) = self.license.split_once("License - ")?;
+        rest.split_whitespace().next()
+    }
+}
+
+/// Icons whose path data appears verbatim in a WASM file.
+///
+/// Icon constants end up as string data, so every icon a binary can render
+/// still has its `d` attribute in the data section. Icons sharing identical
+/// path data are indistinguishable and are all reported.
+fn scan_wasm<'a>(wasm: &[u8], icons: &[&'a ManifestIcon]) -> BTreeSet<(&'a str, &'a str)> {
+    let mut by_key: HashMap<&[u8], Vec<&'a ManifestIcon>> = HashMap::new();
+    let mut short = Vec::new();
+    for icon in icons {
+        let d = icon.path_data.as_bytes();
+        if d.len() >= WASM_KEY_LEN {
+            by_key.entry(&d[..WASM_KEY_LEN]).or_default().push(icon);
+        } else {
+            short.push(*icon);
+        }
+    }
+
+    let mut found = BTreeSet::new();
+    for (start, window) in wasm.windows(WASM_KEY_LEN).enumerate() {
+        if let Some(candidates) = by_key.get(window) {
+            for icon in candidates {
+                if wasm[start..].starts_with(icon.path_data.as_bytes()) {
+                    found.insert((icon.category.as_str(), icon.name.as_str()));
+                }
+            }
+        }
+    }
+    for icon in short {
+        let d = icon.path_data.as_bytes();
+        if !d.is_empty() && wasm.windows(d.len()).any(|window| window == d) {
+            found.insert((icon.category.as_str(), icon.name.as_str()));
+        }
+    }
+    found
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn generate_markdown(packs: &[Pack]) -> String {
+    let mut output = String::new();
+    output.push_str("# Icon licenses\n\n");
+    for pack in packs {
+        output.push_str(&format!("## {}\n\n", pack.name));
+        output.push_str(&format!("- License: `{}`", pack.spdx_license));
+        if let Some(url) = pack.license_url() {
+            output.push_str(&format!(" ({})", url));
+        }
+        output.push('\n');
+        output.push_str(&format!("- Attribution: {}\n\n", pack.attribution));
+        output.push_str(&format!("> {}\n\n", pack.license));
+        output.push_str(&format!("Icons used ({}):\n\n", pack.icons.len()));
+        for icon in &pack.icons {
+            output.push_str(&format!("- `{}` ({}/{})\n", icon.import_path, icon.category, icon.name));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn generate_html(packs: &[Pack]) -> String {
+    let mut output = String::new();
+    output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str("<title>Icon licenses</title>\n</head>\n<body>\n<h1>Icon licenses</h1>\n");
+    for pack in packs {
+        output.push_str(&format!("<section>\n<h2>{}</h2>\n", escape_html(&pack.name)));
+        output.push_str(&format!("<p>License: <code>{}</code>", pack.spdx_license));
+        if let Some(url) = pack.license_url() {
+            let url = escape_html(url);
+            output.push_str(&format!(" (<a href=\"{}\">{}</a>)", url, url));
+        }
+        output.push_str("</p>\n");
+        output.push_str(&format!("<p>{}</p>\n", escape_html(pack.attribution)));
+        output.push_str(&format!("<blockquote>{}</blockquote>\n", escape_html(pack.license)));
+        output.push_str(&format!("<p>Icons used ({}):</p>\n<ul>\n", pack.icons.len()));
+        for icon in &pack.icons {
+            output.push_str(&format!(
+                "<li><code>{}</code> ({}/{})</li>\n",
+                escape_html(&icon.import_path),
+                escape_html(&icon.category),
+                escape_html(&icon.name)
+            ));
+        }
+        output.push_str("</ul>\n</section>\n");
+    }
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// SPDX identifiers only allow letters, digits, `.` and `-`
+fn spdx_id(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '-' })
+        .collect()
+}
+
+/// Current time as an SPDX `Created` timestamp, e.g. `2025-01-31T12:00:00Z`
+fn spdx_timestamp() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let (days, time) = (seconds / 86_400, seconds % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// A random version 4 UUID, making each document's namespace unique
+fn document_uuid() -> String {
+    // Every RandomState is seeded with fresh random keys
+    let random = |salt: u64| RandomState::new().hash_one((salt, SystemTime::now()));
+    let high = random(0) & 0xffff_ffff_ffff_0fff | 0x4000;
+    let low = random(1) & 0x3fff_ffff_ffff_ffff | 0x8000_0000_0000_0000;
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xffff,
+        low >> 48,
+        low & 0xffff_ffff_ffff
+    )
+}
+
+fn generate_spdx(packs: &[Pack], document_name: &str) -> String {
+    let mut output = String::new();
+    output.push_str("SPDXVersion: SPDX-2.3\n");
+    output.push_str("DataLicense: CC0-1.0\n");
+    output.push_str("SPDXID: SPDXRef-DOCUMENT\n");
+    output.push_str(&format!("DocumentName: {}\n", document_name));
+    output.push_str(&format!(
+        "DocumentNamespace: {}/{}-icons-{}\n",
+        SPDX_NAMESPACE_BASE,
+        spdx_id(document_name),
+        document_uuid()
+    ));
+    output.push_str("Creator: Tool: yew-shortcuts-icon-processor\n");
+    output.push_str(&format!("Created: {}\n", spdx_timestamp()));
+
+    for pack in packs {
+        let package_id = format!("SPDXRef-Package-{}", spdx_id(&pack.name));
+        output.push_str(&format!("\nPackageName: {}\n", pack.name));
+        output.push_str(&format!("SPDXID: {}\n", package_id));
+        output.push_str("PackageDownloadLocation: https://fontawesome.com\n");
+        output.push_str("FilesAnalyzed: false\n");
+        output.push_str(&format!("PackageLicenseConcluded: {}\n", pack.spdx_license));
+        output.push_str(&format!("PackageLicenseDeclared: {}\n", pack.spdx_license));
+        output.push_str(&format!("PackageCopyrightText: <text>{}</text>\n", pack.license));
+        output.push_str(&format!("PackageAttributionText: <text>{}</text>\n", pack.license));
+        output.push_str(&format!(
+            "PackageComment: <text>Icons used ({}): {}</text>\n",
+            pack.icons.len(),
+            pack.icons
+                .iter()
+                .map(|icon| format!("{}/{}", icon.category, icon.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        output.push_str(&format!("Relationship: SPDXRef-DOCUMENT DESCRIBES {}\n", package_id));
+    }
+
+    if packs.iter().any(|pack| pack.spdx_license.starts_with("LicenseRef-")) {
+        output.push_str("\nLicenseID: LicenseRef-Font-Awesome-Pro\n");
+        output.push_str("LicenseName: Font Awesome Pro License\n");
+        output.push_str("ExtractedText: <text>See https://fontawesome.com/license</text>\n");
+    }
+    output
+}
+
+fn print_usage() {
+    eprintln!("Usage: icon-processor license-report (--scan <crate> | --wasm <file>)... [--format <format>] [--out <file>] [--manifest <file>]...");
+    eprintln!();
+    eprintln!("  --scan <crate>     Report every icons::<category>::<NAME> used in a crate's sources");
+    eprintln!("  --wasm <file>      Report every icon whose path data is retained in a built WASM file");
+    eprintln!("  --format <format>  markdown (default), html or spdx");
+    eprintln!("  --out <file>       Write the report to a file instead of stdout");
+    eprintln!("  --manifest <file>  Icon manifest to read; repeatable (default: fontawesome.json plus the Pro manifest if generated)");
+}
+
+/// Entry point for `icon-processor license-report`
+pub fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut scan_dirs = Vec::new();
+    let mut wasm_files = Vec::new();
+    let mut format = Format::Markdown;
+    let mut out: Option<PathBuf> = None;
+    let mut manifest_paths: Vec<PathBuf> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scan" => scan_dirs.push(args.next().ok_or("--scan needs a crate directory")?),
+            "--wasm" => wasm_files.push(args.next().ok_or("--wasm needs a file")?),
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("markdown" | "md") => Format::Markdown,
+                    Some("html") => Format::Html,
+                    Some("spdx") => Format::Spdx,
+                    other => {
+                        return Err(format!("unknown format {:?}; expected markdown, html or spdx", other).into())
+                    }
+                }
+            }
+            "--out" => out = Some(args.next().ok_or("--out needs a file")?.into()),
+            "--manifest" => manifest_paths.push(args.next().ok_or("--manifest needs a path")?.into()),
+            "-h" | "--help" => {
+                print_usage();
+                return Ok(());
+            }
+            other => {
+                print_usage();
+                return Err(format!("unexpected argument '{}'", other).into());
+            }
+        }
+    }
+    if scan_dirs.is_empty() && wasm_files.is_empty() {
+        print_usage();
+        return Err("nothing to scan".into());
+    }
+
+    if manifest_paths.is_empty() {
+        manifest_paths.push("../yew-shortcuts/src/fontawesome.json".into());
+        let pro = Path::new("../yew-shortcuts/src/fontawesome/pro/fontawesome-pro.json");
+        if pro.exists() {
+            manifest_paths.push(pro.into());
+        }
+    }
+    let manifests = manifest_paths
+        .iter()
+        .map(|path| Manifest::read(path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // (category, kebab name) of every icon found
+    let mut used: BTreeSet<(String, String)> = BTreeSet::new();
+
+    let categories: BTreeSet<String> = manifests
+        .iter()
+        .flat_map(|manifest| &manifest.icons)
+        .map(|icon| icon.category.clone())
+        .collect();
+    let mut scanned = BTreeSet::new();
+    for dir in &scan_dirs {
+        scan_crate(Path::new(dir), &categories, &mut scanned)?;
+    }
+    for (category, name) in &scanned {
+        let spec = format!("{}::{}", category, name);
+        match manifests.iter().find_map(|manifest| resolve(manifest, &spec)) {
+            Some(icon) => {
+                used.insert((icon.category.clone(), icon.name.clone()));
+            }
+            // Project icons from yew-shortcuts-build carry their own license comment
+            None => eprintln!("Skipping icons::{}: not in any manifest", spec),
+        }
+    }
+
+    let all_icons: Vec<&ManifestIcon> = manifests.iter().flat_map(|manifest| &manifest.icons).collect();
+    for file in &wasm_files {
+        let wasm = fs::read(file)?;
+        for (category, name) in scan_wasm(&wasm, &all_icons) {
+            used.insert((category.to_string(), name.to_string()));
+        }
+    }
+
+    let packs: Vec<Pack> = manifests
+        .iter()
+        .map(|manifest| {
+            let icons = manifest
+                .icons
+                .iter()
+                .filter(|icon| used.contains(&(icon.category.clone(), icon.name.clone())))
+                .collect();
+            Pack::new(manifest, icons)
+        })
+        .filter(|pack| !pack.icons.is_empty())
+        .collect();
+
+    let document_name = scan_dirs
+        .iter()
+        .chain(&wasm_files)
+        .filter_map(|path| Path::new(path).canonicalize().ok())
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+        .next()
+        .unwrap_or_else(|| "icons".to_string());
+
+    let report = match format {
+        Format::Markdown => generate_markdown(&packs),
+        Format::Html => generate_html(&packs),
+        Format::Spdx => generate_spdx(&packs, &document_name),
+    };
+
+    match out {
+        Some(path) => {
+            fs::write(&path, report)?;
+            let counts: Vec<String> = packs
+                .iter()
+                .map(|pack| format!("{} {}", pack.icons.len(), pack.name))
+                .collect();
+            eprintln!("Wrote {} ({})", path.display(), counts.join(", "));
+        }
+        None => print!("{}", report),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FREE_LICENSE: &str = "!Font Awesome Free v7.0.0 by @fontawesome - https://fontawesome.com License - https://fontawesome.com/license/free Copyright 2025 Fonticons, Inc.";
+    const PRO_LICENSE: &str = "!Font Awesome Pro v7.0.0 by @fontawesome - https://fontawesome.com License - https://fontawesome.com/license (Commercial License) Copyright 2025 Fonticons, Inc.";
+
+    fn icon(category: &str, rust_name: &str, path_data: &str) -> ManifestIcon {
+        ManifestIcon::new(category, rust_name, "0 0 512 512", path_data).unwrap()
+    }
+
+    #[test]
+    fn packs_follow_the_license_comment() {
+        let free = Manifest::new(FREE_LICENSE, Vec::new());
+        let pack = Pack::new(&free, Vec::new());
+        assert_eq!(pack.name, "Font Awesome Free v7.0.0");
+        assert_eq!(pack.spdx_license, "CC-BY-4.0");
+        assert_eq!(pack.license_url(), Some("https://fontawesome.com/license/free"));
+        assert!(pack.attribution.contains("requires attribution"));
+
+        let pro = Manifest::new(PRO_LICENSE, Vec::new());
+        let pack = Pack::new(&pro, Vec::new());
+        assert_eq!(pack.name, "Font Awesome Pro v7.0.0");
+        assert_eq!(pack.spdx_license, "LicenseRef-Font-Awesome-Pro");
+
+        let unknown = Manifest::new("", Vec::new());
+        assert_eq!(Pack::new(&unknown, Vec::new()).name, "Unknown icon pack");
+    }
+
+    #[test]
+    fn finds_path_data_retained_in_wasm() {
+        let house = icon("solid", "HOUSE", "M277.8 8.6c-12.3-11.4-31.3-11.4-43.5 0z");
+        let dot = icon("solid", "DOT", "M0 0h1z");
+        let gear = icon("solid", "GEAR", "M195.1 9.5C198.1-5.3 211.2-16 226.4-16z");
+        let icons = [&house, &dot, &gear];
+
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        wasm.extend_from_slice(house.path_data.as_bytes());
+        wasm.extend_from_slice(b"\0\0");
+        wasm.extend_from_slice(dot.path_data.as_bytes());
+        // Only the start of the gear's path data, which is not enough
+        wasm.extend_from_slice(&gear.path_data.as_bytes()[..WASM_KEY_LEN + 4]);
+
+        let found = scan_wasm(&wasm, &icons);
+        assert_eq!(found.into_iter().collect::<Vec<_>>(), [("solid", "dot"), ("solid", "house")]);
+    }
+
+    #[test]
+    fn reports_escape_and_identify_packs() {
+        let free = Manifest::new(FREE_LICENSE, vec![icon("solid", "HOUSE", "M0 0h1z")]);
+        let pro = Manifest::new(PRO_LICENSE, vec![icon("duotone", "GEAR", "M0 0h2z")]);
+        let packs = [Pack::new(&free, free.icons.iter().collect()), Pack::new(&pro, pro.icons.iter().collect())];
+
+        let markdown = generate_markdown(&packs);
+        assert!(markdown.contains("## Font Awesome Free v7.0.0"));
+        assert!(markdown.contains("- `icons::solid::HOUSE` (solid/house)"));
+
+        let html = generate_html(&packs);
+        assert!(html.contains("<code>icons::duotone::GEAR</code> (duotone/gear)"));
+
+        let spdx = generate_spdx(&packs, "my app");
+        assert!(spdx.contains("SPDXID: SPDXRef-Package-Font-Awesome-Free-v7.0.0\n"));
+        assert!(spdx.contains("PackageComment: <text>Icons used (1): duotone/gear</text>\n"));
+        assert!(spdx.contains("LicenseID: LicenseRef-Font-Awesome-Pro\n"));
+    }
+
+    #[test]
+    fn spdx_namespaces_are_unique_and_tool_owned() {
+        let free = Manifest::new(FREE_LICENSE, vec![icon("solid", "HOUSE", "M0 0h1z")]);
+        let packs = [Pack::new(&free, free.icons.iter().collect())];
+        let namespace = |spdx: &str| {
+            let line = spdx.lines().find(|line| line.starts_with("DocumentNamespace: ")).unwrap();
+            line["DocumentNamespace: ".len()..].to_string()
+        };
+
+        let first = namespace(&generate_spdx(&packs, "my app"));
+        let second = namespace(&generate_spdx(&packs, "my app"));
+        assert_ne!(first, second);
+
+        let prefix = format!("{}/my-app-icons-", SPDX_NAMESPACE_BASE);
+        assert!(first.starts_with("https://github.com/") && first.starts_with(&prefix), "{}", first);
+        let uuid = &first[prefix.len()..];
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+        assert!(uuid.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(escape_html("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+    }
+
+    #[test]
+    fn timestamps_are_utc_iso_8601() {
+        let timestamp = spdx_timestamp();
+        assert_eq!(timestamp.len(), "2025-01-31T12:00:00Z".len());
+        assert!(timestamp.starts_with("20"));
+        assert_eq!(&timestamp[10..11], "T");
+        assert!(timestamp.ends_with('Z'));
+    }
+}
@@ -1,307 +1,199 @@
-use roxmltree::Document;
-use std::collections::BTreeMap;
+use icon_model::aliases::{deprecated_names, parse_aliases};
+use icon_model::catalog::generate_icons_html;
+use icon_model::codegen::{generate_icons_markdown, generate_pro_module, generate_rust_module};
+use icon_model::full::FullPlacement;
+use icon_model::manifest::Manifest;
+use icon_model::metadata::{read_metadata, IconMetadata};
+use icon_model::{IconData, IconSet, SvgError, SvgErrorKind};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::io::Write;
 use std::path::Path;
-use walkdir::WalkDir;
 
-#[derive(Debug, Clone)]
-struct IconData {
-    name: String,
-    category: String,
-    view_box: String,
-    path_data: String,
-    comment: String,
-    file_path: String,
-}
+mod diff;
+mod export;
+mod full;
+mod legacy_module;
+mod license_report;
+mod similarity;
+mod validation;
 
-fn snake_case_to_upper(s: &str) -> String {
-    let mut result = s.to_uppercase().replace('-', "_");
-    // Ensure identifiers don't start with a number
-    if result.chars().next().is_some_and(|c| c.is_ascii_digit()) {
-        result = format!("ICON_{}", result);
-    }
-    result
-}
+use validation::{Issue, IssueKind, Report};
 
-fn process_svg_file(path: &Path) -> Result<IconData, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(path)?;
-    
-    // Extract the comment (it's between <!-- and -->)
-    let comment = if let Some(start) = content.find("<!--") {
-        if let Some(end) = content.find("-->") {
-            content[start + 4..end].trim().to_string()
-        } else {
-            String::new()
-        }
-    } else {
-        String::new()
-    };
-    
-    let doc = Document::parse(&content)?;
-    
-    let root = doc.root_element();
-    let svg = root
-        .descendants()
-        .find(|n| n.tag_name().name() == "svg")
-        .ok_or("No SVG element found")?;
-    
-    let view_box = svg
-        .attribute("viewBox")
-        .ok_or("No viewBox attribute")?
-        .to_string();
-    
-    let path_elem = svg
-        .descendants()
-        .find(|n| n.tag_name().name() == "path")
-        .ok_or("No path element found")?;
-    
-    let path_data = path_elem
-        .attribute("d")
-        .ok_or("No d attribute in path")?
-        .to_string();
-    
-    let file_name = path
-        .file_stem()
-        .ok_or("No file stem")?
-        .to_str()
-        .ok_or("Invalid UTF-8 in filename")?;
-    
-    let category = path
-        .parent()
-        .and_then(|p| p.file_name())
-        .and_then(|n| n.to_str())
-        .ok_or("Cannot determine category")?;
-    
-    let name = snake_case_to_upper(file_name);
-    
-    Ok(IconData {
-        name,
-        category: category.to_string(),
-        view_box,
-        path_data,
-        comment,
-        file_path: path.display().to_string(),
-    })
-}
-
-fn process_icons_directory(dir: &Path) -> (Vec<IconData>, String) {
-    let mut icons = Vec::new();
-    let mut license_comment = None;
-    
-    for entry in WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().is_some_and(|ext| ext == "svg"))
-    {
-        match process_svg_file(entry.path()) {
-            Ok(icon_data) => {
-                // Check that all icons have the same license comment
-                if let Some(ref expected_comment) = license_comment {
-                    if icon_data.comment != *expected_comment {
-                        eprintln!(
-                            "Warning: Different comment in {}: '{}' vs expected '{}'",
-                            icon_data.file_path, icon_data.comment, expected_comment
-                        );
-                    }
-                } else {
-                    license_comment = Some(icon_data.comment.clone());
-                }
-                
-                icons.push(icon_data);
-            }
-            Err(e) => {
-                eprintln!("Error processing {}: {}", entry.path().display(), e);
-            }
-        }
+impl From<SvgError> for Issue {
+    fn from(error: SvgError) -> Self {
+        let kind = match error.kind {
+            SvgErrorKind::Parse | SvgErrorKind::Unsupported => IssueKind::ParseFailure,
+            SvgErrorKind::MissingViewBox => IssueKind::MissingViewBox,
+            SvgErrorKind::LicenseMismatch => IssueKind::LicenseMismatch,
+        };
+        Issue::new(kind, Some(&error.file), error.message)
     }
-    
-    let license = license_comment.unwrap_or_default();
-    (icons, license)
 }
 
-fn extract_dimensions(view_box: &str) -> (u32, u32) {
-    // viewBox format is "x y width height"
-    let parts: Vec<&str> = view_box.split_whitespace().collect();
-    if parts.len() == 4 {
-        let width = parts[2].parse().unwrap_or(0);
-        let height = parts[3].parse().unwrap_or(0);
-        (width, height)
-    } else {
-        (0, 0)
+/// Reads an icon directory, recording every problem in the report
+fn process_icons_directory(dir: &Path, report: &mut Report) -> (Vec<IconData>, String) {
+    let IconSet { icons, license, errors } = icon_model::process_icons_directory(dir);
+    for error in errors {
+        report.push(error.into());
     }
+    (icons, license)
 }
 
-fn generate_icons_markdown(cropped_icons: &[IconData]) -> String {
-    let mut output = String::new();
+/// Derives the full icon placement for every cropped icon that has a full
+/// counterpart, and verifies each one renders identically to the original
+fn derive_full_placements(
+    cropped_icons: &[IconData],
+    full_icons: &[IconData],
+    report: &mut Report,
+) -> BTreeMap<(String, String), FullPlacement> {
+    let full_by_key: BTreeMap<(&str, &str), &IconData> = full_icons
+        .iter()
+        .map(|icon| ((icon.category.as_str(), icon.name.as_str()), icon))
+        .collect();
     
-    // Add header
-    output.push_str("# FontAwesome 7.0 Icons\n\n");
-    output.push_str(&format!("Total icons: {}\n\n", cropped_icons.len()));
+    let mut placements = BTreeMap::new();
     
-    // Group icons by category
-    let mut by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
     for icon in cropped_icons {
-        by_category.entry(icon.category.clone()).or_default().push(icon);
-    }
-    
-    // Generate markdown for each category
-    for (category, icons) in &by_category {
-        output.push_str(&format!("## {} ({} icons)\n\n", category, icons.len()));
+        let Some(full_icon) = full_by_key.get(&(icon.category.as_str(), icon.name.as_str())) else {
+            continue;
+        };
         
-        // Sort icons by name for consistent output
-        let mut sorted_icons = icons.clone();
-        sorted_icons.sort_by_key(|i| &i.name);
+        let result = icon_model::full::derive_placement(icon, full_icon).and_then(|placement| {
+            full::verify_placement(icon, full_icon, &placement).map(|()| placement)
+        });
         
-        for icon in sorted_icons {
-            let (width, height) = extract_dimensions(&icon.view_box);
-            output.push_str(&format!(
-                "- `icons::{}::{}` {}x{}\n",
-                category,
-                icon.name,
-                width,
-                height
-            ));
+        match result {
+            Ok(placement) => {
+                placements.insert((icon.category.clone(), icon.name.clone()), placement);
+            }
+            Err(e) => report.push(Issue::new(IssueKind::FullMismatch, None, e)),
         }
-        output.push_str("\n");
     }
     
-    output
+    placements
 }
 
-fn generate_rust_module(
-    cropped_icons: &[IconData],
-    full_icons: &[IconData],
-    license: &str,
-) -> String {
-    let mut output = String::new();
-    
-    // Add header
-    output.push_str("// This file is auto-generated. Do not edit manually.\n\n");
-    
-    // Add the shared license constant
-    output.push_str(&format!("/// FontAwesome license comment shared by all icons\n"));
-    output.push_str(&format!("pub const FONTAWESOME_LICENSE: &str = r#\"{}\"#;\n\n", license));
-    
-    // CroppedIcon struct for cropped icons
-    output.push_str("/// Represents a cropped FontAwesome icon with its SVG path data\n");
-    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
-    output.push_str("pub struct CroppedIcon {\n");
-    output.push_str("    /// The SVG viewBox attribute value\n");
-    output.push_str("    pub view_box: &'static str,\n");
-    output.push_str("    /// The SVG path data (d attribute)\n");
-    output.push_str("    pub d: &'static str,\n");
-    output.push_str("}\n\n");
-
-    // FullIcon struct for full SVGs (with standard viewBox)
-    output.push_str("/// Represents a full FontAwesome icon with standard viewBox\n");
-    output.push_str("#[cfg(feature = \"full-svg\")]\n");
-    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
-    output.push_str("pub struct FullIcon {\n");
-    output.push_str("    /// The SVG path data (d attribute)\n");
-    output.push_str("    pub d: &'static str,\n");
-    output.push_str("}\n\n");
-    
-    output.push_str("/// Standard viewBox for full SVG icons\n");
-    output.push_str("#[cfg(feature = \"full-svg\")]\n");
-    output.push_str("pub const FULL_VIEW_BOX: &str = \"0 0 640 640\";\n\n");
-    
-    // Icon struct that contains both
-    output.push_str("/// Represents a FontAwesome icon that can have both cropped and full variants\n");
-    output.push_str("#[derive(Debug, Clone, Copy, PartialEq)]\n");
-    output.push_str("pub struct Icon {\n");
-    output.push_str("    /// The cropped version of the icon\n");
-    output.push_str("    pub cropped: CroppedIcon,\n");
-    output.push_str("    /// The full version of the icon (when feature enabled)\n");
-    output.push_str("    #[cfg(feature = \"full-svg\")]\n");
-    output.push_str("    pub full: FullIcon,\n");
-    output.push_str("}\n\n");
-    
-    // Group icons by category
-    let mut cropped_by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
-    for icon in cropped_icons {
-        cropped_by_category.entry(icon.category.clone()).or_default().push(icon);
+/// FontAwesome's search metadata, or nothing when the download didn't include it
+fn load_metadata(path: &Path) -> BTreeMap<String, IconMetadata> {
+    if !path.exists() {
+        println!("No icon metadata at {}; the manifest will have no aliases or search terms", path.display());
+        return BTreeMap::new();
     }
-    
-    let mut full_by_category: BTreeMap<String, Vec<&IconData>> = BTreeMap::new();
-    for icon in full_icons {
-        full_by_category.entry(icon.category.clone()).or_default().push(icon);
+    match read_metadata(path) {
+        Ok(metadata) => {
+            println!("Read search metadata for {} icons from {}", metadata.len(), path.display());
+            metadata
+        }
+        Err(e) => {
+            eprintln!("Warning: {}: {}", path.display(), e);
+            BTreeMap::new()
+        }
     }
-    
-    // Generate module for each category
-    for (category, icons) in &cropped_by_category {
-        output.push_str(&format!("/// {} icons\n", category));
-        output.push_str(&format!("pub mod {} {{\n", category));
-        output.push_str("    use super::{Icon, CroppedIcon};\n");
-        output.push_str("    #[cfg(feature = \"full-svg\")]\n");
-        output.push_str("    use super::FullIcon;\n\n");
-        
-        // Sort icons by name for consistent output
-        let mut sorted_icons = icons.clone();
-        sorted_icons.sort_by_key(|i| &i.name);
-        
-        // Get corresponding full icons
-        let full_category_icons = full_by_category.get(category);
-        
-        for icon in sorted_icons {
-            let icon_name = &icon.name;
-            
-            // Find corresponding full icon if available
-            let full_icon = full_category_icons
-                .and_then(|full_icons| full_icons.iter().find(|fi| fi.name == icon.name));
-            
-            output.push_str(&format!("    /// {} icon\n", icon_name));
-            
-            if full_icon.is_some() {
-                // Generate Icon with both cropped and full
-                output.push_str(&format!("    pub const {}: &Icon = &Icon {{\n", icon_name));
-                output.push_str(&format!("        cropped: CroppedIcon {{\n"));
-                output.push_str(&format!("            view_box: \"{}\",\n", icon.view_box));
-                output.push_str(&format!("            d: r#\"{}\"#,\n", icon.path_data));
-                output.push_str(&format!("        }},\n"));
-                output.push_str(&format!("        #[cfg(feature = \"full-svg\")]\n"));
-                output.push_str(&format!("        full: FullIcon {{\n"));
-                output.push_str(&format!("            d: r#\"{}\"#,\n", full_icon.unwrap().path_data));
-                output.push_str(&format!("        }},\n"));
-                output.push_str("    };\n\n");
-            } else {
-                // Generate Icon with only cropped (shouldn't happen if icons match)
-                output.push_str(&format!("    pub const {}: &Icon = &Icon {{\n", icon_name));
-                output.push_str(&format!("        cropped: CroppedIcon {{\n"));
-                output.push_str(&format!("            view_box: \"{}\",\n", icon.view_box));
-                output.push_str(&format!("            d: r#\"{}\"#,\n", icon.path_data));
-                output.push_str(&format!("        }},\n"));
-                output.push_str("    };\n\n");
+}
+
+fn print_usage() {
+    eprintln!("Usage: icon-processor [command] [options]");
+    eprintln!();
+    eprintln!("Commands:");
+    eprintln!("  (none)   Generate fontawesome.rs, fontawesome.json, ICONS.md and ICONS.html from ../svgs-7 and ../svgs-full-7");
+    eprintln!("           (search metadata is read from ../metadata-7/icons.json when present)");
+    eprintln!("           --strict           Fail on any validation issue, not just fatal ones");
+    eprintln!("           --report <file>    Write the validation report as JSON");
+    eprintln!("           --msgpack          Also write the icon manifest as MessagePack");
+    eprintln!("           --pro <dir>        Also generate the extra styles of a Font Awesome Pro or Kit download");
+    eprintln!("  diff     Compare two icon set versions and emit a changelog and aliases");
+    eprintln!("  export   Write a sprite, per-icon SVGs and CSS/JSON maps for a subset of icons");
+    eprintln!("  license-report  List the icons a crate or WASM file uses with their licenses and attributions");
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    
+    match args.first().map(String::as_str) {
+        None | Some("--strict" | "--report" | "--msgpack" | "--pro") => generate(&args),
+        Some("diff") => {
+            if let Err(e) = diff::run(&args[1..]) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
             }
         }
-        
-        output.push_str("}\n\n");
-    }
-    
-    // Add icons module that re-exports all categories
-    output.push_str("/// Module containing all FontAwesome icons organized by category\n");
-    output.push_str("pub mod icons {\n");
-    for category in cropped_by_category.keys() {
-        output.push_str(&format!("    pub use super::{};\n", category));
+        Some("export") => {
+            if let Err(e) = export::run(&args[1..]) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Some("license-report") => {
+            if let Err(e) = license_report::run(&args[1..]) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Some("-h" | "--help") => print_usage(),
+        Some(other) => {
+            eprintln!("Unknown command '{}'", other);
+            print_usage();
+            std::process::exit(2);
+        }
     }
-    output.push_str("}\n\n");
-    
-    output
 }
 
-fn main() {
+fn generate(args: &[String]) {
+    let mut strict = false;
+    let mut report_path = None;
+    let mut msgpack = false;
+    let mut pro_dir = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" => strict = true,
+            "--msgpack" => msgpack = true,
+            "--report" => match args.next() {
+                Some(path) => report_path = Some(path),
+                None => {
+                    eprintln!("--report needs a path");
+                    std::process::exit(2);
+                }
+            },
+            "--pro" => match args.next() {
+                Some(path) => pro_dir = Some(Path::new(path)),
+                None => {
+                    eprintln!("--pro needs the directory of a Pro download");
+                    std::process::exit(2);
+                }
+            },
+            other => {
+                eprintln!("Unknown option '{}'", other);
+                print_usage();
+                std::process::exit(2);
+            }
+        }
+    }
+    
+    let mut report = Report::default();
+    
     let cropped_dir = Path::new("../svgs-7");
     let full_dir = Path::new("../svgs-full-7");
     let output_path = Path::new("../yew-shortcuts/src/fontawesome.rs");
+    let manifest_path = Path::new("../yew-shortcuts/src/fontawesome.json");
+    let msgpack_path = Path::new("../yew-shortcuts/src/fontawesome.msgpack");
     let markdown_path = Path::new("../ICONS.md");
+    let catalog_path = Path::new("../ICONS.html");
+    let metadata_path = Path::new("../metadata-7/icons.json");
+    let category_dir = Path::new("../yew-shortcuts/src/fontawesome");
+    let aliases_path = Path::new("../yew-shortcuts/src/fontawesome/aliases.json");
+    let crate_manifest_path = Path::new("../yew-shortcuts/Cargo.toml");
+    let pro_module_dir = Path::new("../yew-shortcuts/src/fontawesome/pro");
+    let pro_manifest_path = Path::new("../yew-shortcuts/src/fontawesome/pro/fontawesome-pro.json");
     
     // Process cropped icons
     println!("Processing cropped icons from {}...", cropped_dir.display());
-    let (cropped_icons, license) = process_icons_directory(cropped_dir);
+    let (cropped_icons, license) = process_icons_directory(cropped_dir, &mut report);
     
     // Process full icons
     println!("Processing full icons from {}...", full_dir.display());
-    let (full_icons, _) = process_icons_directory(full_dir);
+    let (full_icons, _) = process_icons_directory(full_dir, &mut report);
     
     // Count icons by category
     let mut cropped_counts: BTreeMap<String, usize> = BTreeMap::new();
@@ -326,17 +218,137 @@ fn main() {
     }
     println!("  Total: {} icons", full_icons.len());
     
+    // Validate identifiers, viewBoxes and cropped/full pairing
+    validation::check_identifiers(&cropped_icons, &mut report);
+    validation::check_view_boxes(&cropped_icons, &mut report);
+    validation::check_pairs(&cropped_icons, &full_icons, &mut report);
+    match fs::read_to_string(crate_manifest_path) {
+        Ok(cargo_toml) => validation::check_features(&cropped_icons, &cargo_toml, &mut report),
+        Err(e) => eprintln!("Warning: could not read {}: {}", crate_manifest_path.display(), e),
+    }
+    
+    // Pro styles missing from the free set, laid out like svgs-7 and svgs-full-7
+    let mut pro = None;
+    if let Some(pro_dir) = pro_dir {
+        let free_categories: BTreeSet<&str> = cropped_counts.keys().map(String::as_str).collect();
+        let is_extra = |icon: &IconData| !free_categories.contains(icon.category.as_str());
+        
+        println!("\nProcessing Pro icons from {}...", pro_dir.display());
+        let (pro_cropped, pro_license) = process_icons_directory(&pro_dir.join("svgs"), &mut report);
+        let (pro_full, _) = process_icons_directory(&pro_dir.join("svgs-full"), &mut report);
+        let pro_cropped: Vec<IconData> = pro_cropped.into_iter().filter(is_extra).collect();
+        let pro_full: Vec<IconData> = pro_full.into_iter().filter(is_extra).collect();
+        
+        let mut pro_counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for icon in &pro_cropped {
+            *pro_counts.entry(&icon.category).or_default() += 1;
+        }
+        for (category, count) in &pro_counts {
+            println!("  {}: {} icons", category, count);
+        }
+        println!("  Total: {} extra icons (styles already in the free set are skipped)", pro_cropped.len());
+        
+        validation::check_identifiers(&pro_cropped, &mut report);
+        validation::check_view_boxes(&pro_cropped, &mut report);
+        validation::check_pairs(&pro_cropped, &pro_full, &mut report);
+        let pro_metadata = load_metadata(&pro_dir.join("metadata/icons.json"));
+        pro = Some((pro_cropped, pro_full, pro_license, pro_metadata));
+    }
+    
+    // Derive full icon placements from the cropped geometry
+    println!("\nDeriving and verifying full icon placements...");
+    let mut placements = derive_full_placements(&cropped_icons, &full_icons, &mut report);
+    if let Some((pro_cropped, pro_full, _, _)) = &pro {
+        placements.extend(derive_full_placements(pro_cropped, pro_full, &mut report));
+    }
+    println!("  Verified {} placements", placements.len());
+    
+    println!();
+    report.print_human(strict);
+    if let Some(path) = report_path {
+        fs::write(path, report.to_json(strict)).expect("Failed to write validation report");
+        println!("Wrote validation report to {}", path);
+    }
+    if report.has_errors(strict) {
+        eprintln!("Validation failed; no files were written");
+        std::process::exit(1);
+    }
+    
     // Generate Rust module
     println!("\nGenerating Rust module...");
-    let rust_code = generate_rust_module(&cropped_icons, &full_icons, &license);
+    let aliases = match fs::read_to_string(aliases_path) {
+        Ok(json) => match parse_aliases(&json) {
+            Ok(table) => deprecated_names(&table),
+            Err(e) => {
+                eprintln!("Warning: {}: {}", aliases_path.display(), e);
+                BTreeMap::new()
+            }
+        },
+        Err(_) => BTreeMap::new(),
+    };
+    if !aliases.is_empty() {
+        println!("Including deprecated aliases from {}", aliases_path.display());
+    }
+    // The constants are documented with the same metadata the manifest records
+    let metadata = load_metadata(metadata_path);
+    let module = generate_rust_module(&cropped_icons, &placements, &aliases, &metadata, &license);
     
     // Write Rust module to file
     let mut file = fs::File::create(output_path).expect("Failed to create output file");
-    file.write_all(rust_code.as_bytes())
+    file.write_all(module.root.as_bytes())
         .expect("Failed to write to output file");
     
     println!("Successfully generated {}", output_path.display());
     
+    // Write one file per category, each behind its own cargo feature
+    fs::create_dir_all(category_dir).expect("Failed to create category module directory");
+    for (category, code) in &module.categories {
+        let category_path = category_dir.join(format!("{}.rs", category));
+        fs::write(&category_path, code).expect("Failed to write category module");
+        println!("Successfully generated {}", category_path.display());
+    }
+    if let Some(code) = &module.aliases {
+        let aliases_module_path = category_dir.join("aliases.rs");
+        fs::write(&aliases_module_path, code).expect("Failed to write aliases module");
+        println!("Successfully generated {}", aliases_module_path.display());
+    }
+    
+    // Generate the machine-readable manifest
+    println!("\nGenerating icon manifest...");
+    let mut manifest = Manifest::from_icons(&cropped_icons, &placements, &license).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    manifest.apply_metadata(&metadata);
+    manifest.apply_perceptual_hashes(similarity::manifest_hash);
+    fs::write(manifest_path, manifest.to_json()).expect("Failed to write manifest");
+    println!("Successfully generated {}", manifest_path.display());
+    if msgpack {
+        fs::write(msgpack_path, manifest.to_msgpack()).expect("Failed to write MessagePack manifest");
+        println!("Successfully generated {}", msgpack_path.display());
+    }
+    
+    // Pro styles go into a gitignored directory behind the `pro` feature
+    if let Some((pro_cropped, _, pro_license, pro_metadata)) = &pro {
+        println!("\nGenerating Pro modules...");
+        let pro_module = generate_pro_module(pro_cropped, &placements, pro_metadata, pro_license);
+        fs::create_dir_all(pro_module_dir).expect("Failed to create Pro module directory");
+        fs::write(pro_module_dir.join("mod.rs"), &pro_module.root).expect("Failed to write Pro module");
+        for (category, code) in &pro_module.categories {
+            let category_path = pro_module_dir.join(format!("{}.rs", category));
+            fs::write(&category_path, code).expect("Failed to write Pro category module");
+            println!("Successfully generated {}", category_path.display());
+        }
+        let mut pro_manifest = Manifest::from_icons(pro_cropped, &placements, pro_license).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        pro_manifest.apply_metadata(pro_metadata);
+        pro_manifest.apply_perceptual_hashes(similarity::manifest_hash);
+        fs::write(pro_manifest_path, pro_manifest.to_json()).expect("Failed to write Pro manifest");
+        println!("Successfully generated {}", pro_manifest_path.display());
+    }
+    
     // Generate markdown documentation
     println!("\nGenerating markdown documentation...");
     let markdown = generate_icons_markdown(&cropped_icons);
@@ -347,4 +359,10 @@ fn main() {
         .expect("Failed to write to markdown file");
     
     println!("Successfully generated {}", markdown_path.display());
+    
+    // Generate the visual HTML catalog
+    println!("\nGenerating HTML catalog...");
+    let catalog = generate_icons_html(&cropped_icons, &aliases, &license);
+    fs::write(catalog_path, catalog).expect("Failed to write HTML catalog");
+    println!("Successfully generated {}", catalog_path.display());
 }
\ No newline at end of file
@@ -0,0 +1,173 @@
+//! Perceptual hashes of icon shapes, for suggesting icons that look alike
+//!
+//! Each icon is rendered centered in a square so its proportions count and
+//! divided into an 8x8 grid. Holes the background cannot reach count as
+//! covered, so the outline style of an icon hashes like its solid style. A
+//! bit is set for every cell the silhouette covers more than its average,
+//! capped at half a cell so solid shapes keep all their cells while thin
+//! strokes that enclose nothing are kept too. Icons that look alike set
+//! mostly the same cells, so the Hamming distance between two hashes
+//! measures how different they look.
+
+use icon_model::manifest::ManifestIcon;
+use icon_model::ViewBox;
+
+/// Cells per side of the grid, giving a 64-bit hash
+const GRID_SIZE: u32 = 8;
+
+/// Pixels per side of a cell when rendering, so coverage is measured over an area
+/// and thin strokes still close off the holes they enclose
+const CELL_PIXELS: u32 = 8;
+
+/// The hash of an icon, or `None` if it cannot be rendered
+pub fn manifest_hash(icon: &ManifestIcon) -> Option<u64> {
+    perceptual_hash(&icon.view_box, &icon.path_data).ok()
+}
+
+/// 64-bit coverage hash of a path drawn centered in a square canvas
+pub fn perceptual_hash(view_box: &str, path_data: &str) -> Result<u64, String> {
+    let bounds = ViewBox::parse(view_box).ok_or_else(|| format!("Invalid viewBox \"{}\"", view_box))?;
+    let side = bounds.width.max(bounds.height);
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}"><path d="{}"/></svg>"#,
+        bounds.min_x - (side - bounds.width) / 2.0,
+        bounds.min_y - (side - bounds.height) / 2.0,
+        side,
+        side,
+        path_data
+    );
+
+    let cells = cell_coverage(&svg)?;
+    let mean = cells.iter().sum::<f32>() / cells.len() as f32;
+    let threshold = mean.min(0.5);
+
+    Ok(cells
+        .iter()
+        .enumerate()
+        .filter(|(_, coverage)| **coverage > threshold)
+        .fold(0, |hash, (bit, _)| hash | (1 << bit)))
+}
+
+/// Renders an SVG document and returns the fraction of each grid cell its
+/// silhouette covers, row by row
+fn cell_coverage(svg: &str) -> Result<Vec<f32>, String> {
+    let size = GRID_SIZE * CELL_PIXELS;
+    let tree = resvg::usvg::Tree::from_str(svg, &resvg::usvg::Options::default())
+        .map_err(|e| e.to_string())?;
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(size, size).ok_or("Failed to allocate pixmap")?;
+
+    let tree_size = tree.size();
+    let transform = resvg::tiny_skia::Transform::from_scale(
+        size as f32 / tree_size.width(),
+        size as f32 / tree_size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    let ink: Vec<f32> = pixmap.pixels().iter().map(|pixel| pixel.alpha() as f32 / 255.0).collect();
+    let outside = background(&ink, size as usize);
+
+    let mut cells = vec![0.0; (GRID_SIZE * GRID_SIZE) as usize];
+    for (index, coverage) in ink.iter().enumerate() {
+        let x = index as u32 % size / CELL_PIXELS;
+        let y = index as u32 / size / CELL_PIXELS;
+        // Ink keeps its antialiased coverage; empty pixels inside the shape are holes
+        let silhouette = if outside[index] { 0.0 } else if *coverage > 0.0 { *coverage } else { 1.0 };
+        cells[(y * GRID_SIZE + x) as usize] += silhouette;
+    }
+    let pixels_per_cell = (CELL_PIXELS * CELL_PIXELS) as f32;
+    Ok(cells.into_iter().map(|sum| sum / pixels_per_cell).collect())
+}
+
+/// Empty pixels reachable from the edge of a `size` x `size` canvas without
+/// crossing any ink; the empty pixels left over are holes in the shape
+fn background(ink: &[f32], size: usize) -> Vec<bool> {
+    let mut outside = vec![false; ink.len()];
+    let mut stack: Vec<usize> = (0..size)
+        .flat_map(|i| [i, (size - 1) * size + i, i * size, i * size + size - 1])
+        .collect();
+    while let Some(index) = stack.pop() {
+        if outside[index] || ink[index] > 0.0 {
+            continue;
+        }
+        outside[index] = true;
+        let (x, y) = (index % size, index / size);
+        if x > 0 {
+            stack.push(index - 1);
+        }
+        if x + 1 < size {
+            stack.push(index + 1);
+        }
+        if y > 0 {
+            stack.push(index - size);
+        }
+        if y + 1 < size {
+            stack.push(index + size);
+        }
+    }
+    outside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLID_HEART: &str = "M47.6 300.4L228.3 469.1c7.5 7 17.4 10.9 27.7 10.9s20.2-3.9 27.7-10.9L464.4 300.4c30.4-28.3 47.6-68 47.6-109.5v-5.8c0-69.9-50.5-129.5-119.4-141C347 36.5 300.6 51.4 268 84L256 96 244 84c-32.6-32.6-79-47.5-124.6-39.9C50.5 55.6 0 115.2 0 185.1v5.8c0 41.5 17.2 81.2 47.6 109.5z";
+
+    const REGULAR_HEART: &str = "M225.8 468.2l-2.5-2.3L48.1 303.2C17.4 274.7 0 234.7 0 192.8v-3.3c0-70.4 50-130.8 119.2-144C158.6 37.9 198.9 47 231 69.6c9 6.4 17.4 13.8 25 22.3c4.2-4.8 8.7-9.2 13.5-13.3c3.7-3.2 7.5-6.2 11.5-9C313.1 47 353.4 37.9 392.8 45.4C462 58.6 512 119.1 512 189.5v3.3c0 41.9-17.4 81.9-48.1 110.4L288.7 465.9l-2.5 2.3c-8.2 7.6-19 11.9-30.2 11.9s-22-4.2-30.2-11.9zM239.1 145c-.4-.3-.7-.7-1-1.1l-17.8-20c-23.1-25.9-58-37.7-92-31.2C81.6 101.5 48 142.1 48 189.5v3.3c0 28.5 11.9 55.8 32.8 75.2L256 430.7 431.2 268c20.9-19.4 32.8-46.7 32.8-75.2v-3.3c0-47.3-33.6-88-80.1-96.9c-34-6.5-69 5.4-92 31.2l-17.8 20c-.3 .4-.7 .7-1 1.1c-4.5 4.5-10.6 7-16.9 7s-12.4-2.5-16.9-7z";
+
+    fn distance(a: u64, b: u64) -> u32 {
+        (a ^ b).count_ones()
+    }
+
+    /// A solid circle and the ring of the regular style
+    const SOLID_CIRCLE: &str = "M0 256a256 256 0 1 1 512 0a256 256 0 1 1-512 0z";
+    const REGULAR_CIRCLE: &str = "M0 256a256 256 0 1 1 512 0a256 256 0 1 1-512 0zm48 0a208 208 0 1 0 416 0a208 208 0 1 0-416 0z";
+
+    #[test]
+    fn styles_of_one_icon_are_closer_than_other_icons() {
+        let solid = perceptual_hash("0 0 512 512", SOLID_HEART).unwrap();
+        let regular = perceptual_hash("0 0 512 512", REGULAR_HEART).unwrap();
+        let unrelated = [
+            // A horizontal bar, like `minus`
+            perceptual_hash("0 0 448 64", "M0 0h448v64H0z").unwrap(),
+            // A diagonal slash
+            perceptual_hash("0 0 512 512", "M0 448L448 0h64v64L64 512H0z").unwrap(),
+            perceptual_hash("0 0 512 512", SOLID_CIRCLE).unwrap(),
+        ];
+
+        for other in unrelated {
+            assert!(
+                distance(solid, regular) < distance(solid, other),
+                "solid and regular heart differ in {} bits, solid heart and {:016x} in {}",
+                distance(solid, regular),
+                other,
+                distance(solid, other)
+            );
+            assert!(distance(solid, regular) < distance(regular, other));
+        }
+    }
+
+    #[test]
+    fn enclosed_holes_count_as_covered() {
+        let solid = perceptual_hash("0 0 512 512", SOLID_CIRCLE).unwrap();
+        let regular = perceptual_hash("0 0 512 512", REGULAR_CIRCLE).unwrap();
+        assert_eq!(solid, regular);
+
+        // A stroke that encloses nothing keeps only its own cells
+        let bar = perceptual_hash("0 0 512 512", "M0 224h512v64H0z").unwrap();
+        assert_eq!(bar, 0xff_u64 << 24 | 0xff_u64 << 32);
+    }
+
+    #[test]
+    fn hash_ignores_position_and_scale() {
+        let square = perceptual_hash("0 0 512 512", "M0 0h512v512H0z").unwrap();
+        let moved = perceptual_hash("100 100 64 64", "M100 100h64v64h-64z").unwrap();
+        assert_eq!(square, moved);
+        assert_eq!(square, u64::MAX);
+    }
+
+    #[test]
+    fn rejects_invalid_view_boxes() {
+        assert!(perceptual_hash("0 0 wide", "M0 0h1v1H0z").is_err());
+    }
+}
@@ -0,0 +1,432 @@
+//! Validation of the icon sources and the identifiers generated from them.
+//!
+//! Problems are collected into a [`Report`] instead of being printed as they
+//! are found. By default only issues that would produce a broken module stop
+//! generation; with `--strict` every issue does.
+
+use icon_model::naming::{self, category_feature, IdentifierProblemKind};
+use icon_model::{IconData, ViewBox};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Kinds of problems found while processing icons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// The SVG could not be read or parsed, or lacks a path
+    ParseFailure,
+    /// The SVG has no viewBox attribute
+    MissingViewBox,
+    /// The viewBox is not four numbers with a positive size
+    InvalidViewBox,
+    /// The viewBox has fractional components. Generation handles them, but
+    /// FontAwesome's viewBoxes are whole numbers, so they hint at a bad export.
+    NonIntegerViewBox,
+    /// The license comment differs from the one shared by the other icons
+    LicenseMismatch,
+    /// A cropped icon has no full counterpart
+    MissingFullIcon,
+    /// A full icon has no cropped counterpart
+    MissingCroppedIcon,
+    /// The full icon could not be reproduced from the cropped geometry
+    FullMismatch,
+    /// Several files map to the same Rust identifier
+    DuplicateIdentifier,
+    /// A generated identifier is a Rust keyword
+    KeywordCollision,
+    /// A category or constant name is not a valid Rust identifier
+    InvalidIdentifier,
+    /// A category's cargo feature is not declared in yew-shortcuts, or not part of `fa-all`
+    UndeclaredFeature,
+}
+
+impl IssueKind {
+    /// Issues that would make the generated module incorrect even outside strict mode
+    pub fn is_fatal(self) -> bool {
+        matches!(
+            self,
+            IssueKind::InvalidViewBox
+                | IssueKind::FullMismatch
+                | IssueKind::DuplicateIdentifier
+                | IssueKind::KeywordCollision
+                | IssueKind::InvalidIdentifier
+        )
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            IssueKind::ParseFailure => "parse failures",
+            IssueKind::MissingViewBox => "missing viewBox",
+            IssueKind::InvalidViewBox => "invalid viewBox",
+            IssueKind::NonIntegerViewBox => "non-integer viewBox",
+            IssueKind::LicenseMismatch => "license comment mismatches",
+            IssueKind::MissingFullIcon => "cropped icons without a full icon",
+            IssueKind::MissingCroppedIcon => "full icons without a cropped icon",
+            IssueKind::FullMismatch => "full icons not reproducible from cropped data",
+            IssueKind::DuplicateIdentifier => "duplicate Rust identifiers",
+            IssueKind::KeywordCollision => "Rust keyword collisions",
+            IssueKind::InvalidIdentifier => "invalid identifiers",
+            IssueKind::UndeclaredFeature => "undeclared category features",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single problem, tied to the file it was found in when there is one
+#[derive(Debug, Clone, Serialize)]
+pub struct Issue {
+    pub kind: IssueKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn new(kind: IssueKind, file: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            file: file.map(str::to_string),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}: {}", file, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// All issues found during a run
+#[derive(Debug, Default)]
+pub struct Report {
+    pub issues: Vec<Issue>,
+}
+
+#[derive(Serialize)]
+struct JsonIssue<'a> {
+    severity: Severity,
+    #[serde(flatten)]
+    issue: &'a Issue,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    strict: bool,
+    passed: bool,
+    error_count: usize,
+    warning_count: usize,
+    counts: BTreeMap<IssueKind, usize>,
+    issues: Vec<JsonIssue<'a>>,
+}
+
+impl Report {
+    pub fn push(&mut self, issue: Issue) {
+        self.issues.push(issue);
+    }
+
+    pub fn severity(&self, issue: &Issue, strict: bool) -> Severity {
+        if strict || issue.kind.is_fatal() {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+
+    fn error_count(&self, strict: bool) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| self.severity(issue, strict) == Severity::Error)
+            .count()
+    }
+
+    /// Whether generation must stop without writing any output
+    pub fn has_errors(&self, strict: bool) -> bool {
+        self.error_count(strict) > 0
+    }
+
+    fn counts(&self) -> BTreeMap<IssueKind, usize> {
+        let mut counts = BTreeMap::new();
+        for issue in &self.issues {
+            *counts.entry(issue.kind).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Prints every issue followed by a summary grouped by kind
+    pub fn print_human(&self, strict: bool) {
+        if self.issues.is_empty() {
+            eprintln!("Validation passed with no issues");
+            return;
+        }
+
+        for issue in &self.issues {
+            let prefix = match self.severity(issue, strict) {
+                Severity::Error => "Error",
+                Severity::Warning => "Warning",
+            };
+            eprintln!("{}: {}", prefix, issue);
+        }
+
+        eprintln!("\nValidation summary:");
+        for (kind, count) in self.counts() {
+            eprintln!("  {}: {}", kind.label(), count);
+        }
+        let errors = self.error_count(strict);
+        eprintln!(
+            "  {} errors, {} warnings{}",
+            errors,
+            self.issues.len() - errors,
+            if strict { " (strict mode)" } else { "" }
+        );
+    }
+
+    pub fn to_json(&self, strict: bool) -> String {
+        let error_count = self.error_count(strict);
+        let report = JsonReport {
+            strict,
+            passed: error_count == 0,
+            error_count,
+            warning_count: self.issues.len() - error_count,
+            counts: self.counts(),
+            issues: self
+                .issues
+                .iter()
+                .map(|issue| JsonIssue {
+                    severity: self.severity(issue, strict),
+                    issue,
+                })
+                .collect(),
+        };
+        serde_json::to_string_pretty(&report).expect("report is always serializable")
+    }
+}
+
+/// Checks the generated constant and module names for collisions and keywords
+pub fn check_identifiers(icons: &[IconData], report: &mut Report) {
+    for problem in naming::check_identifiers(icons) {
+        let kind = match problem.kind {
+            IdentifierProblemKind::Duplicate => IssueKind::DuplicateIdentifier,
+            IdentifierProblemKind::Keyword => IssueKind::KeywordCollision,
+            IdentifierProblemKind::Invalid => IssueKind::InvalidIdentifier,
+        };
+        report.push(Issue::new(kind, problem.file.as_deref(), problem.message));
+    }
+}
+
+/// Checks that every viewBox is four whole numbers with a positive size
+pub fn check_view_boxes(icons: &[IconData], report: &mut Report) {
+    for icon in icons {
+        match ViewBox::parse(&icon.view_box) {
+            Some(view_box) => {
+                let values = [view_box.min_x, view_box.min_y, view_box.width, view_box.height];
+                if values.iter().any(|value| value.fract() != 0.0) {
+                    report.push(Issue::new(
+                        IssueKind::NonIntegerViewBox,
+                        Some(&icon.file_path),
+                        format!("viewBox '{}' has non-integer values", icon.view_box),
+                    ));
+                }
+            }
+            None => report.push(Issue::new(
+                IssueKind::InvalidViewBox,
+                Some(&icon.file_path),
+                format!("viewBox '{}' is not four numbers with a positive size", icon.view_box),
+            )),
+        }
+    }
+}
+
+/// Checks that cropped and full icon sets contain the same icons
+pub fn check_pairs(cropped_icons: &[IconData], full_icons: &[IconData], report: &mut Report) {
+    let cropped: BTreeMap<(&str, &str), &IconData> = cropped_icons
+        .iter()
+        .map(|icon| ((icon.category.as_str(), icon.name.as_str()), icon))
+        .collect();
+    let full: BTreeMap<(&str, &str), &IconData> = full_icons
+        .iter()
+        .map(|icon| ((icon.category.as_str(), icon.name.as_str()), icon))
+        .collect();
+
+    for (key, icon) in &cropped {
+        if !full.contains_key(key) {
+            report.push(Issue::new(
+                IssueKind::MissingFullIcon,
+                Some(&icon.file_path),
+                format!(
+                    "no full icon for icons::{}::{}; it will be generated without a full variant",
+                    key.0, key.1
+                ),
+            ));
+        }
+    }
+    for (key, icon) in &full {
+        if !cropped.contains_key(key) {
+            report.push(Issue::new(
+                IssueKind::MissingCroppedIcon,
+                Some(&icon.file_path),
+                format!("no cropped icon for icons::{}::{}; it will be skipped", key.0, key.1),
+            ));
+        }
+    }
+}
+
+/// Checks that every category module has a matching feature in
+/// `yew-shortcuts/Cargo.toml` that the `fa-all` umbrella enables
+pub fn check_features(icons: &[IconData], cargo_toml: &str, report: &mut Report) {
+    let feature_deps = |feature: &str| {
+        cargo_toml.lines().find_map(|line| {
+            let (name, deps) = line.split_once('=')?;
+            (name.trim() == feature).then(|| deps.trim().to_string())
+        })
+    };
+    let umbrella = feature_deps("fa-all").unwrap_or_default();
+
+    let mut categories: BTreeMap<&str, &str> = BTreeMap::new();
+    for icon in icons {
+        categories.entry(&icon.category).or_insert(&icon.file_path);
+    }
+    for (category, file) in categories {
+        let feature = category_feature(category);
+        if feature_deps(&feature).is_none() {
+            report.push(Issue::new(
+                IssueKind::UndeclaredFeature,
+                Some(file),
+                format!(
+                    "category '{}' needs `{} = []` in the yew-shortcuts features",
+                    category, feature
+                ),
+            ));
+        } else if !umbrella.contains(&format!("\"{}\"", feature)) {
+            report.push(Issue::new(
+                IssueKind::UndeclaredFeature,
+                Some(file),
+                format!("feature `{}` is not enabled by `fa-all`", feature),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(category: &str, name: &str, view_box: &str) -> IconData {
+        IconData {
+            name: name.to_string(),
+            category: category.to_string(),
+            view_box: view_box.to_string(),
+            path_data: "M0 0h10v10H0z".to_string(),
+            comment: String::new(),
+            file_path: format!("{}/{}.svg", category, name.to_lowercase()),
+        }
+    }
+
+    fn kinds(report: &Report) -> Vec<IssueKind> {
+        report.issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn view_boxes() {
+        let icons = [
+            icon("solid", "HOUSE", "0 0 576 512"),
+            icon("solid", "HALF", "0 0 10.5 512"),
+            icon("solid", "FLAT", "0 0 512 0"),
+            icon("solid", "WORDS", "zero zero 1 1"),
+        ];
+        let mut report = Report::default();
+        check_view_boxes(&icons, &mut report);
+
+        assert_eq!(
+            kinds(&report),
+            [IssueKind::NonIntegerViewBox, IssueKind::InvalidViewBox, IssueKind::InvalidViewBox]
+        );
+        assert_eq!(report.issues[0].file.as_deref(), Some("solid/half.svg"));
+    }
+
+    #[test]
+    fn non_integer_view_boxes_only_fail_strict_runs() {
+        let mut report = Report::default();
+        check_view_boxes(&[icon("solid", "HALF", "0 0 10.5 512")], &mut report);
+        assert!(!report.has_errors(false));
+        assert!(report.has_errors(true));
+
+        check_view_boxes(&[icon("solid", "FLAT", "0 0 512 0")], &mut report);
+        assert!(report.has_errors(false));
+    }
+
+    #[test]
+    fn pairs() {
+        let cropped = [icon("solid", "HOUSE", "0 0 576 512"), icon("solid", "GEAR", "0 0 512 512")];
+        let full = [icon("solid", "HOUSE", "0 0 640 640"), icon("brands", "GITHUB", "0 0 640 640")];
+        let mut report = Report::default();
+        check_pairs(&cropped, &full, &mut report);
+
+        assert_eq!(kinds(&report), [IssueKind::MissingFullIcon, IssueKind::MissingCroppedIcon]);
+        assert!(report.issues[0].message.contains("icons::solid::GEAR"));
+        assert!(report.issues[1].message.contains("icons::brands::GITHUB"));
+        assert!(!report.has_errors(false));
+    }
+
+    #[test]
+    fn identifiers() {
+        let mut duplicate = icon("solid", "ARROW_UP", "0 0 1 1");
+        duplicate.file_path = "solid/arrow_up.svg".to_string();
+        let icons = [icon("solid", "ARROW_UP", "0 0 1 1"), duplicate, icon("solid", "CAFÉ", "0 0 1 1")];
+        let mut report = Report::default();
+        check_identifiers(&icons, &mut report);
+
+        let kinds = kinds(&report);
+        assert!(kinds.contains(&IssueKind::DuplicateIdentifier), "{:?}", kinds);
+        assert!(kinds.contains(&IssueKind::InvalidIdentifier), "{:?}", kinds);
+        assert!(report.has_errors(false));
+    }
+
+    #[test]
+    fn features() {
+        let cargo_toml = "[features]\nfa-all = [\"fa-solid\"]\nfa-solid = []\nfa-regular = []\n";
+        let icons = [
+            icon("solid", "HOUSE", "0 0 1 1"),
+            icon("regular", "HEART", "0 0 1 1"),
+            icon("duotone", "GEAR", "0 0 1 1"),
+        ];
+        let mut report = Report::default();
+        check_features(&icons, cargo_toml, &mut report);
+
+        let messages: Vec<&str> = report.issues.iter().map(|issue| issue.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "category 'duotone' needs `fa-duotone = []` in the yew-shortcuts features",
+                "feature `fa-regular` is not enabled by `fa-all`",
+            ]
+        );
+    }
+
+    #[test]
+    fn json_report() {
+        let mut report = Report::default();
+        check_view_boxes(&[icon("solid", "HALF", "0 0 10.5 512")], &mut report);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json(false)).unwrap();
+        assert_eq!(json["passed"], true);
+        assert_eq!(json["warning_count"], 1);
+        assert_eq!(json["counts"]["non_integer_view_box"], 1);
+        assert_eq!(json["issues"][0]["severity"], "warning");
+        assert_eq!(json["issues"][0]["file"], "solid/half.svg");
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json(true)).unwrap();
+        assert_eq!(json["passed"], false);
+        assert_eq!(json["issues"][0]["severity"], "error");
+    }
+}
@@ -0,0 +1,175 @@
+use icon_model::manifest::Manifest;
+use quote::ToTokens;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let manifest_path = Path::new("../yew-shortcuts/src/fontawesome.json");
+
+    // Tell cargo to rerun this build script if the icon manifest changes
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    // The manifest is written by icon-processor next to fontawesome.rs
+    let manifest = Manifest::read(manifest_path).expect("Failed to read fontawesome.json");
+    if manifest.icons.is_empty() {
+        panic!("No icons found in {}", manifest_path.display());
+    }
+
+    // Static table of every free icon, embedded in the server binary
+    let mut table = String::new();
+    table.push_str("/// Auto-generated icon table from build.rs\n");
+    table.push_str("pub static ICONS: &[StaticIcon] = &[\n");
+    for icon in &manifest.icons {
+        table.push_str("    StaticIcon {\n");
+        table.push_str(&format!("        name: {:?},\n", icon.name));
+        table.push_str(&format!("        rust_name: {:?},\n", icon.rust_name));
+        table.push_str(&format!("        category: {:?},\n", icon.category));
+        table.push_str(&format!("        view_box: {:?},\n", icon.view_box));
+        table.push_str(&format!("        min_x: {:?},\n", icon.min_x));
+        table.push_str(&format!("        min_y: {:?},\n", icon.min_y));
+        table.push_str(&format!("        width: {:?},\n", icon.width));
+        table.push_str(&format!("        height: {:?},\n", icon.height));
+        table.push_str(&format!("        aspect_ratio: {:?},\n", icon.aspect_ratio));
+        table.push_str(&format!("        path_data: {:?},\n", icon.path_data));
+        table.push_str(&format!("        import_path: {:?},\n", icon.import_path));
+        table.push_str(&format!("        aliases: &{:?},\n", icon.aliases));
+        table.push_str(&format!("        search_terms: &{:?},\n", icon.search_terms));
+        table.push_str(&format!("        phash: {:?},\n", icon.perceptual_hash()));
+        match &icon.full {
+            Some(full) => table.push_str(&format!(
+                "        full: Some(StaticFull {{ view_box: {:?}, offset_x: {:?}, offset_y: {:?}, scale: {:?} }}),\n",
+                full.view_box, full.offset_x, full.offset_y, full.scale
+            )),
+            None => table.push_str("        full: None,\n"),
+        }
+        table.push_str("    },\n");
+    }
+    table.push_str("];\n");
+
+    // References to the same icons in yew_shortcuts::fontawesome, so the tests
+    // fail to compile if the module lacks one of them
+    let mut module_icons = String::new();
+    module_icons.push_str("/// Auto-generated references into the fontawesome module from build.rs\n");
+    module_icons.push_str("pub static MODULE_ICONS: &[(&str, &yew_shortcuts::fontawesome::Icon)] = &[\n");
+    for icon in &manifest.icons {
+        module_icons.push_str(&format!(
+            "    ({:?}, yew_shortcuts::fontawesome::{}),\n",
+            icon.import_path, icon.import_path
+        ));
+    }
+    module_icons.push_str("];\n");
+
+    let component_path = Path::new("../yew-shortcuts/src/component.rs");
+    println!("cargo:rerun-if-changed={}", component_path.display());
+    let props = component_props(component_path);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("icon_table.rs"), table).expect("Failed to write icon table");
+    fs::write(Path::new(&out_dir).join("module_icons.rs"), module_icons)
+        .expect("Failed to write module icon references");
+    fs::write(Path::new(&out_dir).join("component_props.rs"), props).expect("Failed to write component props");
+}
+
+/// Table of `FontAwesomeSvgProps` fields, so generated code and prop docs follow the real component
+fn component_props(component_path: &Path) -> String {
+    let source = fs::read_to_string(component_path).expect("Failed to read component.rs");
+    let file = syn::parse_file(&source).expect("Failed to parse component.rs");
+    let fields = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Struct(item) if item.ident == "FontAwesomeSvgProps" => Some(&item.fields),
+            _ => None,
+        })
+        .expect("FontAwesomeSvgProps not found in component.rs");
+
+    let mut props = String::new();
+    props.push_str("/// Auto-generated from FontAwesomeSvgProps by build.rs\n");
+    props.push_str("pub static COMPONENT_PROPS: &[StaticProp] = &[\n");
+    for field in fields {
+        let name = field.ident.as_ref().expect("FontAwesomeSvgProps has unnamed fields").to_string();
+        let prop_type = type_name(&field.ty);
+
+        let mut default_value = None;
+        let mut feature = None;
+        let mut description = Vec::new();
+        for attr in &field.attrs {
+            let path = attr.path();
+            if path.is_ident("prop_or") {
+                let expr: syn::Expr = attr.parse_args().expect("Invalid prop_or");
+                default_value = Some(expr.to_token_stream().to_string());
+            } else if path.is_ident("prop_or_else") {
+                let function: syn::Path = attr.parse_args().expect("Invalid prop_or_else");
+                default_value = Some(format!("{}()", function.to_token_stream()).replace(' ', ""));
+            } else if path.is_ident("prop_or_default") {
+                default_value = Some(if prop_type.starts_with("Option<") {
+                    "None".to_string()
+                } else {
+                    format!("{}::default()", prop_type)
+                });
+            } else if path.is_ident("cfg") {
+                // Only `#[cfg(feature = "...")]` gates props today
+                if let Ok(syn::MetaNameValue { path: key, value, .. }) = attr.parse_args() {
+                    if let (true, syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. })) =
+                        (key.is_ident("feature"), value)
+                    {
+                        feature = Some(lit.value());
+                    }
+                }
+            } else if path.is_ident("doc") {
+                if let syn::Meta::NameValue(syn::MetaNameValue {
+                    value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(doc), .. }),
+                    ..
+                }) = &attr.meta
+                {
+                    description.push(doc.value().trim().to_string());
+                }
+            }
+        }
+
+        props.push_str("    StaticProp {\n");
+        props.push_str(&format!("        name: {:?},\n", name));
+        props.push_str(&format!("        prop_type: {:?},\n", prop_type));
+        props.push_str(&format!("        required: {:?},\n", default_value.is_none()));
+        props.push_str(&format!("        default_value: {:?},\n", default_value));
+        props.push_str(&format!("        feature: {:?},\n", feature));
+        props.push_str(&format!("        description: {:?},\n", description.join(" ")));
+        props.push_str("    },\n");
+    }
+    props.push_str("];\n");
+    props
+}
+
+/// A type as written in source, e.g. `Option<Callback<MouseEvent>>` rather than the token stream's spacing
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Reference(reference) => format!(
+            "&{}{}{}",
+            reference.lifetime.as_ref().map(|lifetime| format!("{} ", lifetime)).unwrap_or_default(),
+            if reference.mutability.is_some() { "mut " } else { "" },
+            type_name(&reference.elem)
+        ),
+        syn::Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => {
+                    let arguments: Vec<String> = arguments
+                        .args
+                        .iter()
+                        .map(|argument| match argument {
+                            syn::GenericArgument::Type(ty) => type_name(ty),
+                            other => other.to_token_stream().to_string(),
+                        })
+                        .collect();
+                    format!("{}<{}>", segment.ident, arguments.join(", "))
+                }
+                _ => segment.ident.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("::"),
+        other => other.to_token_stream().to_string(),
+    }
+}
@@ -1,21 +1,84 @@
-use anyhow::Result;
-use regex::Regex;
+use anyhow::{Context, Result};
+use icon_model::manifest::{Manifest, ManifestIcon};
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::semantic::SemanticIndex;
+use crate::types::{FullInfo, IconInfo};
+
+/// An icon of the free set, embedded at compile time
+pub struct StaticIcon {
+    pub name: &'static str,
+    pub rust_name: &'static str,
+    pub category: &'static str,
+    pub view_box: &'static str,
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub aspect_ratio: f32,
+    pub path_data: &'static str,
+    pub import_path: &'static str,
+    pub aliases: &'static [&'static str],
+    pub search_terms: &'static [&'static str],
+    pub phash: Option<u64>,
+    pub full: Option<StaticFull>,
+}
+
+/// Placement of an embedded icon in its full-size viewBox
+pub struct StaticFull {
+    pub view_box: &'static str,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub scale: f32,
+}
 
-use crate::types::IconInfo;
+// Static table generated by build.rs from fontawesome.json
+mod table {
+    use super::{StaticFull, StaticIcon};
+    include!(concat!(env!("OUT_DIR"), "/icon_table.rs"));
+}
+
+/// Environment variable naming the Pro manifest to serve
+pub const PRO_MANIFEST_ENV: &str = "YEW_SHORTCUTS_PRO_MANIFEST";
 
-// Include the generated fontawesome.rs file content at compile time
-const FONTAWESOME_RS: &str = include_str!("../../yew-shortcuts/src/fontawesome.rs");
+/// Where `icon-processor --pro` writes the Pro manifest, relative to the workspace root
+const PRO_MANIFEST: &str = "yew-shortcuts/src/fontawesome/pro/fontawesome-pro.json";
+
+/// Pro styles are generated locally and never committed, so they are found at
+/// startup: the manifest named by `YEW_SHORTCUTS_PRO_MANIFEST`, or the
+/// generated one when the server runs from the workspace root
+fn pro_manifest() -> Option<PathBuf> {
+    match std::env::var_os(PRO_MANIFEST_ENV) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(PathBuf::from(PRO_MANIFEST)).filter(|path| path.exists()),
+    }
+}
 
 #[derive(Clone)]
 pub struct IconIndex {
     pub icons: Vec<IconInfo>,
     pub by_name_category: HashMap<(String, String), usize>, // (name, category) -> index
+    /// Keyword index over names, aliases and search terms
+    pub semantic: SemanticIndex,
 }
 
 impl IconIndex {
-    pub fn load() -> Result<Self> {
-        let icons = parse_fontawesome_file()?;
+    /// Loads the free icons plus the local Pro manifest, if found, and any extra manifests
+    pub fn load(extra_manifests: &[PathBuf]) -> Result<Self> {
+        let mut icons = builtin_icons();
+        
+        let pro_manifest = pro_manifest();
+        let manifests = pro_manifest
+            .as_deref()
+            .into_iter()
+            .chain(extra_manifests.iter().map(PathBuf::as_path));
+        for path in manifests {
+            let manifest = Manifest::read(path)
+                .with_context(|| format!("Failed to read icon manifest {}", path.display()))?;
+            tracing::info!("Loaded {} icons from {}", manifest.icons.len(), path.display());
+            icons.extend(manifest.icons.into_iter().map(icon_info));
+        }
         
         // Build lookup index
         let mut by_name_category = HashMap::new();
@@ -23,9 +86,12 @@ impl IconIndex {
             by_name_category.insert((icon.name.clone(), icon.category.clone()), idx);
         }
 
+        let semantic = SemanticIndex::build(&icons);
+
         Ok(Self {
             icons,
             by_name_category,
+            semantic,
         })
     }
 
@@ -35,6 +101,7 @@ impl IconIndex {
             .and_then(|&idx| self.icons.get(idx))
     }
 
+    /// Category names with their icon counts, in alphabetical order
     pub fn categories(&self) -> Vec<(&str, usize)> {
         let mut category_counts: HashMap<&str, usize> = HashMap::new();
         for icon in &self.icons {
@@ -46,121 +113,107 @@ impl IconIndex {
     }
 }
 
-fn parse_fontawesome_file() -> Result<Vec<IconInfo>> {
-    // Use the state machine approach directly as it's more reliable
-    parse_with_state_machine()
+/// The free icons compiled into the server
+fn builtin_icons() -> Vec<IconInfo> {
+    table::ICONS
+        .iter()
+        .map(|icon| IconInfo {
+            name: icon.name.to_string(),
+            rust_name: icon.rust_name.to_string(),
+            category: icon.category.to_string(),
+            view_box: icon.view_box.to_string(),
+            min_x: icon.min_x,
+            min_y: icon.min_y,
+            width: icon.width,
+            height: icon.height,
+            aspect_ratio: icon.aspect_ratio,
+            path_data: icon.path_data.to_string(),
+            import_path: icon.import_path.to_string(),
+            aliases: icon.aliases.iter().map(|alias| alias.to_string()).collect(),
+            search_terms: icon.search_terms.iter().map(|term| term.to_string()).collect(),
+            phash: icon.phash,
+            full: icon.full.as_ref().map(|full| FullInfo {
+                view_box: full.view_box.to_string(),
+                offset_x: full.offset_x,
+                offset_y: full.offset_y,
+                scale: full.scale,
+            }),
+        })
+        .collect()
 }
 
-fn parse_with_state_machine() -> Result<Vec<IconInfo>> {
-    let mut icons = Vec::new();
-    let lines: Vec<&str> = FONTAWESOME_RS.lines().collect();
-    let mut i = 0;
-    let mut current_module = String::new();
+fn icon_info(icon: ManifestIcon) -> IconInfo {
+    let phash = icon.perceptual_hash();
+    IconInfo {
+        name: icon.name,
+        rust_name: icon.rust_name,
+        category: icon.category,
+        view_box: icon.view_box,
+        min_x: icon.min_x,
+        min_y: icon.min_y,
+        width: icon.width,
+        height: icon.height,
+        aspect_ratio: icon.aspect_ratio,
+        path_data: icon.path_data,
+        import_path: icon.import_path,
+        aliases: icon.aliases,
+        search_terms: icon.search_terms,
+        phash,
+        full: icon.full.map(|full| FullInfo {
+            view_box: full.view_box,
+            offset_x: full.offset_x,
+            offset_y: full.offset_y,
+            scale: full.scale,
+        }),
+    }
+}
 
-    while i < lines.len() {
-        let line = lines[i];
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::path::Path;
 
-        // Check for module declaration
-        if line.contains("pub mod") && line.contains("{") {
-            if let Some(caps) = Regex::new(r"pub mod (\w+)")?.captures(line) {
-                current_module = caps[1].to_string();
-            }
-        }
+    mod module {
+        include!(concat!(env!("OUT_DIR"), "/module_icons.rs"));
+    }
 
-        // Check for icon constant  
-        if line.contains("pub const") && line.contains(": &Icon") && !current_module.is_empty() {
-            // Extract icon name
-            let icon_name = if let Some(caps) = Regex::new(r"pub const (\w+):")?.captures(line) {
-                caps[1].to_string()
-            } else {
-                i += 1;
-                continue;
-            };
-
-            // Look for viewBox in next few lines
-            let mut view_box = String::new();
-            let mut path_data = String::new();
-            
-            for j in i..std::cmp::min(i + 20, lines.len()) {
-                if lines[j].contains("view_box:") {
-                    if let Some(caps) = Regex::new(r#"view_box: "([^"]+)""#)?.captures(lines[j]) {
-                        view_box = caps[1].to_string();
-                    }
-                }
-                if lines[j].contains("d: r#") {
-                    // Path data might span multiple lines
-                    // Find start of raw string
-                    if let Some(start_idx) = lines[j].find("r#\"") {
-                        let start_line = &lines[j][start_idx + 3..];
-                        // Check if it ends on same line
-                        if let Some(end_idx) = start_line.find("\"#") {
-                            path_data = start_line[..end_idx].to_string();
-                        } else {
-                            // Multi-line path data
-                            let mut path_parts = vec![start_line];
-                            for k in (j + 1)..std::cmp::min(j + 10, lines.len()) {
-                                if let Some(end_idx) = lines[k].find("\"#") {
-                                    path_parts.push(&lines[k][..end_idx]);
-                                    path_data = path_parts.join("");
-                                    break;
-                                } else {
-                                    path_parts.push(lines[k].trim());
-                                }
-                            }
-                        }
-                    }
-                }
-                
-                if !view_box.is_empty() && !path_data.is_empty() {
-                    break;
-                }
-            }
-
-            if !view_box.is_empty() && !path_data.is_empty() {
-                let (width, height) = parse_view_box(&view_box)?;
-                let name = rust_name_to_kebab_case(&icon_name);
-                let import_path = format!("icons::{}::{}", current_module, icon_name);
-
-                icons.push(IconInfo {
-                    name,
-                    rust_name: icon_name,
-                    category: current_module.clone(),
-                    width,
-                    height,
-                    view_box,
-                    path_data,
-                    import_path,
-                });
-            }
+    #[test]
+    fn index_matches_fontawesome_module() {
+        let index = builtin_icons();
+        assert_eq!(index.len(), module::MODULE_ICONS.len());
+
+        // Every indexed icon resolves to a constant with the same data
+        for (info, (import_path, icon)) in index.iter().zip(module::MODULE_ICONS) {
+            assert_eq!(&info.import_path, import_path);
+            assert_eq!(info.category, icon.category, "{}", import_path);
+            assert_eq!(info.name, icon.name, "{}", import_path);
+            assert_eq!(info.view_box, icon.cropped.view_box, "{}", import_path);
+            assert_eq!(info.path_data, icon.cropped.d, "{}", import_path);
         }
 
-        i += 1;
+        // And the manifest describes no icon the index lacks
+        let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../yew-shortcuts/src/fontawesome.json");
+        let manifest = Manifest::read(&manifest_path).expect("Failed to read fontawesome.json");
+        let described: BTreeSet<&str> = manifest.icons.iter().map(|icon| icon.import_path.as_str()).collect();
+        let indexed: BTreeSet<&str> = index.iter().map(|icon| icon.import_path.as_str()).collect();
+        assert_eq!(described, indexed);
     }
 
-    Ok(icons)
-}
-
-fn parse_view_box(view_box: &str) -> Result<(u32, u32)> {
-    let parts: Vec<&str> = view_box.split_whitespace().collect();
-    if parts.len() != 4 {
-        anyhow::bail!("Invalid viewBox format: {}", view_box);
+    #[test]
+    fn loads_manifests_without_full_icons() {
+        // A Pro download without svgs-full yields a manifest with no full placements
+        let manifest = Manifest::new(
+            "pro license",
+            vec![ManifestIcon::new("duotone", "HOUSE", "0 0 576 512", "M0 0h576v512H0z").unwrap()],
+        );
+        let file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        std::fs::write(file.path(), manifest.to_json()).unwrap();
+
+        let index = IconIndex::load(&[file.path().to_path_buf()]).unwrap();
+        let house = index.find_icon("house", "duotone").unwrap();
+        assert!(house.full.is_none());
+        assert_eq!(house.import_path, "icons::duotone::HOUSE");
+        assert!(index.categories().contains(&("duotone", 1)));
     }
-    
-    let width = parts[2].parse::<u32>()?;
-    let height = parts[3].parse::<u32>()?;
-    
-    Ok((width, height))
 }
-
-fn rust_name_to_kebab_case(rust_name: &str) -> String {
-    rust_name
-        .chars()
-        .map(|c| {
-            if c == '_' {
-                '-'
-            } else {
-                c.to_ascii_lowercase()
-            }
-        })
-        .collect()
-}
\ No newline at end of file
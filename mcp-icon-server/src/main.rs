@@ -1,14 +1,44 @@
-use anyhow::Result;
-use rmcp::{ServiceExt, transport::stdio};
+use anyhow::{Context, Result};
+use rmcp::{
+    ServiceExt,
+    transport::{
+        stdio,
+        streamable_http_server::{StreamableHttpService, session::local::LocalSessionManager},
+    },
+};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
 use tracing_subscriber::EnvFilter;
 
+mod bitmap_font;
 mod icon_index;
+mod render;
+mod resources;
+mod semantic;
 mod server;
 mod tools;
 mod types;
 
 use server::IconServer;
 
+/// Address the HTTP transport listens on when no `--bind` is given
+const DEFAULT_BIND: &str = "127.0.0.1:8080";
+
+/// Path the MCP endpoint is served under
+const HTTP_PATH: &str = "/mcp";
+
+/// How long open connections, such as SSE streams, get to finish after a shutdown signal
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+enum Transport {
+    Stdio,
+    Http,
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize tracing for debugging (logs to stderr)
@@ -22,18 +52,172 @@ async fn main() -> Result<()> {
 
     tracing::info!("Starting MCP Icon Server for yew-shortcuts");
 
-    // Create the server instance
-    let service = IconServer::new()?;
+    // Extra icon manifests, e.g. a Pro set generated elsewhere
+    let mut extra_manifests = Vec::new();
+    let mut audit_path = None;
+    let mut json = false;
+    let mut transport = Transport::Stdio;
+    let mut bind = None;
+    let mut audit_root = None;
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().is_some_and(|arg| arg == "audit-icon-usage") {
+        args.next();
+        audit_path = Some(args.next().ok_or_else(|| anyhow::anyhow!("audit-icon-usage needs a project directory"))?);
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--manifest" => match args.next() {
+                Some(path) => extra_manifests.push(PathBuf::from(path)),
+                None => anyhow::bail!("--manifest needs a path"),
+            },
+            "--json" if audit_path.is_some() => json = true,
+            "--transport" => match args.next().as_deref() {
+                Some("stdio") => transport = Transport::Stdio,
+                Some("http") => transport = Transport::Http,
+                Some(other) => anyhow::bail!("Unknown transport '{}', expected stdio or http", other),
+                None => anyhow::bail!("--transport needs stdio or http"),
+            },
+            "--bind" => match args.next() {
+                Some(addr) => {
+                    let addr = addr.parse::<SocketAddr>().with_context(|| format!("Invalid --bind address '{}'", addr))?;
+                    bind = Some(addr);
+                }
+                None => anyhow::bail!("--bind needs an address such as {}", DEFAULT_BIND),
+            },
+            "--audit-root" => match args.next() {
+                Some(path) => audit_root = Some(PathBuf::from(path)),
+                None => anyhow::bail!("--audit-root needs a directory"),
+            },
+            "-h" | "--help" => {
+                eprintln!("Usage: mcp-icon-server [--transport stdio|http] [--bind <addr>] [--audit-root <dir>] [--manifest <file>]...");
+                eprintln!("       mcp-icon-server audit-icon-usage <dir> [--json] [--manifest <file>]...");
+                eprintln!();
+                eprintln!("  --transport <kind> Serve over stdio (default) or streamable HTTP at {}", HTTP_PATH);
+                eprintln!("  --bind <addr>      Address the HTTP transport listens on (default {}); it has no authentication", DEFAULT_BIND);
+                eprintln!("  --manifest <file>  Also serve the icons of a fontawesome.json-style manifest");
+                eprintln!("  --audit-root <dir> Directory audit_icon_usage may scan below; over stdio the working directory by default,");
+                eprintln!("                     over HTTP the tool is only offered when this is given");
+                eprintln!();
+                eprintln!("Pro icons are read from ${} or, from the workspace root, the manifest `icon-processor --pro` generates.", icon_index::PRO_MANIFEST_ENV);
+                eprintln!("  audit-icon-usage   Report the icons a Rust project uses and exit, failing on unknown icons");
+                eprintln!("  --json             Print the audit as JSON");
+                return Ok(());
+            }
+            other => anyhow::bail!("Unknown argument '{}'", other),
+        }
+    }
+
+    if let Some(path) = audit_path {
+        let index = icon_index::IconIndex::load(&extra_manifests)?;
+        let dir = tools::audit::resolve_project_dir(Path::new(&path), ".")?;
+        let result = tools::audit::audit_icon_usage(&index, &dir)?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        } else {
+            print!("{}", tools::audit::format_report(&result));
+        }
+        if !result.unknown_icons.is_empty() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    // Serve over stdio transport for Claude Code
-    let transport = stdio();
-    
+    if bind.is_some() && matches!(transport, Transport::Stdio) {
+        anyhow::bail!("--bind only applies to --transport http");
+    }
+
+    match transport {
+        Transport::Stdio => {
+            // The stdio client is whoever started the server, so it may audit the working directory
+            let audit_root = match audit_root {
+                Some(root) => root,
+                None => std::env::current_dir().context("Failed to read the working directory")?,
+            };
+            serve_stdio(IconServer::new(&extra_manifests, Some(audit_root))?).await
+        }
+        Transport::Http => {
+            let addr = match bind {
+                Some(addr) => addr,
+                None => DEFAULT_BIND.parse()?,
+            };
+            // There is no authentication: anyone who can reach the port can call every tool
+            if !addr.ip().is_loopback() {
+                eprintln!("Warning: serving on {} without authentication; anyone who can reach it can use the server", addr);
+            }
+            serve_http(IconServer::new(&extra_manifests, audit_root)?, addr).await
+        }
+    }
+}
+
+/// Serves a single client, such as Claude Code, over stdin and stdout
+async fn serve_stdio(service: IconServer) -> Result<()> {
     tracing::info!("Server initialized, waiting for connections...");
-    
-    let server = service.serve(transport).await?;
-    
+
+    let server = service.serve(stdio()).await?;
+
     // Wait for the server to complete
     server.waiting().await?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Serves any number of clients over streamable HTTP (with SSE responses) until interrupted
+async fn serve_http(service: IconServer, addr: SocketAddr) -> Result<()> {
+    // Each session gets its own handler; clones share the loaded index
+    let mcp = StreamableHttpService::new(
+        move || Ok(service.clone()),
+        LocalSessionManager::default().into(),
+        Default::default(),
+    );
+    let router = axum::Router::new().nest_service(HTTP_PATH, mcp);
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind {}", addr))?;
+    let shutting_down = Arc::new(Notify::new());
+    let shutdown = shutdown_signal(shutting_down.clone())?;
+
+    // Printed even when logging is off, so scripts binding port 0 can find the server
+    eprintln!("Listening on http://{}{}", listener.local_addr()?, HTTP_PATH);
+
+    let server = async { axum::serve(listener, router).with_graceful_shutdown(shutdown).await };
+    let grace_expired = async {
+        shutting_down.notified().await;
+        tokio::time::sleep(SHUTDOWN_GRACE).await;
+    };
+
+    tokio::select! {
+        result = server => result?,
+        _ = grace_expired => tracing::warn!("Closing connections still open after {:?}", SHUTDOWN_GRACE),
+    }
+
+    tracing::info!("Server shut down");
+    Ok(())
+}
+
+/// Resolves on Ctrl-C or, on Unix, SIGTERM, letting in-flight requests finish
+///
+/// The handlers are installed right away, so signals arriving before the server runs are not lost.
+fn shutdown_signal(shutting_down: Arc<Notify>) -> Result<impl Future<Output = ()>> {
+    #[cfg(unix)]
+    let (mut interrupt, mut terminate) = {
+        use tokio::signal::unix::{signal, SignalKind};
+        (signal(SignalKind::interrupt())?, signal(SignalKind::terminate())?)
+    };
+
+    Ok(async move {
+        #[cfg(unix)]
+        tokio::select! {
+            _ = interrupt.recv() => {},
+            _ = terminate.recv() => {},
+        }
+        #[cfg(not(unix))]
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+
+        tracing::info!("Shutting down");
+        shutting_down.notify_one();
+    })
+}
@@ -0,0 +1,49 @@
+use anyhow::{anyhow, Context, Result};
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{Options, Tree};
+
+use crate::types::IconInfo;
+
+/// Fill used when rasterizing, since images have no `currentColor` to inherit
+pub const RASTER_FILL: &str = "#000000";
+
+/// A standalone SVG document of the cropped icon
+pub fn svg_document(icon: &IconInfo, fill: &str) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{}"><path fill="{}" d="{}"/></svg>"#,
+        icon.view_box, fill, icon.path_data
+    )
+}
+
+/// A standalone SVG document of the full-size variant, if the icon has one
+pub fn full_svg_document(icon: &IconInfo, fill: &str) -> Option<String> {
+    let full = icon.full.as_ref()?;
+    Some(format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{}"><path fill="{}" transform="translate({} {}) scale({})" d="{}"/></svg>"#,
+        full.view_box, fill, full.offset_x, full.offset_y, full.scale, icon.path_data
+    ))
+}
+
+/// Renders an SVG document `height` pixels tall, as wide as its aspect ratio requires
+pub fn rasterize_svg(svg: &str, height: u32) -> Result<Pixmap> {
+    let tree = Tree::from_str(svg, &Options::default()).context("Failed to parse SVG")?;
+    let size = tree.size();
+
+    let width = (height as f32 * size.width() / size.height()).ceil().max(1.0) as u32;
+    let mut pixmap = Pixmap::new(width, height)
+        .ok_or_else(|| anyhow!("Failed to allocate a {}x{} pixmap", width, height))?;
+
+    // Uniform scale; rounding the width up leaves at most a pixel of padding
+    let scale = height as f32 / size.height();
+    resvg::render(&tree, Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+
+    Ok(pixmap)
+}
+
+/// PNG bytes of the cropped icon in black, `height` pixels tall
+pub fn png(icon: &IconInfo, height: u32) -> Result<Vec<u8>> {
+    rasterize_svg(&svg_document(icon, RASTER_FILL), height)
+        .with_context(|| format!("Failed to render {}/{}", icon.category, icon.name))?
+        .encode_png()
+        .context("Failed to encode PNG")
+}
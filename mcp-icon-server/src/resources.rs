@@ -0,0 +1,259 @@
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::icon_index::IconIndex;
+use crate::render;
+use crate::types::IconInfo;
+
+/// Resource template of the SVG of an icon
+pub const SVG_TEMPLATE: &str = "fa://{category}/{name}.svg";
+
+/// Resource template of a rasterized icon; `?size=<px>` sets its height
+pub const PNG_TEMPLATE: &str = "fa://{category}/{name}.png";
+
+/// Icons per `resources/list` page; each icon is listed as SVG and PNG
+pub const PAGE_SIZE: usize = 250;
+
+/// Height of PNG resources without a `size` query
+pub const DEFAULT_PNG_SIZE: u32 = 128;
+
+/// Largest PNG height a client can request
+pub const MAX_PNG_SIZE: u32 = 1024;
+
+const SCHEME: &str = "fa://";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Svg,
+    /// Height in pixels
+    Png(u32),
+}
+
+impl Format {
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Format::Svg => "image/svg+xml",
+            Format::Png(_) => "image/png",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Svg => "svg",
+            Format::Png(_) => "png",
+        }
+    }
+}
+
+/// A listed resource
+#[derive(Debug, Clone)]
+pub struct ResourceEntry {
+    pub uri: String,
+    pub name: String,
+    pub description: String,
+    pub mime_type: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourcePage {
+    pub resources: Vec<ResourceEntry>,
+    /// Pass as `cursor` to get the next page; absent on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// The contents of a read resource
+#[derive(Debug, Clone)]
+pub enum ResourceBody {
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceData {
+    pub mime_type: &'static str,
+    pub body: ResourceBody,
+}
+
+pub fn uri(icon: &IconInfo, format: Format) -> String {
+    format!("{}{}/{}.{}", SCHEME, icon.category, icon.name, format.extension())
+}
+
+/// One page of icon resources, in index order
+pub fn list(index: &IconIndex, cursor: Option<&str>) -> Result<ResourcePage> {
+    // The cursor is the position of the next icon
+    let offset: usize = match cursor {
+        Some(cursor) => cursor
+            .parse()
+            .with_context(|| format!("Invalid cursor '{}'", cursor))?,
+        None => 0,
+    };
+    let end = offset.saturating_add(PAGE_SIZE).min(index.icons.len());
+    let next_cursor = (end < index.icons.len()).then(|| end.to_string());
+
+    let resources = index
+        .icons
+        .get(offset..end)
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|icon| {
+            [Format::Svg, Format::Png(DEFAULT_PNG_SIZE)].map(|format| ResourceEntry {
+                uri: uri(icon, format),
+                name: format!("{}/{}.{}", icon.category, icon.name, format.extension()),
+                description: format!("{} icon {} ({})", icon.category, icon.name, icon.import_path),
+                mime_type: format.mime_type(),
+            })
+        })
+        .collect();
+
+    Ok(ResourcePage {
+        resources,
+        next_cursor,
+    })
+}
+
+/// Reads `fa://{category}/{name}.svg` or `fa://{category}/{name}.png[?size=<px>]`
+pub fn read(index: &IconIndex, uri: &str) -> Result<ResourceData> {
+    let (icon, format) = parse_uri(index, uri)?;
+    let body = match format {
+        Format::Svg => ResourceBody::Text(render::svg_document(icon, "currentColor")),
+        Format::Png(size) => ResourceBody::Blob(render::png(icon, size)?),
+    };
+    Ok(ResourceData {
+        mime_type: format.mime_type(),
+        body,
+    })
+}
+
+fn parse_uri<'a>(index: &'a IconIndex, uri: &str) -> Result<(&'a IconInfo, Format)> {
+    let rest = uri
+        .strip_prefix(SCHEME)
+        .ok_or_else(|| anyhow!("Resource URI '{}' does not start with {}", uri, SCHEME))?;
+    let (path, query) = match rest.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (rest, None),
+    };
+    let (category, file) = path
+        .split_once('/')
+        .ok_or_else(|| anyhow!("Resource URI '{}' is not fa://{{category}}/{{name}}.{{svg,png}}", uri))?;
+
+    let (name, format) = if let Some(name) = file.strip_suffix(".svg") {
+        if query.is_some() {
+            bail!("SVG resources take no query: '{}'", uri);
+        }
+        (name, Format::Svg)
+    } else if let Some(name) = file.strip_suffix(".png") {
+        (name, Format::Png(png_size(query)?))
+    } else {
+        bail!("Resource URI '{}' must end in .svg or .png", uri);
+    };
+
+    let icon = index
+        .find_icon(name, category)
+        .ok_or_else(|| anyhow!("Icon '{}' not found in category '{}'", name, category))?;
+    Ok((icon, format))
+}
+
+fn png_size(query: Option<&str>) -> Result<u32> {
+    let Some(query) = query else {
+        return Ok(DEFAULT_PNG_SIZE);
+    };
+    let mut size = DEFAULT_PNG_SIZE;
+    for pair in query.split('&') {
+        match pair.split_once('=') {
+            Some(("size", value)) => {
+                size = value
+                    .parse()
+                    .with_context(|| format!("Invalid PNG size '{}'", value))?;
+            }
+            _ => bail!("Unknown PNG resource parameter '{}'", pair),
+        }
+    }
+    if !(1..=MAX_PNG_SIZE).contains(&size) {
+        bail!("PNG size must be between 1 and {}, got {}", MAX_PNG_SIZE, size);
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(uri: &str) -> Result<Format> {
+        let index = IconIndex::load(&[]).unwrap();
+        parse_uri(&index, uri).map(|(_, format)| format)
+    }
+
+    fn error(uri: &str) -> String {
+        parse(uri).expect_err(uri).to_string()
+    }
+
+    #[test]
+    fn parses_svg_and_png_uris() {
+        assert_eq!(parse("fa://solid/house.svg").unwrap(), Format::Svg);
+        assert_eq!(parse("fa://solid/house.png").unwrap(), Format::Png(DEFAULT_PNG_SIZE));
+        assert_eq!(parse("fa://solid/house.png?size=1").unwrap(), Format::Png(1));
+        assert_eq!(parse(&format!("fa://solid/house.png?size={}", MAX_PNG_SIZE)).unwrap(), Format::Png(MAX_PNG_SIZE));
+    }
+
+    #[test]
+    fn rejects_out_of_range_and_malformed_sizes() {
+        for size in ["0", &(MAX_PNG_SIZE + 1).to_string()] {
+            assert!(error(&format!("fa://solid/house.png?size={}", size)).contains("PNG size must be between 1 and"));
+        }
+        for size in ["-1", "abc", "", "1.5"] {
+            assert!(error(&format!("fa://solid/house.png?size={}", size)).contains("Invalid PNG size"));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_query_keys() {
+        assert!(error("fa://solid/house.png?color=red").contains("Unknown PNG resource parameter 'color=red'"));
+        assert!(error("fa://solid/house.png?size=64&x=1").contains("Unknown PNG resource parameter 'x=1'"));
+        assert!(error("fa://solid/house.png?size").contains("Unknown PNG resource parameter 'size'"));
+    }
+
+    #[test]
+    fn rejects_malformed_uris() {
+        assert!(error("fa://solid/house.svg?x").contains("SVG resources take no query"));
+        assert!(error("fa://house.svg").contains("is not fa://{category}/{name}.{svg,png}"));
+        assert!(error("fa://solid/house.gif").contains("must end in .svg or .png"));
+        assert!(error("https://solid/house.svg").contains("does not start with fa://"));
+        assert!(error("fa://solid/no-such-icon.svg").contains("not found in category 'solid'"));
+    }
+
+    #[test]
+    fn pages_cover_every_icon_once() {
+        let index = IconIndex::load(&[]).unwrap();
+        let mut uris = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = list(&index, cursor.as_deref()).unwrap();
+            uris.extend(page.resources.iter().map(|resource| resource.uri.clone()));
+            match page.next_cursor {
+                Some(next) => {
+                    // Only the last page is short
+                    assert_eq!(page.resources.len(), 2 * PAGE_SIZE);
+                    cursor = Some(next);
+                }
+                None => break,
+            }
+        }
+
+        let expected: Vec<String> = index
+            .icons
+            .iter()
+            .flat_map(|icon| [uri(icon, Format::Svg), uri(icon, Format::Png(DEFAULT_PNG_SIZE))])
+            .collect();
+        assert_eq!(uris, expected);
+    }
+
+    #[test]
+    fn cursors_past_the_end_give_an_empty_last_page() {
+        let index = IconIndex::load(&[]).unwrap();
+        for cursor in [index.icons.len().to_string(), usize::MAX.to_string()] {
+            let page = list(&index, Some(&cursor)).unwrap();
+            assert!(page.resources.is_empty());
+            assert!(page.next_cursor.is_none());
+        }
+        assert!(list(&index, Some("abc")).is_err());
+    }
+}
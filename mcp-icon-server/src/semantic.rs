@@ -0,0 +1,394 @@
+//! Keyword search over icon names, FontAwesome aliases and search terms
+//!
+//! Fuzzy matching only looks at the icon name, so "delete" never finds
+//! `trash`. This index tokenizes the name, aliases and search terms of every
+//! icon, expands the query through a small synonym table and ranks icons with
+//! BM25, remembering which field and word produced each hit.
+
+use std::collections::HashMap;
+
+use crate::types::IconInfo;
+
+/// BM25 term frequency saturation
+const K1: f32 = 1.2;
+
+/// BM25 document length normalization
+const B: f32 = 0.75;
+
+/// Query weight of the first word a query word maps to in the synonym table;
+/// later synonyms get a share of it by position
+const SYNONYM_WEIGHT: f32 = 0.6;
+
+/// Query words mapped to the words FontAwesome uses for the same idea, best match first
+const SYNONYMS: &[(&str, &[&str])] = &[
+    ("account", &["user", "person"]),
+    ("add", &["plus"]),
+    ("alert", &["bell", "exclamation", "triangle"]),
+    ("analytics", &["chart", "pie"]),
+    ("attach", &["paperclip"]),
+    ("attachment", &["paperclip"]),
+    ("back", &["arrow", "left", "chevron"]),
+    ("bin", &["trash"]),
+    ("buy", &["cart", "bag"]),
+    ("call", &["phone"]),
+    ("cancel", &["xmark", "ban"]),
+    ("chat", &["comment", "message"]),
+    ("close", &["xmark"]),
+    ("config", &["gear", "sliders", "wrench"]),
+    ("configuration", &["gear", "sliders", "wrench"]),
+    ("confirm", &["check"]),
+    ("console", &["terminal"]),
+    ("create", &["plus", "pen"]),
+    ("date", &["calendar"]),
+    ("debug", &["bug"]),
+    ("delete", &["trash", "xmark", "eraser", "minus"]),
+    ("delivery", &["truck"]),
+    ("directory", &["folder"]),
+    ("document", &["file"]),
+    ("done", &["check"]),
+    ("duplicate", &["copy", "clone"]),
+    ("edit", &["pen", "pencil"]),
+    ("email", &["envelope", "at"]),
+    ("error", &["exclamation", "xmark", "bug"]),
+    ("favorite", &["star", "heart"]),
+    ("find", &["magnifying", "glass"]),
+    ("fullscreen", &["expand", "maximize"]),
+    ("garbage", &["trash"]),
+    ("global", &["globe", "earth"]),
+    ("hamburger", &["bars"]),
+    ("help", &["question", "circle"]),
+    ("hide", &["eye", "slash"]),
+    ("home", &["house"]),
+    ("idea", &["lightbulb"]),
+    ("internet", &["globe", "wifi"]),
+    ("label", &["tag"]),
+    ("like", &["heart", "thumbs"]),
+    ("loading", &["spinner", "notch"]),
+    ("location", &["map", "pin"]),
+    ("login", &["bracket", "key"]),
+    ("logout", &["bracket", "door"]),
+    ("love", &["heart"]),
+    ("mail", &["envelope"]),
+    ("menu", &["bars", "ellipsis"]),
+    ("message", &["envelope", "comment"]),
+    ("money", &["dollar", "coins", "wallet"]),
+    ("new", &["plus"]),
+    ("next", &["arrow", "right", "chevron"]),
+    ("notification", &["bell"]),
+    ("ok", &["check"]),
+    ("options", &["gear", "sliders", "ellipsis"]),
+    ("package", &["box"]),
+    ("password", &["key", "lock"]),
+    ("payment", &["credit", "card", "money"]),
+    ("people", &["users", "user"]),
+    ("photo", &["image", "camera"]),
+    ("picture", &["image"]),
+    ("preferences", &["gear", "sliders"]),
+    ("previous", &["arrow", "left", "chevron"]),
+    ("printer", &["print"]),
+    ("profile", &["user", "person", "id"]),
+    ("refresh", &["rotate", "arrows"]),
+    ("reload", &["rotate"]),
+    ("remove", &["trash", "xmark", "minus"]),
+    ("save", &["floppy", "disk"]),
+    ("schedule", &["calendar", "clock"]),
+    ("search", &["magnifying", "glass"]),
+    ("security", &["shield", "lock"]),
+    ("send", &["paper", "plane"]),
+    ("settings", &["gear", "sliders", "wrench"]),
+    ("shipping", &["truck", "box"]),
+    ("shop", &["cart", "store", "bag"]),
+    ("show", &["eye"]),
+    ("statistics", &["chart"]),
+    ("stats", &["chart"]),
+    ("success", &["check"]),
+    ("sync", &["rotate", "arrows"]),
+    ("team", &["users", "people"]),
+    ("theme", &["palette", "moon", "sun"]),
+    ("time", &["clock"]),
+    ("translate", &["language"]),
+    ("view", &["eye"]),
+    ("visible", &["eye"]),
+    ("wait", &["hourglass", "spinner"]),
+    ("warning", &["exclamation", "triangle"]),
+    ("wireless", &["wifi"]),
+    ("world", &["globe", "earth"]),
+    ("zoom", &["magnifying"]),
+];
+
+/// Where in an icon a word was found; names count most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Field {
+    Name,
+    Alias,
+    Term,
+}
+
+impl Field {
+    fn weight(self) -> f32 {
+        match self {
+            Field::Name => 3.0,
+            Field::Alias => 2.0,
+            Field::Term => 1.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Field::Name => "name",
+            Field::Alias => "alias",
+            Field::Term => "search term",
+        }
+    }
+}
+
+/// One indexed word in one icon
+#[derive(Debug, Clone)]
+struct Posting {
+    icon: usize,
+    /// Field-weighted number of occurrences
+    frequency: f32,
+    /// The most important field the word appeared in
+    field: Field,
+    /// The name, alias or search term the word came from
+    source: String,
+}
+
+/// A scored icon with the reasons it matched
+#[derive(Debug, Clone, Default)]
+pub struct SemanticMatch {
+    pub score: f32,
+    pub explanation: Vec<String>,
+}
+
+/// BM25 index over the icons of an [`IconIndex`](crate::icon_index::IconIndex), in the same order
+#[derive(Debug, Clone, Default)]
+pub struct SemanticIndex {
+    postings: HashMap<String, Vec<Posting>>,
+    lengths: Vec<f32>,
+    average_length: f32,
+}
+
+impl SemanticIndex {
+    pub fn build(icons: &[IconInfo]) -> Self {
+        let mut postings: HashMap<String, Vec<Posting>> = HashMap::new();
+        let mut lengths = Vec::with_capacity(icons.len());
+
+        for (idx, icon) in icons.iter().enumerate() {
+            let fields = std::iter::once((Field::Name, &icon.name))
+                .chain(icon.aliases.iter().map(|alias| (Field::Alias, alias)))
+                .chain(icon.search_terms.iter().map(|term| (Field::Term, term)));
+
+            let mut length = 0.0;
+            for (field, text) in fields {
+                for word in tokenize(text) {
+                    length += field.weight();
+                    let list = postings.entry(word).or_default();
+                    match list.last_mut().filter(|posting| posting.icon == idx) {
+                        Some(posting) => {
+                            posting.frequency += field.weight();
+                            if field < posting.field {
+                                posting.field = field;
+                                posting.source = text.clone();
+                            }
+                        }
+                        None => list.push(Posting {
+                            icon: idx,
+                            frequency: field.weight(),
+                            field,
+                            source: text.clone(),
+                        }),
+                    }
+                }
+            }
+            lengths.push(length);
+        }
+
+        let average_length = if lengths.is_empty() {
+            0.0
+        } else {
+            lengths.iter().sum::<f32>() / lengths.len() as f32
+        };
+
+        Self {
+            postings,
+            lengths,
+            average_length,
+        }
+    }
+
+    /// Scores every icon sharing a word with the query or its synonyms, keyed by icon position
+    pub fn search(&self, query: &str) -> HashMap<usize, SemanticMatch> {
+        // (indexed word, query weight, the query word it was reached from)
+        let mut expanded: Vec<(String, f32, String)> = Vec::new();
+        for word in query_words(query) {
+            let stemmed = stem(&word);
+            expanded.push((stemmed.clone(), 1.0, word.clone()));
+            let synonyms = SYNONYMS
+                .iter()
+                .filter(|(key, _)| *key == word || stem(key) == stemmed)
+                .flat_map(|(_, synonyms)| synonyms.iter());
+            for (position, synonym) in synonyms.enumerate() {
+                let synonym = stem(synonym);
+                if !expanded.iter().any(|(existing, _, _)| *existing == synonym) {
+                    expanded.push((synonym, SYNONYM_WEIGHT / (position + 1) as f32, word.clone()));
+                }
+            }
+        }
+
+        let icon_count = self.lengths.len() as f32;
+        let mut matches: HashMap<usize, SemanticMatch> = HashMap::new();
+        // Only the best synonym of a query word counts, so an icon listing several
+        // synonyms does not outrank the icon the query word usually means
+        let mut synonym_scores: HashMap<(usize, &str), f32> = HashMap::new();
+        for (indexed, weight, query_word) in &expanded {
+            let Some(list) = self.postings.get(indexed) else {
+                continue;
+            };
+            let document_frequency = list.len() as f32;
+            let idf = (1.0 + (icon_count - document_frequency + 0.5) / (document_frequency + 0.5)).ln();
+
+            for posting in list {
+                let length = self.lengths[posting.icon] / self.average_length.max(f32::EPSILON);
+                let frequency = posting.frequency;
+                let score = weight * idf * frequency * (K1 + 1.0) / (frequency + K1 * (1.0 - B + B * length));
+
+                let direct = stem(query_word) == *indexed;
+                let reason = if direct {
+                    format!("{} \"{}\" matches \"{}\"", posting.field.label(), posting.source, query_word)
+                } else {
+                    format!(
+                        "{} \"{}\" matches \"{}\", a synonym of \"{}\"",
+                        posting.field.label(),
+                        posting.source,
+                        indexed,
+                        query_word
+                    )
+                };
+                let entry = matches.entry(posting.icon).or_default();
+                if direct {
+                    entry.score += score;
+                } else {
+                    let best = synonym_scores.entry((posting.icon, query_word.as_str())).or_default();
+                    *best = best.max(score);
+                }
+                if !entry.explanation.contains(&reason) {
+                    entry.explanation.push(reason);
+                }
+            }
+        }
+        for ((icon, _), score) in synonym_scores {
+            if let Some(entry) = matches.get_mut(&icon) {
+                entry.score += score;
+            }
+        }
+        matches
+    }
+}
+
+/// Lowercase query words, keeping the original spelling for explanations
+fn query_words(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Lowercase, stemmed words of a name, alias or search term
+fn tokenize(text: &str) -> Vec<String> {
+    query_words(text).iter().map(|word| stem(word)).collect()
+}
+
+/// Folds plurals so "gears" finds `gear` and "users" finds `user`
+fn stem(word: &str) -> String {
+    match word.strip_suffix('s') {
+        Some(singular) if singular.len() >= 3 && !singular.ends_with('s') => singular.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An icon with FontAwesome's metadata; the shape does not matter for keyword search
+    fn icon(name: &str, aliases: &[&str], search_terms: &[&str]) -> IconInfo {
+        IconInfo {
+            name: name.to_string(),
+            rust_name: name.to_uppercase().replace('-', "_"),
+            category: "solid".to_string(),
+            view_box: "0 0 512 512".to_string(),
+            min_x: 0.0,
+            min_y: 0.0,
+            width: 512.0,
+            height: 512.0,
+            aspect_ratio: 1.0,
+            path_data: String::new(),
+            import_path: format!("icons::solid::{}", name.to_uppercase().replace('-', "_")),
+            aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            search_terms: search_terms.iter().map(|term| term.to_string()).collect(),
+            phash: None,
+            full: None,
+        }
+    }
+
+    /// Icons competing for "delete" and "settings", with their FontAwesome aliases and search terms
+    fn icons() -> Vec<IconInfo> {
+        vec![
+            icon("trash", &[], &["delete", "garbage", "hide", "remove"]),
+            icon("xmark", &["close", "multiply", "remove", "times"], &["cancel", "close", "cross", "error", "exit", "incorrect", "x"]),
+            icon("eraser", &[], &["art", "delete", "remove", "rubber"]),
+            icon("minus", &["subtract"], &["collapse", "delete", "hide", "math", "minify", "negative", "remove", "trash"]),
+            icon("gear", &["cog"], &["cog", "cogwheel", "gear", "mechanical", "settings", "sprocket", "tool", "wheel"]),
+            icon("sliders", &["sliders-h"], &["adjust", "settings", "sliders", "toggle"]),
+            icon("wrench", &[], &["construction", "fix", "mechanic", "plumbing", "settings", "spanner", "tool", "update"]),
+            icon("location-dot", &["map-marker-alt"], &["address", "coordinates", "destination", "gps", "map", "pin", "place"]),
+            icon("house", &["home"], &["abode", "building", "residence"]),
+        ]
+    }
+
+    /// Icon names ordered by score, best first
+    fn ranking(query: &str) -> Vec<String> {
+        let icons = icons();
+        let mut matches: Vec<(usize, SemanticMatch)> = SemanticIndex::build(&icons).search(query).into_iter().collect();
+        matches.sort_by(|a, b| b.1.score.total_cmp(&a.1.score));
+        matches.into_iter().map(|(idx, _)| icons[idx].name.clone()).collect()
+    }
+
+    #[test]
+    fn delete_ranks_trash_first() {
+        let ranking = ranking("delete");
+        assert_eq!(ranking[0], "trash");
+        for name in ["xmark", "eraser", "minus"] {
+            assert!(ranking.iter().any(|found| found == name), "{} missing from {:?}", name, ranking);
+        }
+    }
+
+    #[test]
+    fn settings_ranks_gear_first() {
+        let ranking = ranking("settings");
+        assert_eq!(ranking[0], "gear");
+        assert!(ranking.contains(&"sliders".to_string()));
+        assert!(ranking.contains(&"wrench".to_string()));
+    }
+
+    #[test]
+    fn explains_synonym_matches() {
+        let icons = icons();
+        let matches = SemanticIndex::build(&icons).search("home");
+        let house = icons.iter().position(|icon| icon.name == "house").unwrap();
+        assert!(matches[&house].explanation.contains(&"alias \"home\" matches \"home\"".to_string()));
+
+        let matches = SemanticIndex::build(&icons).search("location");
+        let pin = icons.iter().position(|icon| icon.name == "location-dot").unwrap();
+        assert!(matches[&pin].explanation.iter().any(|reason| reason.contains("a synonym of \"location\"")));
+    }
+
+    #[test]
+    fn synonyms_do_not_repeat_their_key() {
+        for (key, synonyms) in SYNONYMS {
+            assert!(!synonyms.contains(key), "\"{}\" lists itself as a synonym", key);
+        }
+    }
+}
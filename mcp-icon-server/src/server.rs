@@ -1,4 +1,5 @@
 use anyhow::Result;
+use base64::prelude::*;
 use rmcp::{
     ErrorData as McpError, RoleServer, ServerHandler,
     model::*,
@@ -6,21 +7,30 @@ use rmcp::{
 };
 use serde_json::{json, Map, Value};
 use std::borrow::Cow;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::icon_index::IconIndex;
+use crate::resources::{self, ResourceBody};
 use crate::tools;
+use crate::tools::preview::PreviewError;
 use crate::types::*;
 
+/// Cheap to clone: the HTTP transport hands every session its own clone sharing one index
 #[derive(Clone)]
 pub struct IconServer {
-    index: IconIndex,
+    index: Arc<IconIndex>,
+    /// `audit_icon_usage` only scans directories below this one; without it the tool is not offered
+    audit_root: Option<Arc<PathBuf>>,
 }
 
 impl IconServer {
-    pub fn new() -> Result<Self> {
-        let index = IconIndex::load()?;
-        Ok(Self { index })
+    pub fn new(extra_manifests: &[PathBuf], audit_root: Option<PathBuf>) -> Result<Self> {
+        let index = IconIndex::load(extra_manifests)?;
+        Ok(Self {
+            index: Arc::new(index),
+            audit_root: audit_root.map(Arc::new),
+        })
     }
 }
 
@@ -30,14 +40,16 @@ impl ServerHandler for IconServer {
             protocol_version: ProtocolVersion::V_2024_11_05,
             capabilities: ServerCapabilities::builder()
                 .enable_tools()
+                .enable_resources()
                 .build(),
             server_info: Implementation::from_build_env(),
-            instructions: Some(
+            instructions: Some(format!(
                 "This server provides FontAwesome icon search and code generation for yew-shortcuts. \
                 Use 'search_icons' to find icons, 'get_icon_code' to generate Yew component code, \
-                'get_icon_details' for detailed icon information, and 'list_categories' to see available categories."
-                .to_string()
-            ),
+                'get_icon_details' for detailed icon information, 'preview_icons' to see what icons look like, 'find_similar_icons' for alternatives that look alike, {}and 'list_categories' to see available categories. \
+                Icons are also resources: fa://{{category}}/{{name}}.svg, and fa://{{category}}/{{name}}.png with an optional ?size=<px>.",
+                if self.audit_root.is_some() { "'audit_icon_usage' to check a project's icons, " } else { "" }
+            )),
         }
     }
 
@@ -46,17 +58,47 @@ impl ServerHandler for IconServer {
         _request: Option<PaginatedRequestParam>,
         _context: RequestContext<RoleServer>,
     ) -> Result<ListToolsResult, McpError> {
+        // Categories come from the loaded manifests, so Pro styles show up when present
+        let categories: Vec<&str> = self.index.categories().into_iter().map(|(name, _)| name).collect();
+
         let mut search_schema = Map::new();
         search_schema.insert("type".to_string(), json!("object"));
         search_schema.insert("properties".to_string(), json!({
             "query": {
                 "type": "string",
-                "description": "Search query for icon names"
+                "description": "Icon name or what it should depict, e.g. 'delete' or 'settings'; matched against names, FontAwesome aliases and search terms, with synonyms"
             },
             "category": {
                 "type": "string",
-                "enum": ["solid", "regular", "brands"],
+                "enum": categories,
                 "description": "Optional category filter"
+            },
+            "limit": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": tools::search::MAX_LIMIT,
+                "default": tools::search::DEFAULT_LIMIT,
+                "description": "Maximum number of icons to return"
+            },
+            "offset": {
+                "type": "integer",
+                "minimum": 0,
+                "default": 0,
+                "description": "Number of matches to skip"
+            },
+            "cursor": {
+                "type": "string",
+                "description": "next_cursor from a previous result, to fetch the next page"
+            },
+            "min_score": {
+                "type": "integer",
+                "description": "Drop matches scoring below this; substring matches score above 1000"
+            },
+            "sort": {
+                "type": "string",
+                "enum": ["score", "name", "category"],
+                "default": "score",
+                "description": "Result order: best match first, by name, or by category then name"
             }
         }));
         search_schema.insert("required".to_string(), json!(["query"]));
@@ -70,7 +112,7 @@ impl ServerHandler for IconServer {
             },
             "category": {
                 "type": "string",
-                "enum": ["solid", "regular", "brands"],
+                "enum": categories,
                 "description": "Icon category"
             }
         }));
@@ -85,21 +127,105 @@ impl ServerHandler for IconServer {
             },
             "category": {
                 "type": "string",
-                "enum": ["solid", "regular", "brands"],
+                "enum": categories,
                 "description": "Icon category"
             }
         }));
         details_schema.insert("required".to_string(), json!(["name", "category"]));
 
+        let mut preview_schema = Map::new();
+        preview_schema.insert("type".to_string(), json!("object"));
+        preview_schema.insert("properties".to_string(), json!({
+            "icons": {
+                "type": "array",
+                "minItems": 1,
+                "maxItems": tools::preview::MAX_ICONS,
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Icon name" },
+                        "category": { "type": "string", "enum": categories, "description": "Icon category" }
+                    },
+                    "required": ["name", "category"]
+                },
+                "description": "Icons to show, in reading order"
+            },
+            "size": {
+                "type": "integer",
+                "minimum": tools::preview::MIN_SIZE,
+                "maximum": tools::preview::MAX_SIZE,
+                "default": tools::preview::DEFAULT_SIZE,
+                "description": "Icon height in pixels"
+            },
+            "color": {
+                "type": "string",
+                "default": "#000000",
+                "description": "Icon color, #rgb or #rrggbb"
+            },
+            "background": {
+                "type": "string",
+                "default": "#ffffff",
+                "description": "Sheet background, #rgb, #rrggbb or 'transparent'"
+            },
+            "full": {
+                "type": "boolean",
+                "default": false,
+                "description": "Show the full-size variants (as with full=true on FontAwesomeSvg) instead of the cropped icons"
+            },
+            "columns": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Icons per row; defaults to a roughly square sheet"
+            }
+        }));
+        preview_schema.insert("required".to_string(), json!(["icons"]));
+
+        let mut similar_schema = Map::new();
+        similar_schema.insert("type".to_string(), json!("object"));
+        similar_schema.insert("properties".to_string(), json!({
+            "name": {
+                "type": "string",
+                "description": "Icon name"
+            },
+            "category": {
+                "type": "string",
+                "enum": categories,
+                "description": "Icon category"
+            },
+            "limit": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": tools::similar::MAX_LIMIT,
+                "default": tools::similar::DEFAULT_LIMIT,
+                "description": "Maximum number of icons to return"
+            },
+            "categories": {
+                "type": "array",
+                "items": { "type": "string", "enum": categories },
+                "description": "Only suggest icons from these categories; all by default"
+            }
+        }));
+        similar_schema.insert("required".to_string(), json!(["name", "category"]));
+
+        let mut audit_schema = Map::new();
+        audit_schema.insert("type".to_string(), json!("object"));
+        audit_schema.insert("properties".to_string(), json!({
+            "path": {
+                "type": "string",
+                "description": "Directory of the Rust project to scan, relative to the server's audit root unless absolute; it must lie within that root"
+            }
+        }));
+        audit_schema.insert("required".to_string(), json!(["path"]));
+
         let mut categories_schema = Map::new();
         categories_schema.insert("type".to_string(), json!("object"));
         categories_schema.insert("properties".to_string(), json!({}));
 
-        Ok(ListToolsResult {
+        let mut result = ListToolsResult {
             tools: vec![
                 Tool {
                     name: Cow::Borrowed("search_icons"),
-                    description: Some(Cow::Borrowed("Search for FontAwesome icons by name or keyword")),
+                    description: Some(Cow::Borrowed("Search for FontAwesome icons by name, alias or keyword, including synonyms. Results are paginated; each has a score, the matched character positions and an explanation of why it matched")),
                     input_schema: Arc::new(search_schema),
                     output_schema: None,
                     annotations: None,
@@ -118,6 +244,27 @@ impl ServerHandler for IconServer {
                     output_schema: None,
                     annotations: None,
                 },
+                Tool {
+                    name: Cow::Borrowed("preview_icons"),
+                    description: Some(Cow::Borrowed("Render icons into a labeled contact-sheet PNG to see what they look like before choosing one")),
+                    input_schema: Arc::new(preview_schema),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: Cow::Borrowed("find_similar_icons"),
+                    description: Some(Cow::Borrowed("Find icons that look like a given icon, across categories, ranked by the distance between perceptual hashes of their rendered shapes")),
+                    input_schema: Arc::new(similar_schema),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: Cow::Borrowed("audit_icon_usage"),
+                    description: Some(Cow::Borrowed("Scan a Rust project for icons::<category>::<NAME> references and FontAwesomeSvg elements. Reports which icons are used where, unknown icons with suggestions, icons used in more than one style and the estimated WASM bytes of icon data")),
+                    input_schema: Arc::new(audit_schema),
+                    output_schema: None,
+                    annotations: None,
+                },
                 Tool {
                     name: Cow::Borrowed("list_categories"),
                     description: Some(Cow::Borrowed("List all available icon categories with counts")),
@@ -127,6 +274,90 @@ impl ServerHandler for IconServer {
                 },
             ],
             next_cursor: None,
+        };
+        // Scanning the file system is only offered where an audit root was configured
+        if self.audit_root.is_none() {
+            result.tools.retain(|tool| tool.name != "audit_icon_usage");
+        }
+        Ok(result)
+    }
+
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let cursor = request.and_then(|request| request.cursor);
+        let page = resources::list(&self.index, cursor.as_deref())
+            .map_err(|e| McpError::invalid_params("Invalid cursor", Some(json!({"error": e.to_string()}))))?;
+
+        let resources = page
+            .resources
+            .into_iter()
+            .map(|entry| {
+                let mut resource = RawResource::new(entry.uri, entry.name);
+                resource.description = Some(entry.description);
+                resource.mime_type = Some(entry.mime_type.to_string());
+                resource.no_annotation()
+            })
+            .collect();
+
+        Ok(ListResourcesResult {
+            resources,
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: vec![
+                RawResourceTemplate {
+                    uri_template: resources::SVG_TEMPLATE.to_string(),
+                    name: "icon-svg".to_string(),
+                    description: Some("SVG of an icon, filled with currentColor".to_string()),
+                    mime_type: Some("image/svg+xml".to_string()),
+                }
+                .no_annotation(),
+                RawResourceTemplate {
+                    uri_template: resources::PNG_TEMPLATE.to_string(),
+                    name: "icon-png".to_string(),
+                    description: Some(format!(
+                        "Black icon on a transparent background, {} pixels tall unless ?size=<px> (at most {}) is given",
+                        resources::DEFAULT_PNG_SIZE,
+                        resources::MAX_PNG_SIZE
+                    )),
+                    mime_type: Some("image/png".to_string()),
+                }
+                .no_annotation(),
+            ],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        ReadResourceRequestParam { uri }: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let data = resources::read(&self.index, &uri)
+            .map_err(|e| McpError::resource_not_found("Resource not found", Some(json!({"uri": uri, "error": e.to_string()}))))?;
+
+        let mime_type = Some(data.mime_type.to_string());
+        let contents = match data.body {
+            ResourceBody::Text(text) => ResourceContents::TextResourceContents { uri, mime_type, text },
+            ResourceBody::Blob(bytes) => ResourceContents::BlobResourceContents {
+                uri,
+                mime_type,
+                blob: BASE64_STANDARD.encode(bytes),
+            },
+        };
+
+        Ok(ReadResourceResult {
+            contents: vec![contents],
         })
     }
 
@@ -142,8 +373,9 @@ impl ServerHandler for IconServer {
             "search_icons" => {
                 let params: SearchIconsParams = serde_json::from_value(arguments)
                     .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
+                // Searching only fails on a bad limit or cursor
                 let result = tools::search::search_icons(&self.index, params)
-                    .map_err(|e| McpError::internal_error("Search failed", Some(json!({"error": e.to_string()}))))?;
+                    .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
                 serde_json::to_value(result)
                     .map_err(|e| McpError::internal_error("Serialization failed", Some(json!({"error": e.to_string()}))))?
             }
@@ -163,6 +395,48 @@ impl ServerHandler for IconServer {
                 serde_json::to_value(result)
                     .map_err(|e| McpError::internal_error("Serialization failed", Some(json!({"error": e.to_string()}))))?
             }
+            "preview_icons" => {
+                let params: PreviewIconsParams = serde_json::from_value(arguments)
+                    .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
+                let result = tools::preview::preview_icons(&self.index, params).map_err(|e| match e {
+                    PreviewError::InvalidParams(_) => {
+                        McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()})))
+                    }
+                    PreviewError::Render(_) => {
+                        McpError::internal_error("Preview failed", Some(json!({"error": e.to_string()})))
+                    }
+                })?;
+                let legend = serde_json::to_value(&result)
+                    .map_err(|e| McpError::internal_error("Serialization failed", Some(json!({"error": e.to_string()}))))?;
+
+                // The sheet goes back as an image the client can show the model, with its layout as text
+                return Ok(CallToolResult::success(vec![
+                    Content::image(BASE64_STANDARD.encode(&result.png), "image/png"),
+                    Content::text(legend.to_string()),
+                ]));
+            }
+            "find_similar_icons" => {
+                let params: FindSimilarIconsParams = serde_json::from_value(arguments)
+                    .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
+                // Only an unknown icon or one without a perceptual hash fails
+                let result = tools::similar::find_similar_icons(&self.index, params)
+                    .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
+                serde_json::to_value(result)
+                    .map_err(|e| McpError::internal_error("Serialization failed", Some(json!({"error": e.to_string()}))))?
+            }
+            "audit_icon_usage" => {
+                let Some(audit_root) = &self.audit_root else {
+                    return Err(McpError::method_not_found::<PingRequestMethod>());
+                };
+                let params: AuditIconUsageParams = serde_json::from_value(arguments)
+                    .map_err(|e| McpError::invalid_params("Invalid parameters", Some(json!({"error": e.to_string()}))))?;
+                let dir = tools::audit::resolve_project_dir(audit_root, &params.path)
+                    .map_err(|e| McpError::invalid_params("Invalid path", Some(json!({"error": e.to_string()}))))?;
+                let result = tools::audit::audit_icon_usage(&self.index, &dir)
+                    .map_err(|e| McpError::internal_error("Audit failed", Some(json!({"error": e.to_string()}))))?;
+                serde_json::to_value(result)
+                    .map_err(|e| McpError::internal_error("Serialization failed", Some(json!({"error": e.to_string()}))))?
+            }
             "list_categories" => {
                 let result = tools::list_categories(&self.index);
                 serde_json::to_value(result)
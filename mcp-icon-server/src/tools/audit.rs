@@ -0,0 +1,341 @@
+use anyhow::{bail, Context, Result};
+use icon_model::naming::rust_name_to_kebab_case;
+use icon_model::usage::{self, IconReference, Location};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::icon_index::IconIndex;
+use crate::types::{
+    AuditIconUsageResult, CategoryInfo, IconInfo, IconUsage, SourceLocation,
+    StyleConflict, UnknownIconReference,
+};
+
+/// Size of one `Icon` constant on wasm32: the category, name, viewBox and
+/// path data slices plus the parsed bounds and optical center
+const ICON_STRUCT_BYTES: usize = 56;
+
+/// Most suggestions listed for an unknown icon
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Bytes the icon's constant and string data add to a WASM build
+fn wasm_bytes(icon: &IconInfo) -> usize {
+    ICON_STRUCT_BYTES + icon.category.len() + icon.name.len() + icon.view_box.len() + icon.path_data.len()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Import paths of icons an unknown reference may have meant
+fn suggestions(index: &IconIndex, category: &str, rust_name: &str) -> Vec<String> {
+    let name = rust_name_to_kebab_case(rust_name);
+    let mut suggestions: Vec<String> = Vec::new();
+    let mut suggest = |icon: &IconInfo| {
+        if suggestions.len() < MAX_SUGGESTIONS && !suggestions.contains(&icon.import_path) {
+            suggestions.push(icon.import_path.clone());
+        }
+    };
+
+    // Renamed icons list their old names as aliases
+    for icon in &index.icons {
+        if icon.category == category && icon.aliases.contains(&name) {
+            suggest(icon);
+        }
+    }
+    // The same icon in another style
+    for icon in &index.icons {
+        if icon.rust_name == rust_name {
+            suggest(icon);
+        }
+    }
+    // Close spellings, preferring the same style
+    let max_distance = name.len() / 3 + 1;
+    let mut close: Vec<(bool, usize, &IconInfo)> = index
+        .icons
+        .iter()
+        .map(|icon| (icon.category != category, levenshtein(&name, &icon.name), icon))
+        .filter(|(_, distance, _)| *distance <= max_distance)
+        .collect();
+    close.sort_by_key(|(other_category, distance, icon)| (*distance, *other_category, &icon.import_path));
+    for (_, _, icon) in close {
+        suggest(icon);
+    }
+
+    suggestions
+}
+
+/// The directory `path` names, relative to `root` unless absolute. Symbolic
+/// links are resolved first, so neither `..` nor a link can reach outside `root`.
+pub fn resolve_project_dir(root: &Path, path: &str) -> Result<PathBuf> {
+    let root = root
+        .canonicalize()
+        .with_context(|| format!("Audit root {} is not accessible", root.display()))?;
+    let dir = root
+        .join(path)
+        .canonicalize()
+        .with_context(|| format!("'{}' is not accessible", path))?;
+    if !dir.starts_with(&root) {
+        bail!("'{}' is outside the audit root {}", path, root.display());
+    }
+    if !dir.is_dir() {
+        bail!("'{}' is not a directory", path);
+    }
+    Ok(dir)
+}
+
+/// Scans a Rust project, as resolved by [`resolve_project_dir`], for icon
+/// references and reports usage, mistakes and size
+pub fn audit_icon_usage(index: &IconIndex, root: &Path) -> Result<AuditIconUsageResult> {
+    let files = usage::collect_rust_files(root).with_context(|| format!("Failed to list files in {}", root.display()))?;
+
+    let categories: BTreeSet<String> = index
+        .categories()
+        .into_iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    let mut references = Vec::new();
+    let mut component_uses = Vec::new();
+    let mut errors = Vec::new();
+    for path in &files {
+        let display = path.strip_prefix(root).unwrap_or(path).display().to_string();
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => {
+                errors.push(format!("{}: {}", display, e));
+                continue;
+            }
+        };
+        let file_usage = match usage::scan_source(&source, &categories) {
+            Ok(file_usage) => file_usage,
+            Err(e) => {
+                let start = e.span().start();
+                errors.push(format!("{}:{}:{}: {}", display, start.line, start.column + 1, e));
+                continue;
+            }
+        };
+
+        let location = |location: Location| SourceLocation {
+            file: display.clone(),
+            line: location.line,
+            column: location.column,
+        };
+        references.extend(
+            file_usage
+                .references
+                .into_iter()
+                .map(|reference| (location(reference.location), reference)),
+        );
+        component_uses.extend(file_usage.components.into_iter().map(location));
+    }
+
+    // Group references by the icon they resolve to, or by how they were written
+    let mut used: BTreeMap<&str, (&IconInfo, Vec<SourceLocation>)> = BTreeMap::new();
+    let mut unknown: BTreeMap<String, (String, String, Vec<SourceLocation>)> = BTreeMap::new();
+    for (location, IconReference { category, rust_name, .. }) in references {
+        let icon = index
+            .icons
+            .iter()
+            .find(|icon| icon.category == category && icon.rust_name == rust_name);
+        match icon {
+            Some(icon) => used
+                .entry(&icon.import_path)
+                .or_insert_with(|| (icon, Vec::new()))
+                .1
+                .push(location),
+            None => unknown
+                .entry(format!("icons::{}::{}", category, rust_name))
+                .or_insert_with(|| (category, rust_name, Vec::new()))
+                .2
+                .push(location),
+        }
+    }
+
+    // The same icon name in several styles usually means one of them is a mistake
+    let mut styles_by_name: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut category_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for (icon, _) in used.values() {
+        styles_by_name.entry(&icon.name).or_default().insert(&icon.category);
+        *category_counts.entry(&icon.category).or_insert(0) += 1;
+    }
+    let inconsistent_styles = styles_by_name
+        .into_iter()
+        .filter(|(_, styles)| styles.len() > 1)
+        .map(|(name, styles)| StyleConflict {
+            name: name.to_string(),
+            categories: styles.into_iter().map(str::to_string).collect(),
+        })
+        .collect();
+    let categories = category_counts
+        .into_iter()
+        .map(|(name, count)| CategoryInfo {
+            name: name.to_string(),
+            count,
+        })
+        .collect();
+
+    let icons: Vec<IconUsage> = used
+        .into_values()
+        .map(|(icon, locations)| IconUsage {
+            name: icon.name.clone(),
+            category: icon.category.clone(),
+            import_path: icon.import_path.clone(),
+            locations,
+            wasm_bytes: wasm_bytes(icon),
+        })
+        .collect();
+    let estimated_wasm_bytes = icons.iter().map(|icon| icon.wasm_bytes).sum();
+
+    let unknown_icons = unknown
+        .into_iter()
+        .map(|(reference, (category, rust_name, locations))| UnknownIconReference {
+            suggestions: suggestions(index, &category, &rust_name),
+            reference,
+            locations,
+        })
+        .collect();
+
+    Ok(AuditIconUsageResult {
+        files_scanned: files.len(),
+        icons,
+        unknown_icons,
+        inconsistent_styles,
+        categories,
+        component_uses,
+        estimated_wasm_bytes,
+        errors,
+    })
+}
+
+fn format_locations(locations: &[SourceLocation]) -> String {
+    locations
+        .iter()
+        .map(|location| format!("{}:{}:{}", location.file, location.line, location.column))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Human-readable report for the `audit-icon-usage` command
+pub fn format_report(result: &AuditIconUsageResult) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "Scanned {} files: {} icons used, about {} bytes of icon data in WASM, {} <FontAwesomeSvg> elements\n",
+        result.files_scanned,
+        result.icons.len(),
+        result.estimated_wasm_bytes,
+        result.component_uses.len()
+    ));
+
+    if !result.icons.is_empty() {
+        output.push_str("\nIcons used:\n");
+        for icon in &result.icons {
+            output.push_str(&format!(
+                "  {} ({} bytes): {}\n",
+                icon.import_path,
+                icon.wasm_bytes,
+                format_locations(&icon.locations)
+            ));
+        }
+    }
+
+    if !result.unknown_icons.is_empty() {
+        output.push_str("\nUnknown icons:\n");
+        for unknown in &result.unknown_icons {
+            output.push_str(&format!("  {}: {}\n", unknown.reference, format_locations(&unknown.locations)));
+            if !unknown.suggestions.is_empty() {
+                output.push_str(&format!("    did you mean {}?\n", unknown.suggestions.join(", ")));
+            }
+        }
+    }
+
+    if !result.inconsistent_styles.is_empty() {
+        output.push_str("\nIcons used in more than one style:\n");
+        for conflict in &result.inconsistent_styles {
+            output.push_str(&format!("  {}: {}\n", conflict.name, conflict.categories.join(", ")));
+        }
+    }
+
+    if !result.errors.is_empty() {
+        output.push_str("\nSkipped files:\n");
+        for error in &result.errors {
+            output.push_str(&format!("  {}\n", error));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A project directory holding one `src/main.rs`
+    fn project(source: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), source).unwrap();
+        dir
+    }
+
+    fn audit(source: &str) -> AuditIconUsageResult {
+        let dir = project(source);
+        let root = resolve_project_dir(dir.path(), ".").unwrap();
+        audit_icon_usage(&IconIndex::load(&[]).unwrap(), &root).unwrap()
+    }
+
+    #[test]
+    fn reports_used_icons_with_locations() {
+        let result = audit("use yew_shortcuts::fontawesome::icons::solid as fas;\nconst HOME: &Icon = fas::HOUSE;\n");
+        assert_eq!(result.files_scanned, 1);
+        assert_eq!(result.icons.len(), 1);
+        assert_eq!(result.icons[0].import_path, "icons::solid::HOUSE");
+        let location = &result.icons[0].locations[0];
+        assert_eq!((location.file.as_str(), location.line, location.column), ("src/main.rs", 2, 26));
+        assert!(result.estimated_wasm_bytes > ICON_STRUCT_BYTES);
+    }
+
+    #[test]
+    fn suggests_icons_for_unknown_references() {
+        let result = audit("const A: &Icon = icons::solid::HART;\n");
+        assert!(result.icons.is_empty());
+        assert_eq!(result.unknown_icons.len(), 1);
+        assert_eq!(result.unknown_icons[0].reference, "icons::solid::HART");
+        assert_eq!(result.unknown_icons[0].suggestions[0], "icons::solid::HEART");
+    }
+
+    #[test]
+    fn reports_icons_used_in_several_styles() {
+        let result = audit("const A: &Icon = icons::solid::HEART;\nconst B: &Icon = icons::regular::HEART;\n");
+        assert_eq!(result.inconsistent_styles.len(), 1);
+        assert_eq!(result.inconsistent_styles[0].name, "heart");
+        assert_eq!(result.inconsistent_styles[0].categories, ["regular", "solid"]);
+    }
+
+    #[test]
+    fn refuses_directories_outside_the_root() {
+        let root = project("");
+        let outside = project("");
+        assert!(resolve_project_dir(root.path(), "src").is_ok());
+        assert!(resolve_project_dir(root.path(), "..").is_err());
+        assert!(resolve_project_dir(root.path(), outside.path().to_str().unwrap()).is_err());
+        assert!(resolve_project_dir(root.path(), "src/main.rs").is_err());
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(outside.path(), root.path().join("link")).unwrap();
+            assert!(resolve_project_dir(root.path(), "link").is_err());
+        }
+    }
+}
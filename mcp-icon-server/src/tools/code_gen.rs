@@ -1,7 +1,63 @@
 use anyhow::{anyhow, Result};
 
 use crate::icon_index::IconIndex;
-use crate::types::{GetIconCodeParams, GetIconCodeResult, PropInfo};
+use crate::types::{GetIconCodeParams, GetIconCodeResult, IconInfo, PropInfo};
+
+/// A field of `FontAwesomeSvgProps`, as parsed from yew-shortcuts by build.rs
+pub struct StaticProp {
+    pub name: &'static str,
+    pub prop_type: &'static str,
+    pub required: bool,
+    pub default_value: Option<&'static str>,
+    /// Cargo feature of yew-shortcuts the prop is gated behind
+    pub feature: Option<&'static str>,
+    pub description: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/component_props.rs"));
+
+/// FontAwesome's full canvas, used when an icon has no full-size variant on record
+const FULL_VIEW_BOX: &str = "0 0 640 640";
+
+/// Imports every generated snippet relies on; icon paths are relative to `fontawesome`
+pub const IMPORT_STATEMENT: &str = "use yew::prelude::*;\nuse yew_shortcuts::{fontawesome::icons, FontAwesomeSvg, IconFit};";
+
+/// Looks up a prop, so snippets can only use props the component really has
+fn prop(name: &str) -> &'static StaticProp {
+    COMPONENT_PROPS
+        .iter()
+        .find(|prop| prop.name == name)
+        .unwrap_or_else(|| panic!("FontAwesomeSvgProps has no `{}` prop", name))
+}
+
+/// `name={value}` for the given prop
+fn attribute(name: &str, value: &str) -> String {
+    format!("{}={{{}}}", prop(name).name, value)
+}
+
+/// Commented `html!` examples of rendering an icon, one per way of using it
+pub fn examples(icon: &IconInfo) -> Vec<(String, String)> {
+    let icon_attr = attribute("icon", &icon.import_path);
+    let full = prop("full");
+    let full_comment = match full.feature {
+        Some(feature) => format!("// Full SVG version (requires \"{}\" feature in Cargo.toml):", feature),
+        None => "// Full SVG version:".to_string(),
+    };
+
+    [
+        ("// Default cropped version (always available):".to_string(), icon_attr.clone()),
+        (full_comment, format!("{} {}", icon_attr, attribute(full.name, "true"))),
+        (
+            "// Square viewBox, so icons of different widths line up in a grid:".to_string(),
+            format!("{} {}", icon_attr, attribute("fit", "IconFit::Square")),
+        ),
+    ]
+    .into_iter()
+    .map(|(comment, attributes)| {
+        (comment, format!("html! {{\n    <FontAwesomeSvg {} />\n}}", attributes))
+    })
+    .collect()
+}
 
 pub fn get_icon_code(index: &IconIndex, params: GetIconCodeParams) -> Result<GetIconCodeResult> {
     // Find the icon
@@ -15,70 +71,197 @@ pub fn get_icon_code(index: &IconIndex, params: GetIconCodeParams) -> Result<Get
             )
         })?;
 
-    // Generate import statement
-    let import_statement = format!(
-        "use yew_shortcuts::{{FontAwesomeSvg, {}}};",
-        icon.import_path
-    );
-
-    // Generate the Yew component code showing both variants
-    let code = format!(
-        r#"// Default cropped version (always available):
-html! {{
-    <FontAwesomeSvg icon={{&{}}} />
-}}
-
-// Full SVG version (requires "full-svg" feature in Cargo.toml):
-html! {{
-    <FontAwesomeSvg icon={{&{}}} full=true />
-}}"#,
-        icon.import_path, icon.import_path
-    );
-
-    // Generate props list
-    let props = vec![
-        PropInfo {
-            name: "icon".to_string(),
-            prop_type: "&'static Icon".to_string(),
-            required: true,
-            default_value: None,
-            description: "The icon to render".to_string(),
-        },
-        PropInfo {
-            name: "full".to_string(),
-            prop_type: "bool".to_string(),
-            required: false,
-            default_value: Some("false".to_string()),
-            description: "Use full SVG with original viewBox (requires 'full-svg' feature)".to_string(),
-        },
-        PropInfo {
-            name: "classes".to_string(),
-            prop_type: "Classes".to_string(),
-            required: false,
-            default_value: Some("Classes::new()".to_string()),
-            description: "CSS classes to apply to the SVG element".to_string(),
-        },
-        PropInfo {
-            name: "style".to_string(),
-            prop_type: "Option<String>".to_string(),
-            required: false,
-            default_value: Some("None".to_string()),
-            description: "Inline style to apply to the SVG element".to_string(),
-        },
-        PropInfo {
-            name: "onclick".to_string(),
-            prop_type: "Option<Callback<MouseEvent>>".to_string(),
-            required: false,
-            default_value: Some("None".to_string()),
-            description: "Click event handler".to_string(),
-        },
-    ];
+    // Generate the Yew component code showing each variant
+    let code = examples(icon)
+        .into_iter()
+        .map(|(comment, html)| format!("{}\n{}", comment, html))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let props = COMPONENT_PROPS
+        .iter()
+        .map(|prop| PropInfo {
+            name: prop.name.to_string(),
+            prop_type: prop.prop_type.to_string(),
+            required: prop.required,
+            default_value: prop.default_value.map(str::to_string),
+            feature: prop.feature.map(str::to_string),
+            description: prop.description.to_string(),
+        })
+        .collect();
 
     Ok(GetIconCodeResult {
         code,
-        import_statement,
+        import_statement: IMPORT_STATEMENT.to_string(),
         viewbox_default: icon.view_box.clone(),
-        viewbox_full: "0 0 640 640".to_string(), // Fixed value for full SVG
+        viewbox_full: icon
+            .full
+            .as_ref()
+            .map_or_else(|| FULL_VIEW_BOX.to_string(), |full| full.view_box.clone()),
+        aspect_ratio: icon.aspect_ratio,
         props,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::details::get_icon_details;
+    use crate::types::GetIconDetailsParams;
+    use proc_macro2::{TokenStream, TokenTree};
+    use std::fs;
+    use std::path::Path;
+
+    /// Icons whose generated code the codegen-check crate compiles, one per free style
+    const CHECKED_ICONS: &[(&str, &str)] = &[("house", "solid"), ("heart", "regular"), ("github", "brands")];
+
+    /// `name=value` pairs of an `html!` element, skipping the tag name
+    fn attributes(tokens: TokenStream) -> Vec<(String, TokenStream)> {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        tokens
+            .windows(3)
+            .filter_map(|window| match window {
+                [TokenTree::Ident(name), TokenTree::Punct(eq), value] if eq.as_char() == '=' => {
+                    let value = match value {
+                        TokenTree::Group(group) => group.stream(),
+                        other => other.clone().into(),
+                    };
+                    Some((name.to_string(), value))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Checks one generated `html!` invocation against the component's props
+    fn check_html(html: &syn::Macro, icon: &IconInfo) {
+        assert!(html.path.is_ident("html"), "not an html! invocation");
+        let attributes = attributes(html.tokens.clone());
+        assert!(!attributes.is_empty(), "no props in {}", html.tokens);
+        for (name, value) in attributes {
+            // `class` belongs to the wrapper div in the details example
+            if name == "class" {
+                continue;
+            }
+            assert!(COMPONENT_PROPS.iter().any(|prop| prop.name == name), "unknown prop `{}`", name);
+            if name == "icon" {
+                // Icon constants are already `&'static Icon`, so they are passed as plain paths
+                let syn::Expr::Path(path) = syn::parse2(value).unwrap() else {
+                    panic!("icon prop is not a path in {}", html.tokens);
+                };
+                let path: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+                assert_eq!(path.join("::"), icon.import_path);
+            }
+        }
+    }
+
+    fn indent(text: &str, depth: usize) -> String {
+        text.lines()
+            .map(|line| match line {
+                "" => "\n".to_string(),
+                line => format!("{}{}\n", "    ".repeat(depth), line),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn props_follow_component() {
+        let names: Vec<&str> = COMPONENT_PROPS.iter().map(|prop| prop.name).collect();
+        for name in ["icon", "full", "fit", "sprite", "classes", "style", "onclick"] {
+            assert!(names.contains(&name), "missing prop `{}`", name);
+        }
+
+        let icon = prop("icon");
+        assert!(icon.required);
+        assert_eq!(icon.prop_type, "&'static Icon");
+        assert_eq!(prop("full").feature, Some("full-svg"));
+        assert_eq!(prop("full").default_value, Some("false"));
+        assert_eq!(prop("sprite").default_value, Some("None"));
+        assert_eq!(prop("onclick").prop_type, "Option<Callback<MouseEvent>>");
+        assert!(COMPONENT_PROPS.iter().all(|prop| !prop.description.is_empty()));
+    }
+
+    #[test]
+    fn generated_code_parses() {
+        let index = IconIndex::load(&[]).unwrap();
+        let import: syn::File = syn::parse_str(IMPORT_STATEMENT).unwrap();
+        assert_eq!(import.items.len(), 2);
+
+        for icon in &index.icons {
+            let params = GetIconCodeParams { name: icon.name.clone(), category: icon.category.clone() };
+            let result = get_icon_code(&index, params).unwrap();
+
+            let block: syn::Block = syn::parse_str(&format!("{{\n{}\n}}", result.code))
+                .unwrap_or_else(|e| panic!("code for {} does not parse: {}", icon.import_path, e));
+            assert_eq!(block.stmts.len(), examples(icon).len());
+            for stmt in &block.stmts {
+                let syn::Stmt::Macro(stmt) = stmt else {
+                    panic!("unexpected statement in code for {}", icon.import_path);
+                };
+                check_html(&stmt.mac, icon);
+            }
+
+            let params = GetIconDetailsParams { name: icon.name.clone(), category: icon.category.clone() };
+            let details = get_icon_details(&index, params).unwrap();
+            let file: syn::File = syn::parse_str(&details.yew_component_example)
+                .unwrap_or_else(|e| panic!("example for {} does not parse: {}", icon.import_path, e));
+            let Some(syn::Item::Fn(component)) = file.items.last() else {
+                panic!("example for {} has no component", icon.import_path);
+            };
+            // A trailing `html! { .. }` parses as a macro statement
+            let Some(syn::Stmt::Macro(html)) = component.block.stmts.last() else {
+                panic!("example for {} does not end in html!", icon.import_path);
+            };
+            check_html(&html.mac, icon);
+        }
+    }
+
+    /// The codegen-check crate holds generated code for a few icons; CI compiles it with `cargo check -p codegen-check`.
+    /// Set `UPDATE_CODEGEN_CHECK=1` to rewrite it after changing the generators.
+    #[test]
+    fn codegen_check_crate_is_current() {
+        let index = IconIndex::load(&[]).unwrap();
+
+        let mut source = String::new();
+        source.push_str("//! Code generated by mcp-icon-server's `get_icon_code` and `get_icon_details`,\n");
+        source.push_str("//! compiled against yew-shortcuts so the server only suggests code that builds.\n");
+        source.push_str("//!\n");
+        source.push_str("//! Do not edit: regenerate with `UPDATE_CODEGEN_CHECK=1 cargo test -p mcp-icon-server`.\n");
+        for (name, category) in CHECKED_ICONS {
+            let icon = index.find_icon(name, category).unwrap();
+            let details = get_icon_details(
+                &index,
+                GetIconDetailsParams { name: name.to_string(), category: category.to_string() },
+            )
+            .unwrap();
+
+            let mut examples_fn = String::new();
+            examples_fn.push_str("pub fn examples() -> Vec<Html> {\n    vec![\n");
+            for (comment, html) in examples(icon) {
+                examples_fn.push_str(&indent(&format!("{}\n{},", comment, html), 2));
+            }
+            examples_fn.push_str("    ]\n}\n");
+
+            source.push_str(&format!("\npub mod {}_{} {{\n", category, name.replace('-', "_")));
+            source.push_str("    pub mod code {\n");
+            source.push_str(&indent(IMPORT_STATEMENT, 2));
+            source.push('\n');
+            source.push_str(&indent(&examples_fn, 2));
+            source.push_str("    }\n\n");
+            source.push_str("    pub mod details {\n");
+            source.push_str(&indent(&details.yew_component_example, 2));
+            source.push_str("    }\n}\n");
+        }
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../codegen-check/src/lib.rs");
+        if std::env::var_os("UPDATE_CODEGEN_CHECK").is_some() {
+            fs::write(&path, &source).unwrap();
+        }
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            current == source,
+            "{} is out of date; rerun with UPDATE_CODEGEN_CHECK=1",
+            path.display()
+        );
+    }
+}
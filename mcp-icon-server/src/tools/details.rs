@@ -22,21 +22,21 @@ pub fn get_icon_details(
     // Generate a complete Yew component example
     let yew_component_example = format!(
         r#"use yew::prelude::*;
-use yew_shortcuts::{{FontAwesomeSvg, {}}};
+use yew_shortcuts::{{fontawesome::icons, FontAwesomeSvg}};
 
 #[function_component]
 pub fn MyIconComponent() -> Html {{
     html! {{
         <div class="icon-container">
-            <FontAwesomeSvg 
-                icon={{&{}}}
+            <FontAwesomeSvg
+                icon={{{}}}
                 classes={{classes!("text-blue-500", "w-6", "h-6")}}
                 style={{"color: currentColor"}}
             />
         </div>
     }}
 }}"#,
-        icon.import_path, icon.import_path
+        icon.import_path
     );
 
     // Generate raw SVG code
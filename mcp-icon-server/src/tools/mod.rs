@@ -1,6 +1,9 @@
+pub mod audit;
 pub mod code_gen;
 pub mod details;
+pub mod preview;
 pub mod search;
+pub mod similar;
 
 use crate::icon_index::IconIndex;
 use crate::types::{CategoryInfo, ListCategoriesResult};
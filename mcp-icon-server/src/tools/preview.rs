@@ -0,0 +1,320 @@
+use anyhow::{anyhow, Context, Error};
+use resvg::tiny_skia::{Color, Paint, Pixmap, PixmapPaint, Transform};
+use std::fmt;
+
+use crate::bitmap_font::{self, ADVANCE, GLYPH_HEIGHT};
+use crate::icon_index::IconIndex;
+use crate::render;
+use crate::types::{PreviewCell, PreviewIconsParams, PreviewIconsResult};
+
+/// Icon height when the caller gives no size
+pub const DEFAULT_SIZE: u32 = 64;
+
+pub const MIN_SIZE: u32 = 16;
+pub const MAX_SIZE: u32 = 256;
+
+/// Most icons a single sheet shows
+pub const MAX_ICONS: usize = 100;
+
+/// Labels longer than this are shortened with ".."
+const LABEL_CHARS: usize = 16;
+
+/// Space around and between cells
+const PADDING: u32 = 12;
+
+/// Space between an icon and its label
+const LABEL_GAP: u32 = 6;
+
+/// Color of the category line under each name
+const CATEGORY_COLOR: (u8, u8, u8) = (0x80, 0x80, 0x80);
+
+/// Why a preview failed
+#[derive(Debug)]
+pub enum PreviewError {
+    /// The request can't be previewed: no or too many icons, an unknown
+    /// icon, a missing full variant, a bad color or an out-of-range size
+    InvalidParams(String),
+    /// Rendering or encoding the sheet failed
+    Render(Error),
+}
+
+impl fmt::Display for PreviewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreviewError::InvalidParams(message) => f.write_str(message),
+            PreviewError::Render(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for PreviewError {}
+
+impl From<Error> for PreviewError {
+    fn from(e: Error) -> Self {
+        PreviewError::Render(e)
+    }
+}
+
+/// Renders icons into one labeled PNG, each above its name and category
+pub fn preview_icons(index: &IconIndex, params: PreviewIconsParams) -> Result<PreviewIconsResult, PreviewError> {
+    let invalid = |message: String| Err(PreviewError::InvalidParams(message));
+    if params.icons.is_empty() {
+        return invalid("No icons to preview".to_string());
+    }
+    if params.icons.len() > MAX_ICONS {
+        return invalid(format!("At most {} icons fit on one sheet, got {}", MAX_ICONS, params.icons.len()));
+    }
+
+    let size = params.size.unwrap_or(DEFAULT_SIZE);
+    if !(MIN_SIZE..=MAX_SIZE).contains(&size) {
+        return invalid(format!("size must be between {} and {}, got {}", MIN_SIZE, MAX_SIZE, size));
+    }
+    let (r, g, b) = parse_color(params.color.as_deref().unwrap_or("#000000"))?;
+    let fill = format!("#{:02x}{:02x}{:02x}", r, g, b);
+    let background = match params.background.as_deref().unwrap_or("#ffffff") {
+        "transparent" => Color::TRANSPARENT,
+        color => {
+            let (r, g, b) = parse_color(color)?;
+            Color::from_rgba8(r, g, b, 255)
+        }
+    };
+
+    let count = params.icons.len();
+    let columns = params
+        .columns
+        .unwrap_or_else(|| (count as f64).sqrt().ceil() as usize)
+        .clamp(1, count);
+    let rows = count.div_ceil(columns);
+
+    // Labels grow with the icons so they stay readable next to them
+    let label_scale = (size / DEFAULT_SIZE).max(1);
+    let line_height = (GLYPH_HEIGHT + 2) * label_scale;
+    let cell_width = size.max(LABEL_CHARS as u32 * ADVANCE * label_scale);
+    let cell_height = size + LABEL_GAP + 2 * line_height;
+
+    let width = columns as u32 * cell_width + (columns as u32 + 1) * PADDING;
+    let height = rows as u32 * cell_height + (rows as u32 + 1) * PADDING;
+    let mut sheet = Pixmap::new(width, height)
+        .ok_or_else(|| anyhow!("Failed to allocate a {}x{} sheet", width, height))?;
+    sheet.fill(background);
+
+    let mut name_paint = Paint::default();
+    name_paint.set_color_rgba8(r, g, b, 255);
+    let mut category_paint = Paint::default();
+    category_paint.set_color_rgba8(CATEGORY_COLOR.0, CATEGORY_COLOR.1, CATEGORY_COLOR.2, 255);
+
+    // Every icon is looked up before any is rendered
+    let mut icons = Vec::with_capacity(count);
+    for icon_ref in &params.icons {
+        let Some(icon) = index.find_icon(&icon_ref.name, &icon_ref.category) else {
+            return invalid(format!("Icon '{}' not found in category '{}'", icon_ref.name, icon_ref.category));
+        };
+        let svg = if params.full {
+            match render::full_svg_document(icon, &fill) {
+                Some(svg) => svg,
+                None => {
+                    return invalid(format!(
+                        "Icon '{}' in category '{}' has no full-size variant",
+                        icon.name, icon.category
+                    ))
+                }
+            }
+        } else {
+            render::svg_document(icon, &fill)
+        };
+        icons.push((icon, svg));
+    }
+
+    let mut cells = Vec::with_capacity(count);
+    for (position, (icon, svg)) in icons.into_iter().enumerate() {
+        let render_icon = |height| {
+            render::rasterize_svg(&svg, height)
+                .with_context(|| format!("Failed to render {}/{}", icon.category, icon.name))
+        };
+        let mut image = render_icon(size)?;
+        if image.width() > cell_width {
+            // Very wide icons are shrunk to fit the cell instead of overlapping
+            let height = (size as u64 * cell_width as u64 / image.width() as u64).max(1) as u32;
+            image = render_icon(height)?;
+        }
+
+        let row = position / columns;
+        let column = position % columns;
+        let cell_x = PADDING + column as u32 * (cell_width + PADDING);
+        let cell_y = PADDING + row as u32 * (cell_height + PADDING);
+
+        // Icons are centered horizontally and sit on the label
+        sheet.draw_pixmap(
+            (cell_x + cell_width.saturating_sub(image.width()) / 2) as i32,
+            (cell_y + size.saturating_sub(image.height())) as i32,
+            image.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+
+        let label_y = cell_y + size + LABEL_GAP;
+        for (line, text, paint) in [
+            (0, &icon.name, &name_paint),
+            (1, &icon.category, &category_paint),
+        ] {
+            let label = shorten(text);
+            let label_width = bitmap_font::text_width(&label, label_scale);
+            bitmap_font::draw_text(
+                &mut sheet,
+                &label,
+                (cell_x + cell_width.saturating_sub(label_width) / 2) as f32,
+                (label_y + line * line_height) as f32,
+                label_scale,
+                paint,
+            );
+        }
+
+        cells.push(PreviewCell {
+            name: icon.name.clone(),
+            category: icon.category.clone(),
+            row,
+            column,
+        });
+    }
+
+    let png = sheet.encode_png().context("Failed to encode PNG")?;
+
+    Ok(PreviewIconsResult {
+        png,
+        width,
+        height,
+        columns,
+        icons: cells,
+    })
+}
+
+fn shorten(text: &str) -> String {
+    if text.chars().count() <= LABEL_CHARS {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(LABEL_CHARS - 2).collect();
+    short.push_str("..");
+    short
+}
+
+/// Parses `#rgb` or `#rrggbb`
+fn parse_color(text: &str) -> Result<(u8, u8, u8), PreviewError> {
+    let invalid = || PreviewError::InvalidParams(format!("Invalid color '{}', expected #rgb or #rrggbb", text));
+    let hex = text.strip_prefix('#').ok_or_else(invalid)?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).map_err(|_| invalid());
+    match hex.len() {
+        3 => {
+            let expand = |i: usize| channel(&hex[i..=i].repeat(2));
+            Ok((expand(0)?, expand(1)?, expand(2)?))
+        }
+        6 => Ok((channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?)),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icon_model::manifest::{Manifest, ManifestIcon};
+    use serde_json::json;
+
+    fn preview(index: &IconIndex, params: serde_json::Value) -> Result<PreviewIconsResult, PreviewError> {
+        preview_icons(index, serde_json::from_value(params).unwrap())
+    }
+
+    fn houses(count: usize) -> serde_json::Value {
+        json!(vec![json!({"name": "house", "category": "solid"}); count])
+    }
+
+    fn assert_invalid(result: Result<PreviewIconsResult, PreviewError>, message: &str) {
+        match result {
+            Err(PreviewError::InvalidParams(e)) => assert!(e.contains(message), "{}", e),
+            Err(e) => panic!("expected invalid params, got {}", e),
+            Ok(_) => panic!("expected invalid params"),
+        }
+    }
+
+    #[test]
+    fn parses_short_and_long_hex_colors() {
+        assert_eq!(parse_color("#abc").unwrap(), (0xaa, 0xbb, 0xcc));
+        assert_eq!(parse_color("#A0b1C2").unwrap(), (0xa0, 0xb1, 0xc2));
+        for color in ["abc", "#", "#ab", "#abcd", "#ggg", "#12345g", "#éé", "red"] {
+            assert!(matches!(parse_color(color), Err(PreviewError::InvalidParams(_))), "{}", color);
+        }
+    }
+
+    #[test]
+    fn shortens_long_labels() {
+        assert_eq!(shorten("sixteen-chars-ok"), "sixteen-chars-ok");
+        assert_eq!(shorten("seventeen-chars-x"), "seventeen-char..");
+        // Counted in characters, not bytes
+        assert_eq!(shorten("ééééééééééééééééé"), "éééééééééééééé..");
+    }
+
+    #[test]
+    fn rejects_out_of_range_sizes_and_counts() {
+        let index = IconIndex::load(&[]).unwrap();
+        for size in [MIN_SIZE, MAX_SIZE] {
+            assert!(preview(&index, json!({"icons": houses(1), "size": size})).is_ok());
+        }
+        for size in [MIN_SIZE - 1, MAX_SIZE + 1] {
+            assert_invalid(preview(&index, json!({"icons": houses(1), "size": size})), "size must be between");
+        }
+        assert_invalid(preview(&index, json!({"icons": houses(0)})), "No icons to preview");
+        assert_invalid(preview(&index, json!({"icons": houses(MAX_ICONS + 1)})), "icons fit on one sheet");
+    }
+
+    #[test]
+    fn rejects_unknown_icons_and_bad_colors() {
+        let index = IconIndex::load(&[]).unwrap();
+        assert_invalid(
+            preview(&index, json!({"icons": [{"name": "no-such-icon", "category": "solid"}]})),
+            "Icon 'no-such-icon' not found in category 'solid'",
+        );
+        assert_invalid(preview(&index, json!({"icons": houses(1), "color": "blue"})), "Invalid color 'blue'");
+        assert_invalid(preview(&index, json!({"icons": houses(1), "background": "#12"})), "Invalid color '#12'");
+        assert!(preview(&index, json!({"icons": houses(1), "background": "transparent"})).is_ok());
+    }
+
+    #[test]
+    fn clamps_columns_to_the_icon_count() {
+        let index = IconIndex::load(&[]).unwrap();
+        let columns = |params: serde_json::Value| preview(&index, params).unwrap().columns;
+        assert_eq!(columns(json!({"icons": houses(5)})), 3);
+        assert_eq!(columns(json!({"icons": houses(5), "columns": 0})), 1);
+        assert_eq!(columns(json!({"icons": houses(3), "columns": 50})), 3);
+
+        let result = preview(&index, json!({"icons": houses(5), "columns": 2})).unwrap();
+        let positions: Vec<(usize, usize)> = result.icons.iter().map(|cell| (cell.row, cell.column)).collect();
+        assert_eq!(positions, [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn shrinks_wide_icons_to_their_cell() {
+        // 20 times wider than tall, far wider than a cell at the default size
+        let manifest = Manifest::new(
+            "license",
+            vec![ManifestIcon::new("custom", "WIDE", "0 0 2000 100", "M0 0h2000v100H0z").unwrap()],
+        );
+        let file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        std::fs::write(file.path(), manifest.to_json()).unwrap();
+        let index = IconIndex::load(&[file.path().to_path_buf()]).unwrap();
+
+        let result = preview(&index, json!({"icons": [{"name": "wide", "category": "custom"}]})).unwrap();
+        let sheet = Pixmap::decode_png(&result.png).unwrap();
+        let cell_width = LABEL_CHARS as u32 * ADVANCE;
+        assert_eq!(sheet.width(), cell_width + 2 * PADDING);
+
+        // The icon keeps its aspect ratio, so it is drawn a twentieth of the cell width high, on the label
+        let inked_rows: Vec<u32> = (PADDING..PADDING + DEFAULT_SIZE)
+            .filter(|&y| (0..sheet.width()).any(|x| sheet.pixel(x, y).unwrap().red() < 128))
+            .collect();
+        let expected = (cell_width / 20) as usize;
+        assert!(inked_rows.len().abs_diff(expected) <= 1, "{} rows inked", inked_rows.len());
+        assert_eq!(inked_rows.last(), Some(&(PADDING + DEFAULT_SIZE - 1)));
+    }
+}
@@ -1,19 +1,51 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 
 use crate::icon_index::IconIndex;
-use crate::types::{SearchIconInfo, SearchIconsParams, SearchIconsResult, IconInfo};
+use crate::types::{SearchIconInfo, SearchIconsParams, SearchIconsResult, SearchSort, IconInfo};
 
+/// Page size when the caller gives no limit
+pub const DEFAULT_LIMIT: usize = 50;
+
+/// Largest page a single call returns
+pub const MAX_LIMIT: usize = 500;
+
+/// Scale from BM25 scores to the fuzzy score range
+const SEMANTIC_SCALE: f32 = 50.0;
+
+/// Keyword matches stay below exact name matches
+const MAX_SEMANTIC_SCORE: i64 = 999;
+
+/// Searches the index for one page of matches. Fails only on invalid paging
+/// parameters: a zero `limit`, which would never advance the cursor, or a
+/// cursor that is not a `next_cursor` of an earlier result.
 pub fn search_icons(index: &IconIndex, params: SearchIconsParams) -> Result<SearchIconsResult> {
+    // The cursor is the offset of the next page
+    let offset = match &params.cursor {
+        Some(cursor) => cursor
+            .parse()
+            .with_context(|| format!("Invalid cursor '{}'", cursor))?,
+        None => params.offset.unwrap_or(0),
+    };
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+    if limit == 0 {
+        bail!("limit must be at least 1");
+    }
+    let limit = limit.min(MAX_LIMIT);
+
     let matcher = SkimMatcherV2::default();
     let query = params.query.to_lowercase();
 
+    // Names, aliases and search terms, with the query expanded by synonyms
+    let mut semantic = index.semantic.search(&query);
+
     // Score and filter icons
-    let mut scored_icons: Vec<(i64, &IconInfo)> = index
+    let mut scored_icons: Vec<(i64, Vec<usize>, Vec<String>, &IconInfo)> = index
         .icons
         .iter()
-        .filter_map(|icon| {
+        .enumerate()
+        .filter_map(|(idx, icon)| {
             // Apply category filter if specified
             if let Some(ref category) = params.category {
                 if icon.category != *category {
@@ -21,39 +53,143 @@ pub fn search_icons(index: &IconIndex, params: SearchIconsParams) -> Result<Sear
                 }
             }
 
+            let keyword = semantic.remove(&idx).map(|keyword| {
+                let score = ((keyword.score * SEMANTIC_SCALE) as i64).min(MAX_SEMANTIC_SCORE);
+                (score, keyword.explanation)
+            });
+
             // Try exact match first
-            if icon.name.contains(&query) {
+            let name_match = if let Some(start) = icon.name.find(&query) {
                 // Boost score for exact substring matches
-                return Some((1000 + (100 - icon.name.len() as i64), icon));
-            }
-
-            // Then try fuzzy matching
-            if let Some(score) = matcher.fuzzy_match(&icon.name, &query) {
-                Some((score, icon))
+                let start = icon.name[..start].chars().count();
+                let positions = (start..start + query.chars().count()).collect();
+                let reason = format!("name contains \"{}\"", query);
+                Some((1000 + (100 - icon.name.len() as i64), positions, reason))
             } else {
-                None
+                // Then try fuzzy matching
+                matcher
+                    .fuzzy_indices(&icon.name, &query)
+                    .map(|(score, positions)| (score, positions, format!("name fuzzy-matches \"{}\"", query)))
+            };
+
+            match (name_match, keyword) {
+                (Some((score, positions, reason)), Some((keyword_score, mut explanation))) => {
+                    explanation.insert(0, reason);
+                    Some((score.max(keyword_score), positions, explanation, icon))
+                }
+                (Some((score, positions, reason)), None) => Some((score, positions, vec![reason], icon)),
+                (None, Some((score, explanation))) => Some((score, Vec::new(), explanation, icon)),
+                (None, None) => None,
             }
         })
+        .filter(|(score, _, _, _)| params.min_score.is_none_or(|min_score| *score >= min_score))
         .collect();
 
-    // Sort by score (highest first)
-    scored_icons.sort_by(|a, b| b.0.cmp(&a.0));
+    match params.sort {
+        // Highest score first, ties by name
+        SearchSort::Score => scored_icons.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.3.name.cmp(&b.3.name))),
+        SearchSort::Name => scored_icons.sort_by(|a, b| a.3.name.cmp(&b.3.name).then_with(|| a.3.category.cmp(&b.3.category))),
+        SearchSort::Category => scored_icons.sort_by(|a, b| a.3.category.cmp(&b.3.category).then_with(|| a.3.name.cmp(&b.3.name))),
+    }
 
     let total_matches = scored_icons.len();
-    
-    // Return all matches with simplified data
+    let end = offset.saturating_add(limit).min(total_matches);
+    let next_cursor = (end < total_matches).then(|| end.to_string());
+
+    // Return one page of matches with simplified data
     let icons: Vec<SearchIconInfo> = scored_icons
         .into_iter()
-        .map(|(_, icon)| SearchIconInfo {
+        .skip(offset)
+        .take(limit)
+        .map(|(score, positions, explanation, icon)| SearchIconInfo {
             name: icon.name.clone(),
             rust_name: icon.rust_name.clone(),
             category: icon.category.clone(),
             import_path: icon.import_path.clone(),
+            score,
+            positions,
+            explanation,
         })
         .collect();
 
     Ok(SearchIconsResult {
         icons,
         total_matches,
+        offset,
+        next_cursor,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn search(index: &IconIndex, params: serde_json::Value) -> Result<SearchIconsResult> {
+        search_icons(index, serde_json::from_value(params).unwrap())
+    }
+
+    fn paths(result: &SearchIconsResult) -> Vec<&str> {
+        result.icons.iter().map(|icon| icon.import_path.as_str()).collect()
+    }
+
+    #[test]
+    fn pages_follow_the_full_result() {
+        let index = IconIndex::load(&[]).unwrap();
+        let all = search(&index, json!({"query": "e", "limit": MAX_LIMIT})).unwrap();
+        assert!(all.total_matches > 2, "too few matches to page through");
+        let all_paths = paths(&all);
+
+        let mut paged = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = search(&index, json!({"query": "e", "limit": 2, "cursor": cursor})).unwrap();
+            assert_eq!(page.total_matches, all.total_matches);
+            assert_eq!(page.offset, paged.len());
+            paged.extend(page.icons.into_iter().map(|icon| icon.import_path));
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(paged, all_paths.iter().take(MAX_LIMIT).copied().collect::<Vec<_>>());
+
+        // Past the end is an empty last page
+        let past = search(&index, json!({"query": "e", "offset": all.total_matches})).unwrap();
+        assert!(past.icons.is_empty());
+        assert_eq!(past.next_cursor, None);
+    }
+
+    #[test]
+    fn sort_modes() {
+        let index = IconIndex::load(&[]).unwrap();
+
+        let by_score = search(&index, json!({"query": "e", "limit": MAX_LIMIT})).unwrap();
+        assert!(by_score.icons.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        let by_name = search(&index, json!({"query": "e", "limit": MAX_LIMIT, "sort": "name"})).unwrap();
+        assert!(by_name.icons.windows(2).all(|pair| (&pair[0].name, &pair[0].category) <= (&pair[1].name, &pair[1].category)));
+
+        let by_category = search(&index, json!({"query": "e", "limit": MAX_LIMIT, "sort": "category"})).unwrap();
+        assert!(by_category.icons.windows(2).all(|pair| (&pair[0].category, &pair[0].name) <= (&pair[1].category, &pair[1].name)));
+
+        // Sorting reorders the same matches
+        let mut sorted = [paths(&by_score), paths(&by_name), paths(&by_category)];
+        for paths in &mut sorted {
+            paths.sort();
+        }
+        assert_eq!(sorted[0], sorted[1]);
+        assert_eq!(sorted[0], sorted[2]);
+    }
+
+    #[test]
+    fn rejects_invalid_paging() {
+        let index = IconIndex::load(&[]).unwrap();
+
+        let error = search(&index, json!({"query": "a", "limit": 0})).unwrap_err();
+        assert_eq!(error.to_string(), "limit must be at least 1");
+
+        let error = search(&index, json!({"query": "a", "cursor": "page-2"})).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid cursor 'page-2'"), "{}", error);
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Result};
+
+use crate::icon_index::IconIndex;
+use crate::types::{FindSimilarIconsParams, FindSimilarIconsResult, IconInfo, SimilarIconInfo};
+
+/// Number of icons returned when the caller gives no limit
+pub const DEFAULT_LIMIT: usize = 10;
+
+/// Most icons a single call returns
+pub const MAX_LIMIT: usize = 100;
+
+/// Bits in a perceptual hash
+const HASH_BITS: u32 = 64;
+
+fn similar_icon(icon: &IconInfo, distance: u32) -> SimilarIconInfo {
+    SimilarIconInfo {
+        name: icon.name.clone(),
+        category: icon.category.clone(),
+        import_path: icon.import_path.clone(),
+        distance,
+        similarity: 1.0 - distance as f32 / HASH_BITS as f32,
+    }
+}
+
+/// Icons whose rendered shapes are closest to the given one, by Hamming distance of perceptual hashes
+pub fn find_similar_icons(index: &IconIndex, params: FindSimilarIconsParams) -> Result<FindSimilarIconsResult> {
+    let icon = index
+        .find_icon(&params.name, &params.category)
+        .ok_or_else(|| anyhow!("Icon '{}' not found in category '{}'", params.name, params.category))?;
+    let hash = icon.phash.ok_or_else(|| {
+        anyhow!(
+            "Icon '{}' in category '{}' has no perceptual hash; regenerate its manifest with icon-processor",
+            icon.name,
+            icon.category
+        )
+    })?;
+
+    let mut similar: Vec<(u32, &IconInfo)> = index
+        .icons
+        .iter()
+        .filter(|other| !(other.name == icon.name && other.category == icon.category))
+        .filter(|other| params.categories.is_empty() || params.categories.contains(&other.category))
+        .filter_map(|other| Some(((other.phash? ^ hash).count_ones(), other)))
+        .collect();
+    similar.sort_by(|(a_distance, a), (b_distance, b)| {
+        a_distance
+            .cmp(b_distance)
+            .then_with(|| a.category.cmp(&b.category))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    Ok(FindSimilarIconsResult {
+        icon: similar_icon(icon, 0),
+        similar: similar
+            .into_iter()
+            .take(limit)
+            .map(|(distance, other)| similar_icon(other, distance))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icon_model::manifest::{Manifest, ManifestIcon};
+    use serde_json::json;
+
+    /// The built-in icons plus ones with the given perceptual hashes, as (category, RUST_NAME, hash)
+    fn index_with(icons: &[(&str, &str, u64)]) -> IconIndex {
+        let icons = icons
+            .iter()
+            .map(|&(category, rust_name, hash)| {
+                let mut icon = ManifestIcon::new(category, rust_name, "0 0 10 10", "M0 0h10v10H0z").unwrap();
+                icon.phash = Some(format!("{:016x}", hash));
+                icon
+            })
+            .collect();
+        let file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        std::fs::write(file.path(), Manifest::new("license", icons).to_json()).unwrap();
+        IconIndex::load(&[file.path().to_path_buf()]).unwrap()
+    }
+
+    fn similar(index: &IconIndex, params: serde_json::Value) -> Vec<(String, String, u32)> {
+        find_similar_icons(index, serde_json::from_value(params).unwrap())
+            .unwrap()
+            .similar
+            .into_iter()
+            .map(|icon| (icon.category, icon.name, icon.distance))
+            .collect()
+    }
+
+    fn entry(category: &str, name: &str, distance: u32) -> (String, String, u32) {
+        (category.to_string(), name.to_string(), distance)
+    }
+
+    #[test]
+    fn excludes_the_icon_itself_and_filters_categories() {
+        let index = index_with(&[
+            ("sim_a", "SQUARE", 0),
+            ("sim_a", "BOX", 0b1),
+            ("sim_b", "SQUARE", 0),
+            ("sim_b", "CUBE", 0b11),
+        ]);
+
+        let both = similar(&index, json!({"name": "square", "category": "sim_a", "categories": ["sim_a", "sim_b"]}));
+        assert_eq!(both, [entry("sim_b", "square", 0), entry("sim_a", "box", 1), entry("sim_b", "cube", 2)]);
+
+        let only_b = similar(&index, json!({"name": "square", "category": "sim_a", "categories": ["sim_b"]}));
+        assert_eq!(only_b, [entry("sim_b", "square", 0), entry("sim_b", "cube", 2)]);
+    }
+
+    #[test]
+    fn orders_ties_by_category_and_name() {
+        let index = index_with(&[
+            ("sim_a", "ORIGIN", 0),
+            ("sim_b", "ZED", 0b1),
+            ("sim_a", "ZED", 0b10),
+            ("sim_a", "ALPHA", 0b100),
+            ("sim_a", "FAR", u64::MAX),
+        ]);
+
+        let result = similar(&index, json!({"name": "origin", "category": "sim_a", "categories": ["sim_a", "sim_b"]}));
+        assert_eq!(
+            result,
+            [
+                entry("sim_a", "alpha", 1),
+                entry("sim_a", "zed", 1),
+                entry("sim_b", "zed", 1),
+                entry("sim_a", "far", HASH_BITS),
+            ]
+        );
+    }
+
+    #[test]
+    fn clamps_the_limit() {
+        let mut icons = vec![("sim_a", "ORIGIN".to_string(), 0)];
+        icons.extend((0..MAX_LIMIT + 20).map(|i| ("sim_a", format!("ICON_{}", i), i as u64)));
+        let icons: Vec<(&str, &str, u64)> = icons
+            .iter()
+            .map(|(category, name, hash)| (*category, name.as_str(), *hash))
+            .collect();
+        let index = index_with(&icons);
+
+        let query = |limit: Option<usize>| {
+            similar(&index, json!({"name": "origin", "category": "sim_a", "categories": ["sim_a"], "limit": limit})).len()
+        };
+        assert_eq!(query(None), DEFAULT_LIMIT);
+        assert_eq!(query(Some(3)), 3);
+        assert_eq!(query(Some(MAX_LIMIT * 10)), MAX_LIMIT);
+    }
+
+    #[test]
+    fn rejects_unknown_icons() {
+        let index = IconIndex::load(&[]).unwrap();
+        let error = find_similar_icons(
+            &index,
+            serde_json::from_value(json!({"name": "no-such-icon", "category": "solid"})).unwrap(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("Icon 'no-such-icon' not found in category 'solid'"));
+    }
+}
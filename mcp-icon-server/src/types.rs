@@ -5,11 +5,36 @@ pub struct IconInfo {
     pub name: String,
     pub rust_name: String,
     pub category: String,
-    pub width: u32,
-    pub height: u32,
     pub view_box: String,
+    pub min_x: f32,
+    pub min_y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// `width / height` of the cropped viewBox
+    pub aspect_ratio: f32,
     pub path_data: String,
     pub import_path: String,
+    /// Other names FontAwesome lists for the icon
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// FontAwesome's search keywords for the icon
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_terms: Vec<String>,
+    /// Perceptual hash of the rendered shape, for finding similar icons
+    #[serde(skip)]
+    pub phash: Option<u64>,
+    /// Placement in the full-size viewBox, when the icon has a full variant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full: Option<FullInfo>,
+}
+
+/// The full variant of an icon: `path_data` drawn at `cropped * scale + offset` inside `view_box`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullInfo {
+    pub view_box: String,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub scale: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +43,25 @@ pub struct SearchIconInfo {
     pub rust_name: String,
     pub category: String,
     pub import_path: String,
+    /// Match score; name substring matches score above 1000
+    pub score: i64,
+    /// Character positions in `name` that matched the query
+    pub positions: Vec<usize>,
+    /// Why the icon matched, e.g. `alias "remove" matches "delete"`
+    pub explanation: Vec<String>,
+}
+
+/// Order of search results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchSort {
+    /// Best match first
+    #[default]
+    Score,
+    /// Alphabetical by icon name
+    Name,
+    /// By category, then icon name
+    Category,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,12 +69,31 @@ pub struct SearchIconsParams {
     pub query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
+    /// Maximum number of icons to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Number of matches to skip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// `next_cursor` of a previous result; takes precedence over `offset`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Drop matches scoring below this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_score: Option<i64>,
+    #[serde(default)]
+    pub sort: SearchSort,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SearchIconsResult {
     pub icons: Vec<SearchIconInfo>,
     pub total_matches: usize,
+    /// Offset of the first returned icon among all matches
+    pub offset: usize,
+    /// Pass as `cursor` to get the next page; absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -45,6 +108,8 @@ pub struct GetIconCodeResult {
     pub import_statement: String,
     pub viewbox_default: String,
     pub viewbox_full: String,
+    /// `width / height` of the default viewBox, for sizing the icon
+    pub aspect_ratio: f32,
     pub props: Vec<PropInfo>,
 }
 
@@ -54,6 +119,8 @@ pub struct PropInfo {
     pub prop_type: String,
     pub required: bool,
     pub default_value: Option<String>,
+    /// Cargo feature of yew-shortcuts the prop needs, if any
+    pub feature: Option<String>,
     pub description: String,
 }
 
@@ -79,4 +146,137 @@ pub struct ListCategoriesResult {
 pub struct CategoryInfo {
     pub name: String,
     pub count: usize,
-}
\ No newline at end of file
+}
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IconRef {
+    pub name: String,
+    pub category: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewIconsParams {
+    pub icons: Vec<IconRef>,
+    /// Icon height in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+    /// Icon color, `#rgb` or `#rrggbb`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Sheet background, `#rgb`, `#rrggbb` or `transparent`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    /// Render the full-size variants instead of the cropped icons
+    #[serde(default)]
+    pub full: bool,
+    /// Icons per row; defaults to a roughly square sheet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewIconsResult {
+    /// The contact sheet, returned as image content rather than JSON
+    #[serde(skip)]
+    pub png: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub columns: usize,
+    /// Icons in reading order, row by row
+    pub icons: Vec<PreviewCell>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewCell {
+    pub name: String,
+    pub category: String,
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditIconUsageParams {
+    /// Directory of the Rust project to scan, within the server's audit root
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// Path relative to the scanned directory
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IconUsage {
+    pub name: String,
+    pub category: String,
+    pub import_path: String,
+    pub locations: Vec<SourceLocation>,
+    /// Estimated bytes of icon data this icon adds to a WASM build
+    pub wasm_bytes: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnknownIconReference {
+    /// The reference as written, e.g. `icons::solid::HOUES`
+    pub reference: String,
+    pub locations: Vec<SourceLocation>,
+    /// Import paths of existing icons it may have meant
+    pub suggestions: Vec<String>,
+}
+
+/// One icon name used in several styles
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StyleConflict {
+    pub name: String,
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditIconUsageResult {
+    pub files_scanned: usize,
+    /// Existing icons referenced by the project, by import path
+    pub icons: Vec<IconUsage>,
+    pub unknown_icons: Vec<UnknownIconReference>,
+    pub inconsistent_styles: Vec<StyleConflict>,
+    /// Number of referenced icons per category
+    pub categories: Vec<CategoryInfo>,
+    /// `<FontAwesomeSvg>` elements in `html!` and other macros
+    pub component_uses: Vec<SourceLocation>,
+    /// Estimated bytes of icon data in a release WASM build, since unused icons are stripped
+    pub estimated_wasm_bytes: usize,
+    /// Files that could not be read or parsed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindSimilarIconsParams {
+    pub name: String,
+    pub category: String,
+    /// Maximum number of icons to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Only return icons of these categories
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimilarIconInfo {
+    pub name: String,
+    pub category: String,
+    pub import_path: String,
+    /// Bits in which the perceptual hashes differ, from 0 to 64
+    pub distance: u32,
+    /// `1 - distance / 64`; 1.0 means the shapes look the same
+    pub similarity: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindSimilarIconsResult {
+    pub icon: SimilarIconInfo,
+    /// Closest icons first, across categories
+    pub similar: Vec<SimilarIconInfo>,
+}
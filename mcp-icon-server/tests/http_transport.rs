@@ -0,0 +1,177 @@
+//! Runs the server with `--transport http` on localhost and talks to it as an MCP client
+
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use rmcp::{
+    ServiceExt,
+    model::{CallToolRequestParam, ClientInfo, ReadResourceRequestParam},
+    transport::StreamableHttpClientTransport,
+};
+use serde_json::{json, Value};
+
+/// Kills the server if a test fails before shutting it down
+struct ServerProcess(Child);
+
+impl Drop for ServerProcess {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Starts the server on an ephemeral port with extra arguments and returns it with its endpoint URL
+fn start_server(args: &[&str]) -> (ServerProcess, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_mcp-icon-server"))
+        .args(["--transport", "http", "--bind", "127.0.0.1:0"])
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start mcp-icon-server");
+
+    let mut lines = BufReader::new(child.stderr.take().unwrap()).lines();
+    let server = ServerProcess(child);
+    let url = lines
+        .by_ref()
+        .map_while(Result::ok)
+        .find_map(|line| line.strip_prefix("Listening on ").map(str::to_string))
+        .expect("server exited without printing its address");
+    // Keep draining stderr so later log lines never block or break the server
+    std::thread::spawn(move || lines.for_each(drop));
+    (server, url)
+}
+
+fn text_content(result: &impl serde::Serialize) -> Value {
+    let result = serde_json::to_value(result).unwrap();
+    let text = result["content"][0]["text"].as_str().expect("tool returned no text content");
+    serde_json::from_str(text).unwrap()
+}
+
+#[tokio::test]
+async fn serves_tools_and_resources_over_http() {
+    let (_server, url) = start_server(&[]);
+    assert!(url.starts_with("http://127.0.0.1:"), "unexpected address {}", url);
+
+    let client = ClientInfo::default()
+        .serve(StreamableHttpClientTransport::from_uri(url))
+        .await
+        .expect("failed to initialize over HTTP");
+
+    let info = client.peer_info().expect("server sent no initialize result");
+    assert!(info.capabilities.tools.is_some());
+    assert!(info.capabilities.resources.is_some());
+
+    let tools = client.list_all_tools().await.unwrap();
+    assert!(tools.iter().any(|tool| tool.name == "search_icons"));
+
+    let result = client
+        .call_tool(CallToolRequestParam {
+            name: "get_icon_details".into(),
+            arguments: json!({ "name": "house", "category": "solid" }).as_object().cloned(),
+        })
+        .await
+        .unwrap();
+    let details = text_content(&result);
+    assert_eq!(details["icon"]["name"], "house");
+    assert_eq!(details["icon"]["import_path"], "icons::solid::HOUSE");
+
+    let resource = client
+        .read_resource(ReadResourceRequestParam { uri: "fa://solid/house.svg".into() })
+        .await
+        .unwrap();
+    let resource = serde_json::to_value(resource).unwrap();
+    assert_eq!(resource["contents"][0]["mimeType"], "image/svg+xml");
+    assert!(resource["contents"][0]["text"].as_str().unwrap().starts_with("<svg"));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn audit_needs_an_audit_root() {
+    let (_server, url) = start_server(&[]);
+    let client = ClientInfo::default()
+        .serve(StreamableHttpClientTransport::from_uri(url))
+        .await
+        .unwrap();
+    let tools = client.list_all_tools().await.unwrap();
+    assert!(!tools.iter().any(|tool| tool.name == "audit_icon_usage"));
+    let result = client
+        .call_tool(CallToolRequestParam {
+            name: "audit_icon_usage".into(),
+            arguments: json!({ "path": "/" }).as_object().cloned(),
+        })
+        .await;
+    assert!(result.is_err());
+    client.cancel().await.unwrap();
+
+    let root = env!("CARGO_MANIFEST_DIR");
+    let (_server, url) = start_server(&["--audit-root", root]);
+    let client = ClientInfo::default()
+        .serve(StreamableHttpClientTransport::from_uri(url))
+        .await
+        .unwrap();
+    let tools = client.list_all_tools().await.unwrap();
+    assert!(tools.iter().any(|tool| tool.name == "audit_icon_usage"));
+    let result = client
+        .call_tool(CallToolRequestParam {
+            name: "audit_icon_usage".into(),
+            arguments: json!({ "path": "src" }).as_object().cloned(),
+        })
+        .await
+        .unwrap();
+    assert!(text_content(&result)["files_scanned"].as_u64().unwrap() > 0);
+    let outside = client
+        .call_tool(CallToolRequestParam {
+            name: "audit_icon_usage".into(),
+            arguments: json!({ "path": ".." }).as_object().cloned(),
+        })
+        .await;
+    assert!(outside.is_err());
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn sessions_are_independent() {
+    let (_server, url) = start_server(&[]);
+
+    let first = ClientInfo::default()
+        .serve(StreamableHttpClientTransport::from_uri(url.clone()))
+        .await
+        .unwrap();
+    let second = ClientInfo::default()
+        .serve(StreamableHttpClientTransport::from_uri(url))
+        .await
+        .unwrap();
+
+    // Closing one session leaves the other usable
+    first.cancel().await.unwrap();
+    let tools = second.list_all_tools().await.unwrap();
+    assert!(!tools.is_empty());
+
+    second.cancel().await.unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn shuts_down_gracefully_on_sigterm() {
+    let (mut server, _url) = start_server(&[]);
+
+    let status = Command::new("kill")
+        .args(["-TERM", &server.0.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    // No connections are open, so the server should exit cleanly well within its grace period
+    for _ in 0..50 {
+        if let Some(status) = server.0.try_wait().unwrap() {
+            assert!(status.success(), "server exited with {}", status);
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    panic!("server did not shut down within 5 seconds of SIGTERM");
+}
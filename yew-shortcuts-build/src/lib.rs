@@ -0,0 +1,259 @@
+//! Build-script helpers for yew-shortcuts
+//!
+//! Generates an icon module from a folder of your own SVGs, in the same shape
+//! as `yew_shortcuts::fontawesome`: one module per subdirectory, an `Icon`
+//! constant per file and the shared license comment.
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use yew_shortcuts_build::{generate_icons, IconsConfig};
+//!
+//! fn main() {
+//!     generate_icons(IconsConfig {
+//!         dir: "assets/icons",
+//!         module: "app_icons",
+//!         ..Default::default()
+//!     })
+//!     .unwrap();
+//! }
+//! ```
+//!
+//! ```rust,ignore
+//! // src/main.rs
+//! #[allow(dead_code)]
+//! mod app_icons {
+//!     include!(concat!(env!("OUT_DIR"), "/app_icons.rs"));
+//! }
+//! ```
+
+use icon_model::codegen::{custom_category, generate_custom_module};
+use icon_model::custom::{load_custom_icon_dir, CustomIcon};
+use icon_model::naming::{self, rust_name_to_kebab_case, symbol_id};
+use icon_model::{SvgError, SvgErrorKind};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where to read icons from and what to call the generated module
+#[derive(Debug, Clone)]
+pub struct IconsConfig<'a> {
+    /// Directory of SVG files, relative to `CARGO_MANIFEST_DIR`.
+    /// Subdirectories become category modules.
+    pub dir: &'a str,
+    /// Name of the generated file in `OUT_DIR`, without the `.rs` extension
+    pub module: &'a str,
+    /// Path the generated code uses to refer to yew-shortcuts
+    pub crate_path: &'a str,
+}
+
+impl Default for IconsConfig<'_> {
+    fn default() -> Self {
+        Self {
+            dir: "icons",
+            module: "icons",
+            crate_path: "::yew_shortcuts",
+        }
+    }
+}
+
+/// Why the icon module could not be generated
+#[derive(Debug)]
+pub enum Error {
+    /// `CARGO_MANIFEST_DIR` or `OUT_DIR` is not set; not running in a build script
+    MissingEnv(&'static str),
+    Io(io::Error),
+    /// SVG files that could not be read or are unsupported
+    Svg(Vec<SvgError>),
+    /// File or directory names that don't make valid or unique Rust identifiers
+    /// or sprite ids
+    Identifiers(Vec<String>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingEnv(var) => write!(f, "{} is not set; call generate_icons from build.rs", var),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Svg(errors) => {
+                writeln!(f, "{} icon(s) could not be compiled:", errors.len())?;
+                for error in errors {
+                    writeln!(f, "  {}", error)?;
+                }
+                Ok(())
+            }
+            Error::Identifiers(problems) => {
+                writeln!(f, "icon names don't map to Rust identifiers:")?;
+                for problem in problems {
+                    writeln!(f, "  {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// Generates `$OUT_DIR/<module>.rs` from the SVGs in `config.dir` and returns its path.
+///
+/// The icons' sprite ids start with `fa-custom-<module>`, so they can't clash
+/// with the FontAwesome icons or with another generated module.
+pub fn generate_icons(config: IconsConfig) -> Result<PathBuf, Error> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| Error::MissingEnv("CARGO_MANIFEST_DIR"))?;
+    let out_dir = env::var("OUT_DIR").map_err(|_| Error::MissingEnv("OUT_DIR"))?;
+    generate_icons_in(&Path::new(&manifest_dir).join(config.dir), Path::new(&out_dir), &config)
+}
+
+fn generate_icons_in(dir: &Path, out_dir: &Path, config: &IconsConfig) -> Result<PathBuf, Error> {
+    if !dir.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("icon directory {} does not exist", dir.display()),
+        )
+        .into());
+    }
+
+    let set = load_custom_icon_dir(dir);
+
+    // Rerun when an icon changes, or when files are added or removed
+    println!("cargo:rerun-if-changed={}", dir.display());
+    for custom in &set.icons {
+        println!("cargo:rerun-if-changed={}", custom.icon.file_path);
+    }
+    for error in set.errors.iter().filter(|e| e.kind != SvgErrorKind::LicenseMismatch) {
+        println!("cargo:rerun-if-changed={}", error.file);
+    }
+
+    let (warnings, errors): (Vec<SvgError>, Vec<SvgError>) = set
+        .errors
+        .into_iter()
+        .partition(|e| e.kind == SvgErrorKind::LicenseMismatch);
+    for warning in warnings {
+        println!("cargo:warning={}", warning);
+    }
+    if !errors.is_empty() {
+        return Err(Error::Svg(errors));
+    }
+
+    check_identifiers(&set.icons, config.module)?;
+
+    let code = generate_custom_module(&set.icons, &set.license, config.crate_path, config.module);
+    let output_path = out_dir.join(format!("{}.rs", config.module));
+    fs::write(&output_path, code)?;
+    Ok(output_path)
+}
+
+/// Same rules `icon-processor` applies to the FontAwesome names, plus unique
+/// sprite ids: `solid-logo.svg` and `solid/logo.svg` would both be `fa-custom-<module>-solid-logo`
+fn check_identifiers(icons: &[CustomIcon], module: &str) -> Result<(), Error> {
+    let mut problems: Vec<String> = naming::check_identifiers(icons.iter().map(|custom| &custom.icon))
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    let mut files_by_symbol: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for custom in icons {
+        let icon = &custom.icon;
+        let id = symbol_id(&custom_category(module, &icon.category), &rust_name_to_kebab_case(&icon.name));
+        files_by_symbol.entry(id).or_default().push(&icon.file_path);
+    }
+    for (id, files) in files_by_symbol {
+        if files.len() > 1 {
+            problems.push(format!("{} all get the sprite id {}", files.join(", "), id));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Identifiers(problems))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M2 2h20v20H2z"/></svg>"#;
+
+    /// Writes the given files below a fresh icon directory and generates the module
+    fn generate(files: &[(&str, &str)]) -> Result<String, Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let icons = dir.path().join("icons");
+        for (file, svg) in files {
+            let path = icons.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, svg).unwrap();
+        }
+        let config = IconsConfig { module: "app_icons", ..Default::default() };
+        let path = generate_icons_in(&icons, dir.path(), &config)?;
+        assert_eq!(path, dir.path().join("app_icons.rs"));
+        Ok(fs::read_to_string(path).unwrap())
+    }
+
+    fn identifier_problems(result: Result<String, Error>) -> Vec<String> {
+        match result {
+            Err(Error::Identifiers(problems)) => problems,
+            other => panic!("expected identifier problems, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn generates_one_module_per_subdirectory() {
+        let code = generate(&[("logo.svg", SQUARE), ("social-media/github.svg", SQUARE), ("solid/house.svg", SQUARE)]).unwrap();
+
+        assert!(code.contains("pub const LOGO: &Icon = &::yew_shortcuts::__private::icon(\n    \"custom-app_icons\",\n    \"logo\","));
+        assert!(code.contains("pub mod social_media {"));
+        // A `solid` directory does not share FontAwesome's `solid` sprite ids
+        assert!(code.contains("    pub const HOUSE: &Icon = &::yew_shortcuts::__private::icon(\n        \"custom-app_icons-solid\","));
+        assert!(code.contains("pub mod icons {\n    pub use super::social_media;\n    pub use super::solid;\n}"));
+    }
+
+    #[test]
+    fn rejects_colliding_names() {
+        let problems = identifier_problems(generate(&[("arrow-up.svg", SQUARE), ("arrow_up.svg", SQUARE)]));
+        assert_eq!(problems.len(), 2, "{:#?}", problems);
+        assert!(problems[0].starts_with("ARROW_UP is generated from 2 files: "), "{}", problems[0]);
+        assert!(problems[1].ends_with(" all get the sprite id fa-custom-app_icons-arrow-up"), "{}", problems[1]);
+
+        let problems = identifier_problems(generate(&[("solid-logo.svg", SQUARE), ("solid/logo.svg", SQUARE)]));
+        assert_eq!(problems.len(), 1, "{:#?}", problems);
+        assert!(problems[0].ends_with(" all get the sprite id fa-custom-app_icons-solid-logo"), "{}", problems[0]);
+    }
+
+    #[test]
+    fn rejects_invalid_module_names() {
+        let problems = identifier_problems(generate(&[("type/house.svg", SQUARE), ("my.icons/house.svg", SQUARE)]));
+        assert_eq!(problems.len(), 2, "{:#?}", problems);
+        assert!(problems[0].ends_with("category 'my.icons' is not a valid Rust module name"), "{}", problems[0]);
+        assert!(problems[1].ends_with("category module name 'type' is a Rust keyword"), "{}", problems[1]);
+    }
+
+    #[test]
+    fn reports_unsupported_svgs() {
+        let circle = r#"<svg viewBox="0 0 24 24"><circle r="4"/></svg>"#;
+        match generate(&[("logo.svg", SQUARE), ("dot.svg", circle)]) {
+            Err(Error::Svg(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].file.ends_with("dot.svg"));
+            }
+            other => panic!("expected an SVG error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn requires_an_existing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = generate_icons_in(&dir.path().join("missing"), dir.path(), &IconsConfig::default());
+        assert!(matches!(result, Err(Error::Io(e)) if e.kind() == io::ErrorKind::NotFound));
+    }
+}
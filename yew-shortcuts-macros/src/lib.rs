@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, punctuated::Punctuated, Ident, Meta, Path, PathArguments};
+use syn::{parse_macro_input, punctuated::Punctuated, Ident, LitStr, Meta, Path, PathArguments};
 
 /// Clone multiple variables at once for use in closures.
 /// 
@@ -106,3 +106,99 @@ pub fn comp(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     TokenStream::from(quote::quote!(#input))
 }
+
+/// Compiles a project-local SVG file into a `&'static Icon` for `FontAwesomeSvg`.
+///
+/// The path is relative to the crate's `CARGO_MANIFEST_DIR`. The file must
+/// contain a single `<path>` without transforms; the viewBox is recomputed to
+/// fit the path tightly, like the cropped FontAwesome icons.
+///
+/// The icon's category is `custom-` followed by a hash of its path data, so
+/// files with the same name in different directories get distinct sprite ids.
+///
+/// # Example
+/// ```rust,ignore
+/// use yew_shortcuts::{include_svg_icon, FontAwesomeSvg};
+/// use yew_shortcuts::fontawesome::Icon;
+///
+/// const LOGO: &Icon = include_svg_icon!("assets/logo.svg");
+///
+/// html! { <FontAwesomeSvg icon={LOGO} /> }
+/// ```
+#[proc_macro]
+pub fn include_svg_icon(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = std::path::Path::new(&manifest_dir).join(lit.value());
+
+    let custom = match icon_model::custom::load_custom_icon(&path) {
+        Ok(custom) => custom,
+        Err(e) => {
+            // The absolute path is noise; the literal already says which file
+            let message = format!("include_svg_icon!({:?}): {}", lit.value(), e.message);
+            return syn::Error::new(lit.span(), message).to_compile_error().into();
+        }
+    };
+
+    let file = path.display().to_string();
+    let category = custom_category(&custom.icon.path_data);
+    let name = icon_model::naming::rust_name_to_kebab_case(&custom.icon.name);
+    let view_box = &custom.cropped_view_box;
+    let bounds: proc_macro2::TokenStream = icon_model::codegen::view_box_literal(
+        view_box,
+        "::yew_shortcuts::fontawesome::ViewBox",
+    )
+    .parse()
+    .expect("view_box_literal emits a valid struct literal");
+    let d = &custom.icon.path_data;
+    let optical_center: proc_macro2::TokenStream =
+        icon_model::codegen::optical_center_literal(view_box, d)
+            .parse()
+            .expect("optical_center_literal emits a valid tuple");
+    let offset_x = custom.full.offset_x;
+    let offset_y = custom.full.offset_y;
+    let scale = custom.full.scale;
+
+    TokenStream::from(quote! {
+        {
+            // Rebuild when the SVG changes
+            const _: &[u8] = include_bytes!(#file);
+            const ICON: &::yew_shortcuts::fontawesome::Icon =
+                &::yew_shortcuts::__private::icon(
+                    #category,
+                    #name,
+                    ::yew_shortcuts::fontawesome::CroppedIcon {
+                        view_box: #view_box,
+                        bounds: #bounds,
+                        optical_center: #optical_center,
+                        d: #d,
+                    },
+                    #offset_x,
+                    #offset_y,
+                    #scale,
+                );
+            ICON
+        }
+    })
+}
+
+/// `custom-` and the first 8 hex digits of the path data's geometry hash
+fn custom_category(path_data: &str) -> String {
+    let hash = format!("{:016x}", icon_model::manifest::geometry_hash(path_data));
+    format!("custom-{}", &hash[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_categories_follow_the_path_data() {
+        let logo = custom_category("M0 0h24v24H0z");
+        assert!(logo.starts_with("custom-") && logo.len() == "custom-".len() + 8, "{}", logo);
+        // The same drawing keeps its id; a different one with the same file name does not collide
+        assert_eq!(custom_category("M0 0h24v24H0z"), logo);
+        assert_ne!(custom_category("M0 0h24v12H0z"), logo);
+    }
+}
@@ -0,0 +1,7 @@
+//! Compile errors reported by the macros
+
+#[test]
+fn include_svg_icon_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
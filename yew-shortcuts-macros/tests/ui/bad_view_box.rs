@@ -0,0 +1,4 @@
+// trybuild builds from target/tests/trybuild/yew-shortcuts-macros, so paths start at the workspace root
+fn main() {
+    let _ = yew_shortcuts_macros::include_svg_icon!("../../../../yew-shortcuts-macros/tests/ui/svg/bad-view-box.svg");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = yew_shortcuts_macros::include_svg_icon!("tests/ui/svg/does-not-exist.svg");
+}
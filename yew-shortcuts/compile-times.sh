@@ -0,0 +1,89 @@
+#!/bin/bash
+#
+# Measures how long yew-shortcuts takes to compile with each icon feature set
+# and writes the results to COMPILE_TIMES.md in the project root.
+#
+# Dependencies are built once up front, so each measurement only covers
+# yew-shortcuts itself. Every feature set is built RUNS times from a clean
+# yew-shortcuts, then RUNS times after touching src/lib.rs, and the median
+# wall-clock times are reported.
+#
+# The results depend on the icons that were generated into src/fontawesome/,
+# so the icon counts of each category are recorded alongside them.
+
+set -e
+
+RUNS=${RUNS:-3}
+FEATURE_SETS=("" "fa-solid" "fa-regular" "fa-brands" "fa-all" "fa-all,full-svg")
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" && pwd )"
+PROJECT_ROOT="$(dirname "$SCRIPT_DIR")"
+OUTPUT="$PROJECT_ROOT/COMPILE_TIMES.md"
+TARGET_DIR="$(mktemp -d)"
+trap 'rm -rf "$TARGET_DIR"' EXIT
+
+cd "$PROJECT_ROOT"
+
+build() {
+    cargo build --quiet --package yew-shortcuts --no-default-features \
+        --features "$1" --target-dir "$TARGET_DIR"
+}
+
+# Wall-clock seconds of a build after running the given preparation command
+timed_build() {
+    $2
+    start=$(date +%s.%N)
+    build "$1"
+    end=$(date +%s.%N)
+    awk -v start="$start" -v end="$end" 'BEGIN { print end - start }'
+}
+
+clean() {
+    cargo clean --quiet --package yew-shortcuts --target-dir "$TARGET_DIR"
+}
+
+touch_crate() {
+    touch "$SCRIPT_DIR/src/lib.rs"
+}
+
+echo "Building dependencies..."
+build ""
+
+median() {
+    sort -n | awk '{ values[NR] = $1 } END { print values[int((NR + 1) / 2)] }'
+}
+
+cpu=$(grep -m1 "model name" /proc/cpuinfo 2>/dev/null | cut -d: -f2 | sed 's/^ //')
+
+{
+    echo "# yew-shortcuts compile times"
+    echo
+    echo "Measured with \`yew-shortcuts/compile-times.sh\` on $(date -u +%Y-%m-%d) at commit $(git rev-parse --short HEAD 2>/dev/null || echo unknown)."
+    echo
+    echo "- Machine: $(uname -sm), ${cpu:-unknown CPU}, $(nproc) cores"
+    echo "- Toolchain: $(rustc --version), $(cargo --version)"
+    echo "- Median of $RUNS debug builds of yew-shortcuts alone, with dependencies prebuilt"
+    echo "- Clean: after \`cargo clean -p yew-shortcuts\`; incremental: after touching \`src/lib.rs\`"
+    echo
+    echo "Icons in the generated module:"
+    echo
+    for file in "$SCRIPT_DIR"/src/fontawesome/*.rs; do
+        [ "$(basename "$file")" = aliases.rs ] && continue
+        echo "- \`$(basename "$file" .rs)\`: $(grep -c ': &Icon = ' "$file") icons"
+    done
+    echo
+    echo "\`fa-all\` compiles every icon, as the single module did before the per-category feature split."
+    echo
+    echo "| Features | Clean build (s) | Incremental build (s) |"
+    echo "|----------|-----------------|-----------------------|"
+} > "$OUTPUT"
+
+for features in "${FEATURE_SETS[@]}"; do
+    label=${features:-"(no icons)"}
+    echo "Measuring $label..."
+    clean_time=$(for _ in $(seq "$RUNS"); do timed_build "$features" clean; done | median)
+    incremental_time=$(for _ in $(seq "$RUNS"); do timed_build "$features" touch_crate; done | median)
+    printf '| `%s` | %.2f | %.2f |\n' "$label" "$clean_time" "$incremental_time" >> "$OUTPUT"
+done
+
+echo "Wrote $OUTPUT"
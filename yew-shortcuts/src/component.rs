@@ -1,8 +1,11 @@
-use crate::fontawesome::{Icon, FONTAWESOME_LICENSE};
+use crate::fit::{view_box_attr, IconFit};
+use crate::fontawesome::{self, Icon};
+use crate::license::LicenseContext;
+use crate::sprite::SpriteContext;
 use yew::prelude::*;
 
 #[cfg(feature = "full-svg")]
-use crate::fontawesome::FULL_VIEW_BOX;
+use crate::fontawesome::{FullIcon, FULL_VIEW_BOX};
 
 /// Properties for FontAwesomeSvg component
 #[derive(Properties, PartialEq)]
@@ -10,11 +13,22 @@ pub struct FontAwesomeSvgProps {
     /// The icon to render
     pub icon: &'static Icon,
 
-    /// Whether to use full SVG mode (when feature enabled)
+    /// Whether to use full SVG mode (when feature enabled). Icons without a
+    /// full variant keep their cropped viewBox.
     #[cfg(feature = "full-svg")]
     #[prop_or(false)]
     pub full: bool,
 
+    /// How to pad the cropped viewBox, e.g. `IconFit::Square` for a uniform grid.
+    /// Ignored when `full` is set.
+    #[prop_or_default]
+    pub fit: IconFit,
+
+    /// Whether to reference the icon from the nearest `IconSpriteProvider`
+    /// instead of inlining its path. Defaults to true inside a provider.
+    #[prop_or_default]
+    pub sprite: Option<bool>,
+
     /// Optional CSS classes to apply to the SVG element
     #[prop_or_default]
     pub classes: Classes,
@@ -31,45 +45,134 @@ pub struct FontAwesomeSvgProps {
 /// FontAwesome SVG component for rendering icons
 #[function_component]
 pub fn FontAwesomeSvg(props: &FontAwesomeSvgProps) -> Html {
+    let sprite = use_context::<SpriteContext>().filter(|_| props.sprite.unwrap_or(true));
+    let license = use_context::<LicenseContext>();
+
+    // Icons are registered while rendering, once per icon and provider: the
+    // sheet and the license notice render after the children in the same
+    // pass, and with server-side rendering no effect ever runs
+    let symbol_id = use_memo((sprite.clone(), props.icon), |(sprite, icon)| {
+        sprite.as_ref().map(|sprite| sprite.0.register(icon))
+    });
+    use_memo((license.clone(), props.icon), |(license, icon)| {
+        if let Some(license) = license {
+            license.0.register(icon);
+        }
+    });
+    let symbol_id = (*symbol_id).clone();
+
+    // Below an IconLicenseProvider the license is attributed once for the page
+    let license_attr = license.is_none().then(|| fontawesome::license(props.icon));
+
+    // Icons registered during render show up in the sheet once it re-renders
+    use_effect_with((symbol_id.clone(), props.icon), move |_| {
+        if let Some(sprite) = sprite {
+            sprite.0.flush();
+        }
+        if let Some(license) = license {
+            license.0.flush();
+        }
+    });
+
     #[cfg(not(feature = "full-svg"))]
-    {
-        let view_box = props.icon.cropped.view_box;
-        let path_d = props.icon.cropped.d;
-
-        html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                viewBox={view_box}
-                class={props.classes.clone()}
-                style={props.style.clone()}
-                onclick={props.onclick.clone()}
-                data-fa-license={FONTAWESOME_LICENSE}
-            >
-                <path fill="currentColor" d={path_d} onclick={props.onclick.clone()} />
-            </svg>
+    let (view_box, transform): (AttrValue, Option<String>) = (fitted_view_box(props), None);
+
+    // The full variant reuses the cropped path, placed on the full canvas
+    #[cfg(feature = "full-svg")]
+    let (view_box, transform) = match &props.icon.full {
+        Some(full) if props.full => {
+            (AttrValue::Static(FULL_VIEW_BOX), Some(full_transform(full)))
         }
+        _ => (fitted_view_box(props), None),
+    };
+
+    let content = match symbol_id {
+        Some(id) => html! {
+            <use href={format!("#{}", id)} {transform} />
+        },
+        None => html! {
+            <path fill="currentColor" {transform} d={props.icon.cropped.d} onclick={props.onclick.clone()} />
+        },
+    };
+
+    html! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox={view_box}
+            class={props.classes.clone()}
+            style={props.style.clone()}
+            onclick={props.onclick.clone()}
+            data-fa-license={license_attr}
+        >
+            { content }
+        </svg>
     }
+}
 
+/// The cropped viewBox padded according to the `fit` prop
+fn fitted_view_box(props: &FontAwesomeSvgProps) -> AttrValue {
+    match props.fit {
+        IconFit::Cropped => AttrValue::Static(props.icon.cropped.view_box),
+        fit => AttrValue::from(view_box_attr(&fit.view_box(props.icon))),
+    }
+}
+
+/// Builds the SVG transform placing a cropped path within [`FULL_VIEW_BOX`]
+#[cfg(feature = "full-svg")]
+fn full_transform(full: &FullIcon) -> String {
+    if full.scale == 1.0 {
+        format!("translate({} {})", full.offset_x, full.offset_y)
+    } else {
+        format!(
+            "translate({} {}) scale({})",
+            full.offset_x, full.offset_y, full.scale
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "full-svg", feature = "fa-solid"))]
+    use super::*;
+
+    /// icon-processor verifies placements by rendering `FullPlacement::transform`,
+    /// so the component must format them identically
     #[cfg(feature = "full-svg")]
-    {
-        let (view_box, path_d) = if props.full {
-            (FULL_VIEW_BOX, props.icon.full.d)
-        } else {
-            (props.icon.cropped.view_box, props.icon.cropped.d)
-        };
-
-        html! {
-            <svg
-                xmlns="http://www.w3.org/2000/svg"
-                viewBox={view_box}
-                class={props.classes.clone()}
-                style={props.style.clone()}
-                onclick={props.onclick.clone()}
-                data-fa-license={FONTAWESOME_LICENSE}
-            >
-                <path fill="currentColor" d={path_d} onclick={props.onclick.clone()} />
-            </svg>
+    #[test]
+    fn full_transform_matches_verified_placements() {
+        use icon_model::full::FullPlacement;
+
+        for (offset_x, offset_y, scale) in [(64.0, 64.0, 1.0), (0.5, -12.25, 1.25), (80.0, 0.0, 0.8125)] {
+            let full = FullIcon { offset_x, offset_y, scale };
+            let placement = FullPlacement { offset_x, offset_y, scale };
+            assert_eq!(full_transform(&full), placement.transform());
         }
     }
-}
 
+    #[cfg(feature = "fa-solid")]
+    #[derive(Properties, PartialEq)]
+    struct FitProps {
+        fit: IconFit,
+    }
+
+    #[cfg(feature = "fa-solid")]
+    #[function_component]
+    fn Fitted(props: &FitProps) -> Html {
+        html! { <FontAwesomeSvg icon={fontawesome::icons::solid::HOUSE} fit={props.fit} /> }
+    }
+
+    /// `IconFit::Full` is centered on the optical center with or without
+    /// `full-svg`; only `full` uses FontAwesome's placement
+    #[cfg(feature = "fa-solid")]
+    #[tokio::test]
+    async fn full_fit_renders_the_same_with_every_feature_set() {
+        let html = yew::LocalServerRenderer::<Fitted>::with_props(FitProps { fit: IconFit::Full })
+            .hydratable(false)
+            .render()
+            .await;
+
+        let view_box = view_box_attr(&IconFit::Full.view_box(fontawesome::icons::solid::HOUSE));
+        assert!(html.contains(&format!(r#"viewBox="{}""#, view_box)), "{}", html);
+        assert!(!html.contains("transform="), "{}", html);
+    }
+}
@@ -0,0 +1,132 @@
+//! Padding cropped icons to a common grid
+//!
+//! Cropped viewBoxes hug the ink, so icons of different shapes render at
+//! different widths. [`IconFit`] pads the viewBox to a consistent shape around
+//! the icon's optical center, using only the cropped data.
+
+use crate::fontawesome::{Icon, ViewBox};
+
+/// Height of FontAwesome's full canvas relative to the glyph height (640 / 512)
+const FULL_CANVAS_SCALE: f32 = 1.25;
+
+/// How an icon's viewBox is shaped before rendering
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum IconFit {
+    /// The cropped viewBox as generated
+    #[default]
+    Cropped,
+    /// A square the size of FontAwesome's full canvas, without needing the
+    /// `full-svg` feature: 1.25 times the icon's height, or its width if that
+    /// is larger, as the full variants are generated. Unlike `full` mode it is
+    /// centered on the optical center rather than the bounding box.
+    Full,
+    /// The smallest square around the icon
+    Square,
+    /// The smallest box of the given width / height ratio around the icon
+    FixedWidth(f32),
+}
+
+impl IconFit {
+    /// The viewBox to render `icon` with
+    pub fn view_box(&self, icon: &Icon) -> ViewBox {
+        let bounds = icon.cropped.bounds;
+        let (width, height) = match *self {
+            IconFit::Cropped => return bounds,
+            IconFit::Full => {
+                let side = (bounds.height * FULL_CANVAS_SCALE).max(bounds.width);
+                (side, side)
+            }
+            IconFit::Square => {
+                let side = bounds.width.max(bounds.height);
+                (side, side)
+            }
+            IconFit::FixedWidth(ratio) if ratio > 0.0 => {
+                if bounds.aspect_ratio() > ratio {
+                    (bounds.width, bounds.width / ratio)
+                } else {
+                    (bounds.height * ratio, bounds.height)
+                }
+            }
+            IconFit::FixedWidth(_) => return bounds,
+        };
+        pad(bounds, icon.cropped.optical_center, width, height)
+    }
+}
+
+/// Grows `bounds` to `width` x `height`, centered on `center` as far as the
+/// original bounds stay inside
+fn pad(bounds: ViewBox, (center_x, center_y): (f32, f32), width: f32, height: f32) -> ViewBox {
+    let axis = |min: f32, extent: f32, center: f32, size: f32| {
+        (center - size / 2.0).min(min).max(min + extent - size)
+    };
+    ViewBox {
+        min_x: axis(bounds.min_x, bounds.width, center_x, width),
+        min_y: axis(bounds.min_y, bounds.height, center_y, height),
+        width,
+        height,
+    }
+}
+
+/// Formats a viewBox as an SVG attribute value
+pub(crate) fn view_box_attr(view_box: &ViewBox) -> String {
+    format!(
+        "{} {} {} {}",
+        view_box.min_x, view_box.min_y, view_box.width, view_box.height
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fontawesome::CroppedIcon;
+
+    /// An icon with the given cropped bounds, its optical center in the middle
+    fn icon(min_x: f32, min_y: f32, width: f32, height: f32) -> Icon {
+        let bounds = ViewBox { min_x, min_y, width, height };
+        let center = (min_x + width / 2.0, min_y + height / 2.0);
+        crate::__private::icon(
+            "test",
+            "box",
+            CroppedIcon { view_box: "", bounds, optical_center: center, d: "" },
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    fn size(view_box: ViewBox) -> (f32, f32) {
+        (view_box.width, view_box.height)
+    }
+
+    #[test]
+    fn full_uses_the_full_canvas_proportions() {
+        // Tall and square icons are 512 of 640 units high
+        assert_eq!(size(IconFit::Full.view_box(&icon(0.0, 0.0, 320.0, 512.0))), (640.0, 640.0));
+        assert_eq!(size(IconFit::Full.view_box(&icon(0.0, 0.0, 512.0, 512.0))), (640.0, 640.0));
+        assert_eq!(size(IconFit::Full.view_box(&icon(0.0, 0.0, 576.0, 512.0))), (640.0, 640.0));
+        // Wider icons span the canvas
+        assert_eq!(size(IconFit::Full.view_box(&icon(0.0, 0.0, 32.0, 16.0))), (32.0, 32.0));
+    }
+
+    #[test]
+    fn square_and_fixed_width() {
+        assert_eq!(size(IconFit::Square.view_box(&icon(0.0, 0.0, 320.0, 512.0))), (512.0, 512.0));
+        assert_eq!(size(IconFit::Square.view_box(&icon(0.0, 0.0, 640.0, 512.0))), (640.0, 640.0));
+        assert_eq!(size(IconFit::FixedWidth(1.25).view_box(&icon(0.0, 0.0, 320.0, 512.0))), (640.0, 512.0));
+        assert_eq!(size(IconFit::FixedWidth(1.0).view_box(&icon(0.0, 0.0, 640.0, 512.0))), (640.0, 640.0));
+        // Invalid ratios leave the icon cropped
+        assert_eq!(size(IconFit::FixedWidth(0.0).view_box(&icon(0.0, 0.0, 320.0, 512.0))), (320.0, 512.0));
+    }
+
+    #[test]
+    fn padding_centers_on_the_optical_center() {
+        let view_box = IconFit::Square.view_box(&icon(10.0, 20.0, 100.0, 50.0));
+        assert_eq!(view_box, ViewBox { min_x: 10.0, min_y: -5.0, width: 100.0, height: 100.0 });
+
+        // Off-center ink is padded unevenly, but the padded box still holds the bounds
+        let mut lopsided = icon(0.0, 0.0, 100.0, 100.0);
+        lopsided.cropped.optical_center = (90.0, 50.0);
+        let view_box = IconFit::Full.view_box(&lopsided);
+        assert_eq!(view_box, ViewBox { min_x: 0.0, min_y: -12.5, width: 125.0, height: 125.0 });
+    }
+}
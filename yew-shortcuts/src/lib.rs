@@ -3,7 +3,7 @@
 //! This crate provides shortcuts and utilities to make Yew development more ergonomic.
 
 // Re-export macros from the proc-macro crate
-pub use yew_shortcuts_macros::{cs, comp};
+pub use yew_shortcuts_macros::{cs, comp, include_svg_icon};
 
 // FontAwesome module
 pub mod fontawesome;
@@ -11,5 +11,55 @@ pub mod fontawesome;
 // Component module
 mod component;
 
+// Sprite-sheet module
+mod sprite;
+
+// viewBox padding module
+mod fit;
+
+// Page-level license module
+mod license;
+
 // Re-export FontAwesomeSvg component
-pub use component::FontAwesomeSvg;
\ No newline at end of file
+pub use component::FontAwesomeSvg;
+
+// Re-export viewBox fitting
+pub use fit::IconFit;
+
+// Re-export sprite-sheet support
+pub use sprite::{symbol_id, IconSpriteProvider};
+
+// Re-export page-level license attribution
+pub use license::IconLicenseProvider;
+
+// Support code for icons generated outside this crate
+#[doc(hidden)]
+pub mod __private {
+    use crate::fontawesome::{CroppedIcon, Icon};
+    #[cfg(feature = "full-svg")]
+    use crate::fontawesome::FullIcon;
+
+    /// Builds an `Icon`, deciding here rather than in the caller's crate
+    /// whether the `full-svg` variant exists
+    #[cfg_attr(not(feature = "full-svg"), allow(unused_variables))]
+    pub const fn icon(
+        category: &'static str,
+        name: &'static str,
+        cropped: CroppedIcon,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+    ) -> Icon {
+        Icon {
+            category,
+            name,
+            cropped,
+            #[cfg(feature = "full-svg")]
+            full: Some(FullIcon {
+                offset_x,
+                offset_y,
+                scale,
+            }),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Page-level license attribution: instead of a `data-fa-license` attribute
+//! on every SVG, icons below an [`IconLicenseProvider`] report their license
+//! once to a single hidden element
+
+use crate::fontawesome::{self, Icon};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Distinct licenses of the icons used below an [`IconLicenseProvider`]
+#[derive(Default)]
+pub(crate) struct LicenseRegistry {
+    licenses: RefCell<BTreeSet<&'static str>>,
+    /// Set when a license was added after the element last rendered
+    dirty: Cell<bool>,
+    /// Re-renders the element
+    refresh: RefCell<Option<Callback<()>>>,
+}
+
+impl LicenseRegistry {
+    /// Records the license of an icon
+    pub(crate) fn register(&self, icon: &Icon) {
+        if self.licenses.borrow_mut().insert(fontawesome::license(icon)) {
+            self.dirty.set(true);
+        }
+    }
+
+    /// Re-renders the element if licenses were added since it last rendered
+    pub(crate) fn flush(&self) {
+        if self.dirty.get() {
+            if let Some(refresh) = &*self.refresh.borrow() {
+                refresh.emit(());
+            }
+        }
+    }
+}
+
+/// Handle to the registry of the nearest [`IconLicenseProvider`]
+#[derive(Clone)]
+pub(crate) struct LicenseContext(pub(crate) Rc<LicenseRegistry>);
+
+impl PartialEq for LicenseContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Properties for IconLicenseProvider component
+#[derive(Properties, PartialEq)]
+pub struct IconLicenseProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Moves the license attribution of descendant `FontAwesomeSvg` instances to
+/// one hidden element, rendered after the children as
+/// `<div hidden="" data-fa-license="...">` with one license per line.
+///
+/// Wrap the whole page in it to keep the attribution without repeating the
+/// license text on every icon.
+#[function_component]
+pub fn IconLicenseProvider(props: &IconLicenseProviderProps) -> Html {
+    let context = use_memo((), |_| LicenseContext(Rc::default()));
+
+    html! {
+        <ContextProvider<LicenseContext> context={(*context).clone()}>
+            { props.children.clone() }
+            <LicenseNotice />
+        </ContextProvider<LicenseContext>>
+    }
+}
+
+#[function_component]
+fn LicenseNotice() -> Html {
+    let context = use_context::<LicenseContext>().expect("LicenseNotice is only used by IconLicenseProvider");
+    let force_update = use_force_update();
+
+    let registry = &context.0;
+    *registry.refresh.borrow_mut() = Some(Callback::from(move |_| force_update.force_update()));
+    registry.dirty.set(false);
+
+    let licenses: Vec<&str> = registry.licenses.borrow().iter().copied().collect();
+    html! {
+        <div hidden=true data-fa-license={licenses.join("\n")} />
+    }
+}
+
+#[cfg(all(test, feature = "fa-solid"))]
+mod tests {
+    use super::*;
+    use crate::fontawesome::icons;
+    use crate::FontAwesomeSvg;
+    use yew::LocalServerRenderer;
+
+    #[test]
+    fn registry_refreshes_only_for_new_licenses() {
+        let registry = LicenseRegistry::default();
+        let refreshes = Rc::new(Cell::new(0));
+        let counter = refreshes.clone();
+        *registry.refresh.borrow_mut() = Some(Callback::from(move |_| counter.set(counter.get() + 1)));
+
+        registry.register(icons::solid::HOUSE);
+        registry.flush();
+        assert_eq!(refreshes.get(), 1);
+        assert_eq!(registry.licenses.borrow().len(), 1);
+
+        // Rendering clears the flag; icons of a known license change nothing
+        registry.dirty.set(false);
+        registry.register(icons::solid::HOUSE);
+        registry.flush();
+        assert_eq!(refreshes.get(), 1);
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct PageProps {
+        icons: Vec<&'static Icon>,
+    }
+
+    #[function_component]
+    fn Page(props: &PageProps) -> Html {
+        html! {
+            <IconLicenseProvider>
+                { for props.icons.iter().map(|icon| html! { <FontAwesomeSvg icon={*icon} /> }) }
+            </IconLicenseProvider>
+        }
+    }
+
+    #[tokio::test]
+    async fn provider_attributes_the_page_once() {
+        let html = LocalServerRenderer::<Page>::with_props(PageProps { icons: vec![icons::solid::HOUSE, icons::solid::HOUSE] })
+            .hydratable(false)
+            .render()
+            .await;
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert_eq!(html.matches("data-fa-license=").count(), 1);
+        // The hidden notice comes after the icons
+        let notice = &html[html.rfind("<svg").unwrap()..];
+        assert!(notice.contains("<div") && notice.contains("data-fa-license=\"!Font Awesome Free"), "{}", notice);
+        assert!(notice.contains("hidden"));
+    }
+}
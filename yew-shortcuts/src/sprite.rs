@@ -0,0 +1,249 @@
+//! Sprite-sheet rendering: every icon is defined once as a `<symbol>` and
+//! each `FontAwesomeSvg` instance only references it with `<use>`
+
+use crate::fontawesome::{self, Icon};
+use crate::license::LicenseContext;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Id of the `<symbol>` an icon is rendered into, e.g. `fa-solid-house`.
+/// Custom icons have `custom-` categories, so they never share an id with a
+/// FontAwesome icon.
+pub fn symbol_id(icon: &Icon) -> String {
+    fontawesome::symbol_id(icon)
+}
+
+/// Icons used below an [`IconSpriteProvider`], in symbol id order
+#[derive(Default)]
+pub(crate) struct SpriteRegistry {
+    icons: RefCell<BTreeMap<String, &'static Icon>>,
+    /// Set when an icon was added after the sheet last rendered
+    dirty: Cell<bool>,
+    /// Re-renders the sheet
+    refresh: RefCell<Option<Callback<()>>>,
+}
+
+impl SpriteRegistry {
+    /// Adds an icon to the sheet and returns its symbol id
+    pub(crate) fn register(&self, icon: &'static Icon) -> String {
+        let id = symbol_id(icon);
+        if !self.icons.borrow().contains_key(&id) {
+            self.icons.borrow_mut().insert(id.clone(), icon);
+            self.dirty.set(true);
+        }
+        id
+    }
+
+    /// Re-renders the sheet if icons were added since it last rendered
+    pub(crate) fn flush(&self) {
+        if self.dirty.get() {
+            if let Some(refresh) = &*self.refresh.borrow() {
+                refresh.emit(());
+            }
+        }
+    }
+
+    /// Distinct licenses of the icons in the sheet, one per line
+    fn licenses(&self) -> String {
+        let licenses: BTreeSet<&str> = self.icons.borrow().values().map(|icon| fontawesome::license(icon)).collect();
+        licenses.into_iter().collect::<Vec<_>>().join("\n")
+    }
+
+    /// The sheet's markup; `with_license` stamps the icons' licenses on it
+    fn sheet(&self, with_license: bool) -> String {
+        let mut output = String::from(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" aria-hidden="true" style="position:absolute;width:0;height:0;overflow:hidden""#,
+        );
+        if with_license && !self.icons.borrow().is_empty() {
+            output.push_str(&format!(r#" data-fa-license="{}""#, escape_attribute(&self.licenses())));
+        }
+        output.push('>');
+        for (id, icon) in self.icons.borrow().iter() {
+            // No viewBox: `<use>` positions the path with the same transform as an inline one
+            output.push_str(&format!(
+                r#"<symbol id="{}" overflow="visible"><path fill="currentColor" d="{}"/></symbol>"#,
+                escape_attribute(id),
+                escape_attribute(icon.cropped.d)
+            ));
+        }
+        output.push_str("</svg>");
+        output
+    }
+}
+
+/// Handle to the registry of the nearest [`IconSpriteProvider`]
+#[derive(Clone)]
+pub(crate) struct SpriteContext(pub(crate) Rc<SpriteRegistry>);
+
+impl PartialEq for SpriteContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Properties for IconSpriteProvider component
+#[derive(Properties, PartialEq)]
+pub struct IconSpriteProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Collects the icons of descendant `FontAwesomeSvg` instances into one
+/// hidden `<svg>` of `<symbol>`s, rendered after the children.
+///
+/// Inside a provider, `FontAwesomeSvg` renders `<use href="#fa-solid-house"/>`
+/// instead of inlining the path data, unless `sprite={false}` is set. Symbols
+/// stay in the sheet for the provider's lifetime.
+///
+/// The sheet carries the licenses of its icons in `data-fa-license`, unless
+/// an [`IconLicenseProvider`](crate::IconLicenseProvider) around it already
+/// attributes them.
+///
+/// With server-side rendering the sheet is written after the children, so it
+/// contains every icon they used; hydration adopts it as is.
+#[function_component]
+pub fn IconSpriteProvider(props: &IconSpriteProviderProps) -> Html {
+    let context = use_memo((), |_| SpriteContext(Rc::default()));
+
+    html! {
+        <ContextProvider<SpriteContext> context={(*context).clone()}>
+            { props.children.clone() }
+            <SpriteSheet />
+        </ContextProvider<SpriteContext>>
+    }
+}
+
+#[function_component]
+fn SpriteSheet() -> Html {
+    let context = use_context::<SpriteContext>().expect("SpriteSheet is only used by IconSpriteProvider");
+    let license = use_context::<LicenseContext>();
+    let force_update = use_force_update();
+
+    let registry = &context.0;
+    *registry.refresh.borrow_mut() = Some(Callback::from(move |_| force_update.force_update()));
+    registry.dirty.set(false);
+
+    // Raw HTML, so hydration takes over the server-rendered sheet even when
+    // the client registers icons in a different order
+    Html::from_html_unchecked(registry.sheet(license.is_none()).into())
+}
+
+/// Escapes text for a double-quoted attribute in raw HTML
+fn escape_attribute(text: &str) -> String {
+    text.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
+
+#[cfg(all(test, feature = "fa-solid", feature = "fa-regular"))]
+mod tests {
+    use super::*;
+    use crate::fontawesome::icons;
+    use crate::{FontAwesomeSvg, IconLicenseProvider};
+    use yew::LocalServerRenderer;
+
+    #[derive(Properties, PartialEq)]
+    struct PageProps {
+        icons: Vec<&'static Icon>,
+        #[prop_or_default]
+        license_provider: bool,
+    }
+
+    #[function_component]
+    fn Page(props: &PageProps) -> Html {
+        let sprites = html! {
+            <IconSpriteProvider>
+                { for props.icons.iter().map(|icon| html! { <FontAwesomeSvg icon={*icon} /> }) }
+            </IconSpriteProvider>
+        };
+        if props.license_provider {
+            html! { <IconLicenseProvider>{ sprites }</IconLicenseProvider> }
+        } else {
+            sprites
+        }
+    }
+
+    async fn render(icons: Vec<&'static Icon>, license_provider: bool) -> String {
+        LocalServerRenderer::<Page>::with_props(PageProps { icons, license_provider })
+            .hydratable(false)
+            .render()
+            .await
+    }
+
+    /// The sheet's markup, which comes after every icon
+    fn sheet(html: &str) -> &str {
+        let start = html.find(r#"<svg xmlns="http://www.w3.org/2000/svg" aria-hidden="true""#).expect("no sprite sheet");
+        let end = start + html[start..].find("</svg>").unwrap() + "</svg>".len();
+        &html[start..end]
+    }
+
+    /// Values of the given attribute, in document order
+    fn attribute_values<'a>(html: &'a str, name: &str) -> Vec<&'a str> {
+        let prefix = format!(r#"{}=""#, name);
+        html.match_indices(&prefix)
+            .map(|(start, _)| {
+                let value = &html[start + prefix.len()..];
+                &value[..value.find('"').unwrap()]
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sheet_holds_exactly_the_symbols_used() {
+        let html = render(vec![icons::solid::HOUSE, icons::regular::HEART, icons::solid::HOUSE], false).await;
+        let sheet = sheet(&html);
+        let body = &html[..html.find(sheet).unwrap()];
+
+        assert_eq!(attribute_values(sheet, "id"), ["fa-regular-heart", "fa-solid-house"]);
+        assert_eq!(attribute_values(body, "href"), ["#fa-solid-house", "#fa-regular-heart", "#fa-solid-house"]);
+        // Path data is only written once, into the sheet
+        assert!(!body.contains(" d=\""));
+    }
+
+    #[tokio::test]
+    async fn sheet_does_not_depend_on_render_order() {
+        // The client renders the sheet from its own registrations, so the
+        // markup it hydrates against must not depend on the order of icons
+        let forward = render(vec![icons::solid::HOUSE, icons::regular::HEART], false).await;
+        let backward = render(vec![icons::regular::HEART, icons::solid::HOUSE], false).await;
+        assert_eq!(sheet(&forward), sheet(&backward));
+    }
+
+    #[tokio::test]
+    async fn sheet_escapes_names_and_path_data() {
+        // Custom icons come from arbitrary SVG files
+        static HOSTILE: Icon = Icon {
+            category: "solid",
+            name: "x\" onload=\"alert(1)",
+            cropped: fontawesome::CroppedIcon {
+                view_box: "0 0 10 10",
+                bounds: fontawesome::ViewBox { min_x: 0.0, min_y: 0.0, width: 10.0, height: 10.0 },
+                optical_center: (5.0, 5.0),
+                d: "M0 0\"/></symbol><script>alert(1)</script><symbol><path d=\"",
+            },
+            #[cfg(feature = "full-svg")]
+            full: None,
+        };
+
+        let html = render(vec![&HOSTILE], false).await;
+        let sheet = sheet(&html);
+        assert!(!sheet.contains("<script>") && !sheet.contains("onload=\""));
+        assert_eq!(attribute_values(sheet, "id"), [escape_attribute(&symbol_id(&HOSTILE))]);
+        assert_eq!(attribute_values(sheet, " d"), [escape_attribute(HOSTILE.cropped.d)]);
+    }
+
+    #[tokio::test]
+    async fn sheet_is_attributed_with_the_icons_licenses() {
+        let html = render(vec![icons::solid::HOUSE, icons::regular::HEART], false).await;
+        let licenses = attribute_values(sheet(&html), "data-fa-license");
+        assert_eq!(licenses, [escape_attribute(fontawesome::license(icons::solid::HOUSE))]);
+
+        let empty = render(Vec::new(), false).await;
+        assert!(attribute_values(sheet(&empty), "data-fa-license").is_empty());
+
+        // An IconLicenseProvider attributes the icons once for the whole page
+        let html = render(vec![icons::solid::HOUSE], true).await;
+        assert!(attribute_values(sheet(&html), "data-fa-license").is_empty());
+        assert_eq!(attribute_values(&html, "data-fa-license").len(), 1);
+    }
+}